@@ -1,4 +1,7 @@
-use crate::{CoreStage, Plugin, PluginGroup, PluginGroupBuilder, StartupSchedule, StartupStage};
+use crate::{
+    sub_app_thread::SubAppThread, CoreStage, Plugin, PluginDependency, PluginError, PluginGroup,
+    PluginGroupBuilder, StartupSchedule, StartupStage,
+};
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
     event::{Event, Events},
@@ -11,7 +14,7 @@ use bevy_ecs::{
     world::World,
 };
 use bevy_utils::{tracing::debug, HashMap};
-use std::fmt::Debug;
+use std::{any::TypeId, fmt::Debug};
 
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
@@ -56,13 +59,90 @@ pub struct App {
     pub runner: Box<dyn Fn(App)>,
     /// A container of [`Stage`]s set to be run in a linear order.
     pub schedule: Schedule,
-    sub_apps: HashMap<Box<dyn AppLabel>, SubApp>,
+    pub(crate) sub_apps: HashMap<Box<dyn AppLabel>, SubApp>,
+    plugins: AddedPlugins,
+}
+
+/// The [`Plugin`]s added to an [`App`].
+#[derive(Default)]
+struct AddedPlugins {
+    /// The name and dependencies of each added plugin.
+    added: HashMap<TypeId, (String, Vec<PluginDependency>)>,
+    /// The built plugins, in the order their build finished.
+    built: Vec<Box<dyn Plugin>>,
+    state: PluginsState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum PluginsState {
+    Building,
+    Finished,
+    Cleaned,
+}
+
+impl Default for PluginsState {
+    fn default() -> Self {
+        PluginsState::Building
+    }
 }
 
 /// Each `SubApp` has its own [`Schedule`] and [`World`], enabling a separation of concerns.
-struct SubApp {
-    app: App,
+pub(crate) struct SubApp {
+    /// Only `None` while the sub app is updated on its thread, during [`App::update`].
+    app: Option<App>,
     runner: Box<dyn Fn(&mut World, &mut App)>,
+    update: SubAppUpdate,
+}
+
+/// How a `SubApp` is updated after its runner has been called.
+enum SubAppUpdate {
+    /// The runner does everything.
+    None,
+    /// Right after the runner.
+    Inline(Box<dyn Fn(&mut App) + Send>),
+    /// On its own thread, while the main schedule runs.
+    Pipelined(SubAppThread),
+}
+
+impl SubApp {
+    fn app(&self) -> &App {
+        self.app.as_ref().expect("the sub app is being updated")
+    }
+
+    fn app_mut(&mut self) -> &mut App {
+        self.app.as_mut().expect("the sub app is being updated")
+    }
+
+    /// Runs the runner of a pipelined sub app, then sends it to be updated on its thread.
+    fn start_pipelined(&mut self, world: &mut World) {
+        if let SubAppUpdate::Pipelined(thread) = &mut self.update {
+            let mut app = self.app.take().expect("the sub app is being updated");
+            (self.runner)(world, &mut app);
+            thread.start(app);
+        }
+    }
+
+    /// Updates a sub app that isn't pipelined, or waits for a pipelined one to be updated.
+    fn finish_update(&mut self, world: &mut World) {
+        match &self.update {
+            SubAppUpdate::None => (self.runner)(world, self.app.as_mut().unwrap()),
+            SubAppUpdate::Inline(update) => {
+                let app = self.app.as_mut().unwrap();
+                (self.runner)(world, app);
+                update(app);
+            }
+            SubAppUpdate::Pipelined(thread) => self.app = Some(thread.wait()),
+        }
+    }
+}
+
+impl Drop for SubApp {
+    fn drop(&mut self) {
+        // The world of a pipelined sub app may hold non-send resources of its thread
+        if let (SubAppUpdate::Pipelined(thread), Some(app)) = (&mut self.update, self.app.take()) {
+            thread.stop(app);
+        }
+    }
 }
 
 impl Default for App {
@@ -100,21 +180,61 @@ impl App {
             schedule: Default::default(),
             runner: Box::new(run_once),
             sub_apps: HashMap::default(),
+            plugins: Default::default(),
         }
     }
 
     /// Advances the execution of the [`Schedule`] by one cycle.
     ///
-    /// This method also updates sub apps.
+    /// This method also updates sub apps. [Pipelined](Self::pipeline_sub_app) sub apps are
+    /// updated while the [`Schedule`] runs, with the data of the previous update.
+    ///
+    /// The first update also [finishes](Self::finish) and [cleans up](Self::cleanup) the plugins,
+    /// if that hasn't already been done.
     ///
     /// See [`add_sub_app`](Self::add_sub_app) and [`run_once`](Schedule::run_once) for more details.
     pub fn update(&mut self) {
+        self.finish();
+        self.cleanup();
         #[cfg(feature = "trace")]
         let _bevy_frame_update_span = info_span!("frame").entered();
+        for sub_app in self.sub_apps.values_mut() {
+            sub_app.start_pipelined(&mut self.world);
+        }
         self.schedule.run(&mut self.world);
         for sub_app in self.sub_apps.values_mut() {
-            (sub_app.runner)(&mut self.world, &mut sub_app.app);
+            sub_app.finish_update(&mut self.world);
+        }
+    }
+
+    /// Advances the application by `frames` frames, calling [`App::update`] for each of them.
+    ///
+    /// This is meant for tests driving an [`App`] without a runner. Together with a manual
+    /// `TimeUpdateStrategy` from `bevy_time`, every frame advances time by the same amount.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// #[derive(Default)]
+    /// struct Frames(u32);
+    ///
+    /// fn count_frames(mut frames: ResMut<Frames>) {
+    ///     frames.0 += 1;
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.init_resource::<Frames>().add_system(count_frames);
+    /// app.update_frames(3);
+    /// assert_eq!(app.world.resource::<Frames>().0, 3);
+    /// ```
+    pub fn update_frames(&mut self, frames: u32) -> &mut Self {
+        for _ in 0..frames {
+            self.update();
         }
+        self
     }
 
     /// Starts the application by calling the app's [runner function](Self::set_runner).
@@ -125,11 +245,49 @@ impl App {
         #[cfg(feature = "trace")]
         let _bevy_app_run_span = info_span!("bevy_app").entered();
 
+        self.finish();
+        self.cleanup();
         let mut app = std::mem::replace(self, App::empty());
         let runner = std::mem::replace(&mut app.runner, Box::new(run_once));
         (runner)(app);
     }
 
+    /// Runs [`Plugin::finish`] for all the plugins added so far, in the order their build
+    /// completed.
+    ///
+    /// This is done by [`run`](Self::run) and by the first [`update`](Self::update), and only
+    /// needs to be called manually to inspect the [`App`] before then. Calling it again does
+    /// nothing.
+    pub fn finish(&mut self) {
+        if self.plugins.state >= PluginsState::Finished {
+            return;
+        }
+        self.plugins.state = PluginsState::Finished;
+        let plugins = std::mem::take(&mut self.plugins.built);
+        for plugin in &plugins {
+            plugin.finish(self);
+        }
+        self.plugins.built.splice(0..0, plugins);
+    }
+
+    /// Runs [`Plugin::cleanup`] for all the plugins added so far, after
+    /// [finishing](Self::finish) them if that hasn't been done.
+    ///
+    /// This is done by [`run`](Self::run) and by the first [`update`](Self::update). Calling it
+    /// again does nothing.
+    pub fn cleanup(&mut self) {
+        self.finish();
+        if self.plugins.state >= PluginsState::Cleaned {
+            return;
+        }
+        self.plugins.state = PluginsState::Cleaned;
+        let plugins = std::mem::take(&mut self.plugins.built);
+        for plugin in &plugins {
+            plugin.cleanup(self);
+        }
+        self.plugins.built.splice(0..0, plugins);
+    }
+
     /// Adds a [`Stage`] with the given `label` to the last position of the app's
     /// [`Schedule`].
     ///
@@ -631,6 +789,21 @@ impl App {
         self
     }
 
+    /// Sends an event of type `T`, which systems can read during the next [`App::update`].
+    ///
+    /// This is mostly useful in tests, to simulate events coming from outside the app.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event type has not been added with [`App::add_event`].
+    pub fn send_event<T>(&mut self, event: T) -> &mut Self
+    where
+        T: Event,
+    {
+        self.world.resource_mut::<Events<T>>().send(event);
+        self
+    }
+
     /// Inserts a [`Resource`] to the current [`App`] and overwrites any [`Resource`] previously added of the same type.
     ///
     /// A [`Resource`] in Bevy represents globally unique data. [`Resource`]s must be added to Bevy apps
@@ -763,13 +936,94 @@ impl App {
     /// #
     /// App::new().add_plugin(bevy_log::LogPlugin::default());
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the plugin can't be added, see [`try_add_plugin`](Self::try_add_plugin).
     pub fn add_plugin<T>(&mut self, plugin: T) -> &mut Self
     where
         T: Plugin,
     {
+        self.add_boxed_plugin(Box::new(plugin))
+    }
+
+    /// Adds a single [`Plugin`], or returns an error if:
+    /// - one of its required [dependencies](Plugin::dependencies) has not been added,
+    /// - it is an optional dependency of a plugin that has already been added,
+    /// - it is [unique](Plugin::is_unique) and has already been added.
+    pub fn try_add_plugin<T>(&mut self, plugin: T) -> Result<&mut Self, PluginError>
+    where
+        T: Plugin,
+    {
+        self.try_add_boxed_plugin(Box::new(plugin))
+    }
+
+    /// Adds a single boxed [`Plugin`], such as a dynamically loaded one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the plugin can't be added, see [`try_add_plugin`](Self::try_add_plugin).
+    pub fn add_boxed_plugin(&mut self, plugin: Box<dyn Plugin>) -> &mut Self {
+        if let Err(error) = self.try_add_boxed_plugin(plugin) {
+            panic!("Error adding plugin: {}", error);
+        }
+        self
+    }
+
+    /// Adds a single boxed [`Plugin`], or returns an error in the same cases as
+    /// [`try_add_plugin`](Self::try_add_plugin).
+    pub fn try_add_boxed_plugin(
+        &mut self,
+        plugin: Box<dyn Plugin>,
+    ) -> Result<&mut Self, PluginError> {
+        let type_id = (*plugin).type_id();
+        if plugin.is_unique() && self.plugins.added.contains_key(&type_id) {
+            return Err(PluginError::Duplicate {
+                plugin: plugin.name().to_string(),
+            });
+        }
+        let dependencies = plugin.dependencies();
+        if let Some(missing) = dependencies.iter().find(|dependency| {
+            !dependency.is_optional() && !self.plugins.added.contains_key(&dependency.type_id())
+        }) {
+            return Err(PluginError::MissingDependency {
+                plugin: plugin.name().to_string(),
+                dependency: missing.name(),
+            });
+        }
+        let late_dependent = self.plugins.added.values().find(|(_, dependencies)| {
+            dependencies
+                .iter()
+                .any(|dependency| dependency.is_optional() && dependency.type_id() == type_id)
+        });
+        if let (false, Some((dependent, _))) =
+            (self.plugins.added.contains_key(&type_id), late_dependent)
+        {
+            return Err(PluginError::DependencyAddedLate {
+                plugin: dependent.clone(),
+                dependency: plugin.name().to_string(),
+            });
+        }
+
         debug!("added plugin: {}", plugin.name());
+        self.plugins
+            .added
+            .insert(type_id, (plugin.name().to_string(), dependencies));
         plugin.build(self);
-        self
+        // Plugins added late still go through all the phases
+        if self.plugins.state >= PluginsState::Finished {
+            plugin.finish(self);
+        }
+        if self.plugins.state >= PluginsState::Cleaned {
+            plugin.cleanup(self);
+        }
+        self.plugins.built.push(plugin);
+        Ok(self)
+    }
+
+    /// Returns `true` if a plugin of type `T` has been added.
+    pub fn is_plugin_added<T: Plugin>(&self) -> bool {
+        self.plugins.added.contains_key(&TypeId::of::<T>())
     }
 
     /// Adds a group of [`Plugin`]s.
@@ -845,6 +1099,8 @@ impl App {
     }
 
     /// Adds the type `T` to the type registry [`Resource`].
+    ///
+    /// The types of its fields and generic parameters are registered along with it.
     #[cfg(feature = "bevy_reflect")]
     pub fn register_type<T: bevy_reflect::GetTypeRegistration>(&mut self) -> &mut Self {
         {
@@ -868,13 +1124,80 @@ impl App {
         self.sub_apps.insert(
             Box::new(label),
             SubApp {
-                app,
+                app: Some(app),
                 runner: Box::new(sub_app_runner),
+                update: SubAppUpdate::None,
             },
         );
         self
     }
 
+    /// Adds an [`App`] as a child of the current one, with a separate function to update it.
+    ///
+    /// The function `extract` is called by the [`update`](Self::update) method with the main app
+    /// world, as with [`add_sub_app`](Self::add_sub_app). It should copy the data the sub app
+    /// needs, so that `sub_app_update`, which is called with the sub app only, can then run
+    /// independently of the main app. This allows the sub app to be
+    /// [pipelined](Self::pipeline_sub_app).
+    pub fn add_sub_app_with_update(
+        &mut self,
+        label: impl AppLabel,
+        app: App,
+        extract: impl Fn(&mut World, &mut App) + 'static,
+        sub_app_update: impl Fn(&mut App) + Send + 'static,
+    ) -> &mut Self {
+        self.sub_apps.insert(
+            Box::new(label),
+            SubApp {
+                app: Some(app),
+                runner: Box::new(extract),
+                update: SubAppUpdate::Inline(Box::new(sub_app_update)),
+            },
+        );
+        self
+    }
+
+    /// Updates a sub app on its own thread, in parallel with the main app.
+    ///
+    /// The sub app must have been added with [`add_sub_app_with_update`](Self::add_sub_app_with_update).
+    /// In each [`update`](Self::update), its extract function is called first, before the main
+    /// [`Schedule`] runs. This is the only point where the main app and the sub app are accessed
+    /// together. The sub app update then runs on the sub app thread while the [`Schedule`] runs,
+    /// so the sub app is always working one frame behind the main app. [`update`](Self::update)
+    /// returns once both are done.
+    ///
+    /// A pipelined sub app can't have sub apps of its own. Its [`World`] is bound to the sub app
+    /// thread before its first update, so it can't hold non-send resources until then. Systems
+    /// accessing non-send resources of the sub app then run on that thread, and extract systems
+    /// can't access them.
+    ///
+    /// On `wasm32`, where threads aren't available, the sub app keeps being updated sequentially.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sub app doesn't exist, or wasn't added with a separate update function.
+    pub fn pipeline_sub_app(&mut self, label: impl AppLabel) -> &mut Self {
+        let name = format!("{:?}", label);
+        let sub_app = match self.sub_apps.get_mut((&label) as &dyn AppLabel) {
+            Some(sub_app) => sub_app,
+            None => panic!("Sub-App with label '{}' does not exist", name),
+        };
+        if cfg!(target_arch = "wasm32") {
+            return self;
+        }
+        match std::mem::replace(&mut sub_app.update, SubAppUpdate::None) {
+            SubAppUpdate::Inline(update) => {
+                sub_app.update = SubAppUpdate::Pipelined(SubAppThread::spawn(name, update));
+            }
+            SubAppUpdate::Pipelined(thread) => sub_app.update = SubAppUpdate::Pipelined(thread),
+            SubAppUpdate::None => panic!(
+                "Sub-App with label '{}' can't be pipelined, it has no separate update function",
+                name
+            ),
+        }
+        self
+    }
+
     /// Retrieves a `SubApp` stored inside this [`App`].
     ///
     /// # Panics
@@ -892,7 +1215,7 @@ impl App {
     pub fn get_sub_app_mut(&mut self, label: impl AppLabel) -> Result<&mut App, impl AppLabel> {
         self.sub_apps
             .get_mut((&label) as &dyn AppLabel)
-            .map(|sub_app| &mut sub_app.app)
+            .map(SubApp::app_mut)
             .ok_or(label)
     }
 
@@ -913,12 +1236,12 @@ impl App {
     pub fn get_sub_app(&self, label: impl AppLabel) -> Result<&App, impl AppLabel> {
         self.sub_apps
             .get((&label) as &dyn AppLabel)
-            .map(|sub_app| &sub_app.app)
+            .map(SubApp::app)
             .ok_or(label)
     }
 }
 
-fn run_once(mut app: App) {
+pub(crate) fn run_once(mut app: App) {
     app.update();
 }
 
@@ -930,3 +1253,251 @@ fn run_once(mut app: App) {
 /// frame is over.
 #[derive(Debug, Clone, Default)]
 pub struct AppExit;
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, Plugin, PluginDependency, PluginError};
+    use bevy_ecs::prelude::*;
+    use std::{
+        rc::Rc,
+        sync::{
+            mpsc::{channel, Receiver},
+            Arc, Mutex,
+        },
+        thread::ThreadId,
+        time::Duration,
+    };
+
+    struct PluginA;
+    impl Plugin for PluginA {
+        fn build(&self, _: &mut App) {}
+    }
+
+    struct PluginB;
+    impl Plugin for PluginB {
+        fn build(&self, _: &mut App) {}
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::required::<PluginA>()]
+        }
+    }
+
+    struct PluginC;
+    impl Plugin for PluginC {
+        fn build(&self, _: &mut App) {}
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::optional::<PluginA>()]
+        }
+    }
+
+    struct NotUnique;
+    impl Plugin for NotUnique {
+        fn build(&self, _: &mut App) {}
+        fn is_unique(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn missing_dependency() {
+        let mut app = App::new();
+        let error = app.try_add_plugin(PluginB).err().unwrap();
+        assert_eq!(
+            error,
+            PluginError::MissingDependency {
+                plugin: std::any::type_name::<PluginB>().to_string(),
+                dependency: std::any::type_name::<PluginA>(),
+            }
+        );
+        assert!(error.to_string().contains("PluginA"));
+        assert!(!app.is_plugin_added::<PluginB>());
+
+        app.add_plugin(PluginA).add_plugin(PluginB);
+        assert!(app.is_plugin_added::<PluginB>());
+    }
+
+    #[test]
+    #[should_panic(expected = "PluginA")]
+    fn add_plugin_panics_naming_missing_dependency() {
+        App::new().add_plugin(PluginB);
+    }
+
+    #[test]
+    fn optional_dependency() {
+        App::new().add_plugin(PluginC);
+        App::new().add_plugin(PluginA).add_plugin(PluginC);
+
+        let mut app = App::new();
+        app.add_plugin(PluginC);
+        assert!(matches!(
+            app.try_add_plugin(PluginA),
+            Err(PluginError::DependencyAddedLate { .. })
+        ));
+    }
+
+    #[test]
+    fn duplicate_plugin() {
+        let mut app = App::new();
+        app.add_plugin(PluginA);
+        assert!(matches!(
+            app.try_add_plugin(PluginA),
+            Err(PluginError::Duplicate { .. })
+        ));
+
+        app.add_plugin(NotUnique).add_plugin(NotUnique);
+    }
+
+    #[derive(Default)]
+    struct Value(u32);
+
+    struct ReadsValue;
+    impl Plugin for ReadsValue {
+        fn build(&self, _: &mut App) {}
+        fn finish(&self, app: &mut App) {
+            let value = app.world.resource::<Value>().0;
+            app.insert_resource(Value(value + 1));
+        }
+        fn cleanup(&self, app: &mut App) {
+            app.world.resource_mut::<Value>().0 *= 10;
+        }
+    }
+
+    struct InsertsValue;
+    impl Plugin for InsertsValue {
+        fn build(&self, app: &mut App) {
+            app.insert_resource(Value(1));
+        }
+    }
+
+    #[test]
+    fn finish_and_cleanup() {
+        let mut app = App::new();
+        app.add_plugin(ReadsValue).add_plugin(InsertsValue);
+        app.update();
+        assert_eq!(app.world.resource::<Value>().0, 20);
+
+        // The phases only run once
+        app.finish();
+        app.cleanup();
+        app.update();
+        assert_eq!(app.world.resource::<Value>().0, 20);
+    }
+
+    #[derive(Default)]
+    struct Frame(u32);
+
+    /// The frames seen by the pipelined sub app.
+    #[derive(Default)]
+    struct ExtractedFrames(Vec<u32>);
+
+    /// Whether the main schedule ran while the sub app was being updated.
+    struct Overlapped(Mutex<Receiver<()>>, Vec<bool>);
+
+    #[test]
+    fn pipelined_sub_app() {
+        let (sender, receiver) = channel();
+        let mut app = App::new();
+        app.init_resource::<Frame>()
+            .insert_resource(Overlapped(Mutex::new(receiver), Vec::new()))
+            .add_system(
+                |mut frame: ResMut<Frame>, mut overlapped: ResMut<Overlapped>| {
+                    // The sub app signals when it is updated, which must happen while this runs
+                    let signaled = overlapped
+                        .0
+                        .lock()
+                        .unwrap()
+                        .recv_timeout(Duration::from_secs(10))
+                        .is_ok();
+                    overlapped.1.push(signaled);
+                    frame.0 += 1;
+                },
+            );
+
+        let mut sub_app = App::empty();
+        sub_app.init_resource::<ExtractedFrames>();
+        app.add_sub_app_with_update(
+            "pipelined",
+            sub_app,
+            |world, sub_app| {
+                let frame = world.resource::<Frame>().0;
+                sub_app
+                    .world
+                    .resource_mut::<ExtractedFrames>()
+                    .0
+                    .push(frame);
+            },
+            move |_| sender.send(()).unwrap(),
+        )
+        .pipeline_sub_app("pipelined");
+
+        app.update_frames(3);
+        assert_eq!(app.world.resource::<Overlapped>().1, vec![true; 3]);
+        // The sub app is one frame behind
+        assert_eq!(app.world.resource::<Frame>().0, 3);
+        assert_eq!(
+            app.sub_app("pipelined")
+                .world
+                .resource::<ExtractedFrames>()
+                .0,
+            vec![0, 1, 2]
+        );
+    }
+
+    /// The thread [`ThreadBound`] was dropped on.
+    #[derive(Default, Clone)]
+    struct DroppedOn(Arc<Mutex<Option<ThreadId>>>);
+
+    /// A non-send resource recording the thread it is dropped on.
+    struct ThreadBound(Rc<()>, DroppedOn);
+
+    impl Drop for ThreadBound {
+        fn drop(&mut self) {
+            *self.1 .0.lock().unwrap() = Some(std::thread::current().id());
+        }
+    }
+
+    #[derive(Default)]
+    struct Marker;
+
+    fn insert_thread_bound(world: &mut World) {
+        if !world.contains_resource::<ThreadBound>() {
+            let dropped_on = world.resource::<DroppedOn>().clone();
+            world.insert_non_send_resource(ThreadBound(Rc::new(()), dropped_on));
+        }
+    }
+
+    #[test]
+    fn pipelined_sub_app_with_non_send_resources() {
+        let dropped_on = DroppedOn::default();
+        let mut app = App::new();
+        let mut sub_app = App::new();
+        sub_app
+            .init_resource::<Marker>()
+            .insert_resource(dropped_on.clone())
+            .add_system(insert_thread_bound.exclusive_system().at_start())
+            .add_system(|_marker: NonSend<Marker>, bound: NonSend<ThreadBound>| {
+                assert_eq!(Rc::strong_count(&bound.0), 1);
+            });
+        app.add_sub_app_with_update("pipelined", sub_app, |_, _| {}, |sub_app| sub_app.update())
+            .pipeline_sub_app("pipelined");
+
+        app.update_frames(3);
+        drop(app);
+        let dropped_on = dropped_on
+            .0
+            .lock()
+            .unwrap()
+            .expect("ThreadBound wasn't dropped");
+        assert_ne!(dropped_on, std::thread::current().id());
+    }
+
+    #[test]
+    #[should_panic = "The sub app thread panicked"]
+    fn pipelined_sub_app_created_with_non_send_resources() {
+        let mut app = App::new();
+        let mut sub_app = App::new();
+        sub_app.insert_non_send_resource(Rc::new(()));
+        app.add_sub_app_with_update("pipelined", sub_app, |_, _| {}, |sub_app| sub_app.update())
+            .pipeline_sub_app("pipelined");
+        app.update();
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{app::AppExit, App};
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
 
 use bevy_utils::tracing::info;
 
@@ -8,17 +8,67 @@ use bevy_utils::tracing::info;
 /// It gets used when the `bevy_ci_testing` feature is enabled to automatically
 /// exit a Bevy app when run through the CI. This is needed because otherwise
 /// Bevy apps would be stuck in the game loop and wouldn't allow the CI to progress.
+///
+/// Apps can also be scripted, by sending [`CiTestingEvent`]s on given frames. Plugins can read
+/// their own fields from the same file with [`load_ci_testing_config`], like the simulated
+/// `inputs` of `bevy_input`:
+///
+/// ```ron
+/// (
+///     exit_after: Some(60),
+///     events: [
+///         (30, Screenshot),
+///     ],
+///     inputs: [
+///         (10, KeyPress(Space)),
+///         (12, KeyRelease(Space)),
+///     ],
+/// )
+/// ```
 #[derive(Deserialize)]
 pub struct CiTestingConfig {
     /// The number of frames after which Bevy should exit.
     pub exit_after: Option<u32>,
+    /// The events to send, each with the frame on which it is sent.
+    #[serde(default)]
+    pub events: Vec<CiTestingEventOnFrame>,
+}
+
+/// A [`CiTestingEvent`] sent on the given frame.
+#[derive(Deserialize)]
+pub struct CiTestingEventOnFrame(pub u32, pub CiTestingEvent);
+
+/// An event scripted in the [`CiTestingConfig`].
+///
+/// Except for [`CiTestingEvent::AppExit`], which is handled by the app itself, these events are
+/// sent for plugins to act on.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum CiTestingEvent {
+    /// Exit the app.
+    AppExit,
+    /// Save the primary window to `screenshot-<frame>.png`, which `bevy_render` does at the end
+    /// of the frame.
+    Screenshot,
 }
 
 fn ci_testing_exit_after(
     mut current_frame: bevy_ecs::prelude::Local<u32>,
     ci_testing_config: bevy_ecs::prelude::Res<CiTestingConfig>,
     mut app_exit_events: bevy_ecs::event::EventWriter<AppExit>,
+    mut ci_testing_events: bevy_ecs::event::EventWriter<CiTestingEvent>,
 ) {
+    for CiTestingEventOnFrame(frame, event) in &ci_testing_config.events {
+        if *frame != *current_frame {
+            continue;
+        }
+        match event {
+            CiTestingEvent::AppExit => {
+                app_exit_events.send(AppExit);
+                info!("Exiting on frame {}. Test successful!", frame);
+            }
+            event => ci_testing_events.send(event.clone()),
+        }
+    }
     if let Some(exit_after) = ci_testing_config.exit_after {
         if *current_frame > exit_after {
             app_exit_events.send(AppExit);
@@ -28,24 +78,32 @@ fn ci_testing_exit_after(
     *current_frame += 1;
 }
 
-pub(crate) fn setup_app(app: &mut App) -> &mut App {
+/// Reads the CI testing configuration file, given by the `CI_TESTING_CONFIG` environment
+/// variable, into `T`.
+///
+/// Fields of the file that `T` doesn't have are ignored, so plugins can each read their part of
+/// the configuration.
+///
+/// # Panics
+///
+/// Panics if the file can't be read, or doesn't match `T`.
+pub fn load_ci_testing_config<T: DeserializeOwned>() -> T {
     #[cfg(not(target_arch = "wasm32"))]
-    let config: CiTestingConfig = {
+    let config = {
         let filename = std::env::var("CI_TESTING_CONFIG")
             .unwrap_or_else(|_| "ci_testing_config.ron".to_string());
-        ron::from_str(
-            &std::fs::read_to_string(filename)
-                .expect("error reading CI testing configuration file"),
-        )
-        .expect("error deserializing CI testing configuration file")
+        std::fs::read_to_string(filename).expect("error reading CI testing configuration file")
     };
     #[cfg(target_arch = "wasm32")]
-    let config: CiTestingConfig = {
-        let config = include_str!("../../../ci_testing_config.ron");
-        ron::from_str(config).expect("error deserializing CI testing configuration file")
-    };
+    let config = include_str!("../../../ci_testing_config.ron");
+    ron::from_str(&config).expect("error deserializing CI testing configuration file")
+}
+
+pub(crate) fn setup_app(app: &mut App) -> &mut App {
+    let config: CiTestingConfig = load_ci_testing_config();
 
     app.insert_resource(config)
+        .add_event::<CiTestingEvent>()
         .add_system(ci_testing_exit_after);
 
     app
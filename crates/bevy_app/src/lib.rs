@@ -6,12 +6,17 @@ mod app;
 mod plugin;
 mod plugin_group;
 mod schedule_runner;
+mod sub_app_thread;
 
 #[cfg(feature = "bevy_ci_testing")]
 mod ci_testing;
 
 pub use app::*;
 pub use bevy_derive::DynamicPlugin;
+#[cfg(feature = "bevy_ci_testing")]
+pub use ci_testing::{
+    load_ci_testing_config, CiTestingConfig, CiTestingEvent, CiTestingEventOnFrame,
+};
 pub use plugin::*;
 pub use plugin_group::*;
 pub use schedule_runner::*;
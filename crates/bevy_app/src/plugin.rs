@@ -1,17 +1,110 @@
 use crate::App;
-use std::any::Any;
+use std::any::{Any, TypeId};
+use thiserror::Error;
 
 /// A collection of Bevy app logic and configuration.
 ///
 /// Plugins configure an [`App`]. When an [`App`] registers a plugin,
-/// the plugin's [`Plugin::build`] function is run.
+/// the plugin's [`Plugin::build`] function is run. Once all plugins have been built,
+/// [`Plugin::finish`] and then [`Plugin::cleanup`] are run for each of them, in the order
+/// they were added.
 pub trait Plugin: Any + Send + Sync {
     /// Configures the [`App`] to which this plugin is added.
     fn build(&self, app: &mut App);
+    /// Finishes configuring the [`App`] once all plugins have been built.
+    ///
+    /// This can be used to read resources inserted by plugins built after this one.
+    fn finish(&self, _app: &mut App) {}
+    /// Runs after [`Plugin::finish`] has been run for all plugins.
+    fn cleanup(&self, _app: &mut App) {}
     /// Configures a name for the [`Plugin`] which is primarily used for debugging.
     fn name(&self) -> &str {
         std::any::type_name::<Self>()
     }
+    /// The plugins that must, or may, be added before this one.
+    ///
+    /// Required dependencies must have been added to the [`App`] before this plugin.
+    /// Optional dependencies can be missing, but when they are added, it must be before this
+    /// plugin. In a [`PluginGroup`](crate::PluginGroup), plugins are reordered so that they
+    /// come after their dependencies.
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        Vec::new()
+    }
+    /// Whether this plugin can only be added once to an [`App`].
+    fn is_unique(&self) -> bool {
+        true
+    }
+}
+
+/// A dependency of a [`Plugin`] on another one, declared in [`Plugin::dependencies`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluginDependency {
+    type_id: TypeId,
+    name: &'static str,
+    optional: bool,
+}
+
+impl PluginDependency {
+    /// A dependency on a plugin of type `T`, which must be added first.
+    pub fn required<T: Plugin>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            name: std::any::type_name::<T>(),
+            optional: false,
+        }
+    }
+
+    /// A dependency on a plugin of type `T` that may be missing, but must be added first
+    /// if it isn't.
+    pub fn optional<T: Plugin>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            name: std::any::type_name::<T>(),
+            optional: true,
+        }
+    }
+
+    /// The [`TypeId`] of the plugin depended on.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// The type name of the plugin depended on.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Whether this plugin can be missing.
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+}
+
+/// An error that occurs when adding a [`Plugin`] to an [`App`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PluginError {
+    /// A required dependency of the plugin has not been added.
+    #[error("plugin {plugin} requires {dependency}, which must be added before it")]
+    MissingDependency {
+        /// The name of the plugin being added.
+        plugin: String,
+        /// The name of the missing plugin.
+        dependency: &'static str,
+    },
+    /// An optional dependency of an already added plugin is added after it.
+    #[error("plugin {dependency} must be added before {plugin}, which optionally depends on it")]
+    DependencyAddedLate {
+        /// The name of the plugin that was added first.
+        plugin: String,
+        /// The name of the plugin being added.
+        dependency: String,
+    },
+    /// A unique plugin is added a second time.
+    #[error("plugin {plugin} has already been added")]
+    Duplicate {
+        /// The name of the plugin being added.
+        plugin: String,
+    },
 }
 
 /// A type representing an unsafe function that returns a mutable pointer to a [`Plugin`].
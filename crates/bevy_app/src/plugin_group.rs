@@ -1,5 +1,5 @@
 use crate::{App, Plugin};
-use bevy_utils::{tracing::debug, tracing::warn, HashMap};
+use bevy_utils::{tracing::warn, HashMap};
 use std::any::TypeId;
 
 /// Combines multiple [`Plugin`]s into a single unit.
@@ -125,15 +125,64 @@ impl PluginGroupBuilder {
         self
     }
 
+    /// Returns the enabled [`Plugin`]s in the order specified, moving plugins after the
+    /// [dependencies](Plugin::dependencies) they have in the group.
+    ///
+    /// # Panics
+    ///
+    /// Panics if plugins of the group depend on each other in a cycle.
+    fn dependency_order(&self) -> Vec<TypeId> {
+        let mut remaining: Vec<TypeId> = self
+            .order
+            .iter()
+            .filter(|ty| matches!(self.plugins.get(ty), Some(entry) if entry.enabled))
+            .copied()
+            .collect();
+        let dependencies: HashMap<TypeId, Vec<TypeId>> = remaining
+            .iter()
+            .map(|ty| {
+                let in_group = self.plugins[ty]
+                    .plugin
+                    .dependencies()
+                    .iter()
+                    .map(|dependency| dependency.type_id())
+                    .filter(|dependency| remaining.contains(dependency))
+                    .collect();
+                (*ty, in_group)
+            })
+            .collect();
+
+        let mut order = Vec::with_capacity(remaining.len());
+        while !remaining.is_empty() {
+            // Take the first plugin whose dependencies have all been placed
+            let next = remaining
+                .iter()
+                .position(|ty| dependencies[ty].iter().all(|dep| order.contains(dep)));
+            match next {
+                Some(index) => order.push(remaining.remove(index)),
+                None => panic!(
+                    "Plugins depend on each other in a cycle: {}.",
+                    remaining
+                        .iter()
+                        .map(|ty| self.plugins[ty].plugin.name())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }
+        }
+        order
+    }
+
     /// Consumes the [`PluginGroupBuilder`] and [builds](Plugin::build) the contained [`Plugin`]s
-    /// in the order specified.
-    pub fn finish(self, app: &mut App) {
-        for ty in &self.order {
-            if let Some(entry) = self.plugins.get(ty) {
-                if entry.enabled {
-                    debug!("added plugin: {}", entry.plugin.name());
-                    entry.plugin.build(app);
-                }
+    /// in the order specified, each one after its [dependencies](Plugin::dependencies).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`Plugin`] can't be added to the [`App`], see [`App::try_add_plugin`].
+    pub fn finish(mut self, app: &mut App) {
+        for ty in self.dependency_order() {
+            if let Some(entry) = self.plugins.remove(&ty) {
+                app.add_boxed_plugin(entry.plugin);
             }
         }
     }
@@ -142,7 +191,7 @@ impl PluginGroupBuilder {
 #[cfg(test)]
 mod tests {
     use super::PluginGroupBuilder;
-    use crate::{App, Plugin};
+    use crate::{App, Plugin, PluginDependency};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -263,4 +312,75 @@ mod tests {
             ]
         );
     }
+
+    struct PluginD;
+    impl Plugin for PluginD {
+        fn build(&self, _: &mut App) {}
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![
+                PluginDependency::required::<PluginC>(),
+                PluginDependency::optional::<PluginA>(),
+            ]
+        }
+    }
+
+    struct PluginE;
+    impl Plugin for PluginE {
+        fn build(&self, _: &mut App) {}
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::required::<PluginD>()]
+        }
+    }
+
+    #[test]
+    fn dependency_order() {
+        let mut group = PluginGroupBuilder::default();
+        group.add(PluginE);
+        group.add(PluginD);
+        group.add(PluginB);
+        group.add(PluginA);
+        group.add(PluginC);
+        group.disable::<PluginB>();
+
+        assert_eq!(
+            group.dependency_order(),
+            vec![
+                std::any::TypeId::of::<PluginA>(),
+                std::any::TypeId::of::<PluginC>(),
+                std::any::TypeId::of::<PluginD>(),
+                std::any::TypeId::of::<PluginE>(),
+            ]
+        );
+
+        let mut app = App::new();
+        group.finish(&mut app);
+        assert!(app.is_plugin_added::<PluginE>());
+        assert!(!app.is_plugin_added::<PluginB>());
+    }
+
+    struct PluginCycleA;
+    impl Plugin for PluginCycleA {
+        fn build(&self, _: &mut App) {}
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::optional::<PluginCycleB>()]
+        }
+    }
+
+    struct PluginCycleB;
+    impl Plugin for PluginCycleB {
+        fn build(&self, _: &mut App) {}
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::required::<PluginCycleA>()]
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cycle")]
+    fn dependency_cycle() {
+        let mut group = PluginGroupBuilder::default();
+        group.add(PluginA);
+        group.add(PluginCycleA);
+        group.add(PluginCycleB);
+        group.dependency_order();
+    }
 }
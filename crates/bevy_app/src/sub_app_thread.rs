@@ -0,0 +1,106 @@
+use crate::App;
+use std::{
+    sync::mpsc::{channel, Receiver, Sender},
+    thread::JoinHandle,
+};
+
+/// A sub app moved to its thread.
+pub(crate) struct SendApp(pub(crate) App);
+
+// SAFETY: An `App` isn't `Send` because of its runner, the runners of its sub apps, and the
+// non-send resources of its world.
+// - `SubAppThread::start` checks that the sub app has no sub apps of its own, and replaces its
+//   runner with `run_once`, which captures nothing.
+// - The thread binds the world to itself before its first update, which panics if the world
+//   already holds non-send resources. Non-send resources can then only be accessed on the thread,
+//   and `SubAppThread::stop` sends the app back to the thread to be dropped there.
+unsafe impl Send for SendApp {}
+
+/// A message to a sub app thread.
+enum Message {
+    Update(SendApp),
+    Drop(SendApp),
+}
+
+/// A thread updating a sub app, see [`App::pipeline_sub_app`].
+pub(crate) struct SubAppThread {
+    to_thread: Sender<Message>,
+    from_thread: Receiver<SendApp>,
+    thread: Option<JoinHandle<()>>,
+    /// Whether the sub app has been sent to the thread, which then owns its world.
+    started: bool,
+}
+
+impl SubAppThread {
+    /// Spawns a thread that calls `update` on each sub app it is sent.
+    ///
+    /// The thread stops when the [`SubAppThread`] is dropped.
+    pub(crate) fn spawn(name: String, update: Box<dyn Fn(&mut App) + Send>) -> Self {
+        let (to_thread, thread_receiver) = channel::<Message>();
+        let (thread_sender, from_thread) = channel();
+        let thread = std::thread::Builder::new()
+            .name(name)
+            .spawn(move || {
+                let mut bound = false;
+                while let Ok(message) = thread_receiver.recv() {
+                    let mut app = match message {
+                        Message::Update(app) => app,
+                        Message::Drop(app) => {
+                            drop(app);
+                            break;
+                        }
+                    };
+                    if !bound {
+                        app.0.world.bind_to_current_thread();
+                        bound = true;
+                    }
+                    update(&mut app.0);
+                    if thread_sender.send(app).is_err() {
+                        break;
+                    }
+                }
+            })
+            .expect("failed to spawn sub app thread");
+        Self {
+            to_thread,
+            from_thread,
+            thread: Some(thread),
+            started: false,
+        }
+    }
+
+    /// Sends `app` to be updated on the thread.
+    pub(crate) fn start(&mut self, mut app: App) {
+        assert!(
+            app.sub_apps.is_empty(),
+            "Pipelined sub apps can't have sub apps of their own"
+        );
+        app.runner = Box::new(crate::app::run_once);
+        self.started = true;
+        if self.to_thread.send(Message::Update(SendApp(app))).is_err() {
+            panic!("The sub app thread has stopped");
+        }
+    }
+
+    /// Waits for the thread to be done updating the sub app, and returns it.
+    pub(crate) fn wait(&self) -> App {
+        match self.from_thread.recv() {
+            Ok(app) => app.0,
+            Err(_) => panic!("The sub app thread panicked"),
+        }
+    }
+
+    /// Drops `app` on the thread owning its world, and waits for the thread to stop.
+    pub(crate) fn stop(&mut self, app: App) {
+        if !self.started {
+            return;
+        }
+        if let Err(message) = self.to_thread.send(Message::Drop(SendApp(app))) {
+            // The thread panicked, and the app can't be dropped on another thread
+            std::mem::forget(message);
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
@@ -1,17 +1,20 @@
 use crate::{
     path::{AssetPath, AssetPathId, SourcePathId},
     Asset, AssetIo, AssetIoError, AssetLifecycle, AssetLifecycleChannel, AssetLifecycleEvent,
-    AssetLoader, Assets, Handle, HandleId, HandleUntyped, LabelId, LoadContext, LoadState,
-    RefChange, RefChangeChannel, SourceInfo, SourceMeta,
+    AssetLoader, Assets, BoxedLoadedAsset, Handle, HandleId, HandleUntyped, LabelId, LoadContext,
+    LoadState, RefChange, RefChangeChannel, SourceInfo, SourceMeta,
 };
 use anyhow::Result;
-use bevy_ecs::system::{Res, ResMut};
+use bevy_ecs::{
+    event::EventWriter,
+    system::{Res, ResMut},
+};
 use bevy_log::warn;
 use bevy_tasks::IoTaskPool;
-use bevy_utils::{Entry, HashMap, Uuid};
-use crossbeam_channel::TryRecvError;
+use bevy_utils::{Entry, HashMap, HashSet, Uuid};
+use crossbeam_channel::{Receiver, Sender, TryRecvError};
 use parking_lot::{Mutex, RwLock};
-use std::{path::Path, sync::Arc};
+use std::{collections::VecDeque, path::Path, sync::Arc};
 use thiserror::Error;
 
 /// Errors that occur while loading assets with an `AssetServer`
@@ -23,12 +26,25 @@ pub enum AssetServerError {
     MissingAssetLoader { extensions: Vec<String> },
     #[error("the given type does not match the type of the loaded asset")]
     IncorrectHandleType,
+    #[error("the asset loader did not produce an asset for: {0:?}")]
+    MissingLabeledAsset(AssetPath<'static>),
     #[error("encountered an error while loading an asset: {0}")]
     AssetLoaderError(anyhow::Error),
     #[error("encountered an error while reading an asset: {0}")]
     AssetIoError(#[from] AssetIoError),
 }
 
+/// An event that is sent when the [`AssetServer`] fails to load an asset.
+#[derive(Debug)]
+pub struct AssetLoadFailedEvent {
+    /// The path of the asset that failed to load.
+    pub path: AssetPath<'static>,
+    /// The reason the load failed.
+    pub error: Arc<AssetServerError>,
+    /// The assets that depend on the failed asset, directly or transitively, nearest first.
+    pub dependency_chain: Vec<AssetPath<'static>>,
+}
+
 fn format_missing_asset_ext(exts: &[String]) -> String {
     if !exts.is_empty() {
         format!(
@@ -48,6 +64,82 @@ pub(crate) struct AssetRefCounter {
     pub(crate) mark_unused_assets: Arc<Mutex<Vec<HandleId>>>,
 }
 
+/// Tracks the assets each asset source depends on, and the reverse.
+#[derive(Default)]
+pub(crate) struct AssetDependencyGraph {
+    dependencies: HashMap<SourcePathId, Vec<AssetPath<'static>>>,
+    dependents: HashMap<SourcePathId, Vec<AssetPath<'static>>>,
+}
+
+impl AssetDependencyGraph {
+    fn set_dependencies(&mut self, source: &AssetPath, dependencies: Vec<AssetPath<'static>>) {
+        let source_id = source.get_id().source_path_id();
+        for dependency in self.dependencies.remove(&source_id).unwrap_or_default() {
+            if let Some(dependents) = self
+                .dependents
+                .get_mut(&dependency.get_id().source_path_id())
+            {
+                dependents.retain(|dependent| dependent.get_id().source_path_id() != source_id);
+            }
+        }
+
+        let source = AssetPath::new(source.path().to_owned(), None);
+        for dependency in &dependencies {
+            let dependents = self
+                .dependents
+                .entry(dependency.get_id().source_path_id())
+                .or_default();
+            if !dependents
+                .iter()
+                .any(|dependent| dependent.get_id().source_path_id() == source_id)
+            {
+                dependents.push(source.clone());
+            }
+        }
+        self.dependencies.insert(source_id, dependencies);
+    }
+
+    /// Forgets the dependencies of a freed asset source, and its dependents if nothing depends
+    /// on it anymore, so the graph only tracks loaded assets.
+    fn remove_source(&mut self, source_id: SourcePathId) {
+        for dependency in self.dependencies.remove(&source_id).unwrap_or_default() {
+            let dependency_id = dependency.get_id().source_path_id();
+            if let Some(dependents) = self.dependents.get_mut(&dependency_id) {
+                dependents.retain(|dependent| dependent.get_id().source_path_id() != source_id);
+                if dependents.is_empty() {
+                    self.dependents.remove(&dependency_id);
+                }
+            }
+        }
+        if self
+            .dependents
+            .get(&source_id)
+            .map_or(false, |dependents| dependents.is_empty())
+        {
+            self.dependents.remove(&source_id);
+        }
+    }
+
+    /// Returns every asset source depending on `source_id`, directly or transitively, in
+    /// breadth-first order.
+    fn transitive_dependents(&self, source_id: SourcePathId) -> Vec<AssetPath<'static>> {
+        let mut visited = HashSet::default();
+        visited.insert(source_id);
+        let mut queue = VecDeque::from([source_id]);
+        let mut result = Vec::new();
+        while let Some(source_id) = queue.pop_front() {
+            for dependent in self.dependents.get(&source_id).into_iter().flatten() {
+                let dependent_id = dependent.get_id().source_path_id();
+                if visited.insert(dependent_id) {
+                    queue.push_back(dependent_id);
+                    result.push(dependent.clone());
+                }
+            }
+        }
+        result
+    }
+}
+
 pub struct AssetServerInternal {
     pub(crate) asset_io: Box<dyn AssetIo>,
     pub(crate) asset_ref_counter: AssetRefCounter,
@@ -56,6 +148,9 @@ pub struct AssetServerInternal {
     loaders: RwLock<Vec<Arc<dyn AssetLoader>>>,
     extension_to_loader_index: RwLock<HashMap<String, usize>>,
     handle_to_path: Arc<RwLock<HashMap<HandleId, AssetPath<'static>>>>,
+    dependency_graph: RwLock<AssetDependencyGraph>,
+    load_failed_sender: Sender<AssetLoadFailedEvent>,
+    load_failed_receiver: Receiver<AssetLoadFailedEvent>,
 }
 
 /// Loads assets from the filesystem on background threads
@@ -70,6 +165,7 @@ impl AssetServer {
     }
 
     pub fn with_boxed_io(asset_io: Box<dyn AssetIo>) -> Self {
+        let (load_failed_sender, load_failed_receiver) = crossbeam_channel::unbounded();
         AssetServer {
             server: Arc::new(AssetServerInternal {
                 loaders: Default::default(),
@@ -78,6 +174,9 @@ impl AssetServer {
                 asset_ref_counter: Default::default(),
                 handle_to_path: Default::default(),
                 asset_lifecycles: Default::default(),
+                dependency_graph: Default::default(),
+                load_failed_sender,
+                load_failed_receiver,
                 asset_io,
             }),
         }
@@ -200,23 +299,108 @@ impl AssetServer {
     }
 
     pub fn get_group_load_state(&self, handles: impl IntoIterator<Item = HandleId>) -> LoadState {
-        let mut load_state = LoadState::Loaded;
-        for handle_id in handles {
-            match handle_id {
-                HandleId::AssetPathId(id) => match self.get_load_state(id) {
-                    LoadState::Loaded => continue,
-                    LoadState::Loading => {
-                        load_state = LoadState::Loading;
-                    }
-                    LoadState::Failed => return LoadState::Failed,
-                    LoadState::NotLoaded => return LoadState::NotLoaded,
-                    LoadState::Unloaded => return LoadState::Unloaded,
-                },
-                HandleId::Id(_, _) => return LoadState::NotLoaded,
-            }
+        combine_load_states(handles.into_iter().map(|handle_id| match handle_id {
+            HandleId::AssetPathId(id) => self.get_load_state(id),
+            HandleId::Id(_, _) => LoadState::NotLoaded,
+        }))
+    }
+
+    /// Returns the combined [`LoadState`] of an asset and all of its dependencies, recursively.
+    ///
+    /// Unlike [`AssetServer::get_load_state`], this only returns [`LoadState::Loaded`] once every
+    /// dependency declared with [`LoadedAsset::with_dependency`](crate::LoadedAsset::with_dependency)
+    /// has been loaded as well.
+    pub fn get_recursive_load_state<H: Into<HandleId>>(&self, handle: H) -> LoadState {
+        self.get_group_recursive_load_state(std::iter::once(handle.into()))
+    }
+
+    /// Returns the combined [`LoadState`] of a group of assets and all of their dependencies,
+    /// recursively.
+    pub fn get_group_recursive_load_state(
+        &self,
+        handles: impl IntoIterator<Item = HandleId>,
+    ) -> LoadState {
+        let mut visited = HashSet::default();
+        combine_load_states(
+            handles
+                .into_iter()
+                .map(|handle_id| self.recursive_load_state(handle_id, &mut visited)),
+        )
+    }
+
+    /// Returns the paths of the assets the given asset depends on, as of its last successful load.
+    ///
+    /// This includes both dependencies declared with
+    /// [`LoadedAsset::with_dependency`](crate::LoadedAsset::with_dependency) and assets loaded
+    /// with [`LoadContext::load_direct`].
+    pub fn dependencies<H: Into<HandleId>>(&self, handle: H) -> Vec<AssetPath<'static>> {
+        match handle.into() {
+            HandleId::AssetPathId(id) => self
+                .server
+                .dependency_graph
+                .read()
+                .dependencies
+                .get(&id.source_path_id())
+                .cloned()
+                .unwrap_or_default(),
+            HandleId::Id(_, _) => Vec::new(),
         }
+    }
 
-        load_state
+    /// Returns the paths of the assets that directly depend on the given asset.
+    pub fn dependents<H: Into<HandleId>>(&self, handle: H) -> Vec<AssetPath<'static>> {
+        match handle.into() {
+            HandleId::AssetPathId(id) => self
+                .server
+                .dependency_graph
+                .read()
+                .dependents
+                .get(&id.source_path_id())
+                .cloned()
+                .unwrap_or_default(),
+            HandleId::Id(_, _) => Vec::new(),
+        }
+    }
+
+    fn recursive_load_state(
+        &self,
+        handle_id: HandleId,
+        visited: &mut HashSet<SourcePathId>,
+    ) -> LoadState {
+        let id = match handle_id {
+            HandleId::AssetPathId(id) => id,
+            HandleId::Id(_, _) => return LoadState::NotLoaded,
+        };
+        // assets that are already being visited are accounted for further up the stack
+        if !visited.insert(id.source_path_id()) {
+            return LoadState::Loaded;
+        }
+
+        let load_state = self.get_load_state(id);
+        if load_state != LoadState::Loaded {
+            return load_state;
+        }
+
+        // collect dependencies in a scope to release the lock before recursing
+        let dependencies = {
+            let asset_sources = self.server.asset_sources.read();
+            asset_sources
+                .get(&id.source_path_id())
+                .and_then(|source_info| source_info.meta.as_ref())
+                .map(|meta| {
+                    meta.assets
+                        .iter()
+                        .flat_map(|asset| asset.dependencies.iter())
+                        .map(|dependency| HandleId::from(dependency.get_id()))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+        combine_load_states(
+            dependencies
+                .into_iter()
+                .map(|dependency| self.recursive_load_state(dependency, visited)),
+        )
     }
 
     /// Queue an [`Asset`] at the provided relative path for asynchronous loading.
@@ -241,10 +425,25 @@ impl AssetServer {
         self.load_untyped(path).typed()
     }
 
+    /// Loads an asset, sending an [`AssetLoadFailedEvent`] if it fails.
     async fn load_async(
         &self,
         asset_path: AssetPath<'_>,
         force: bool,
+    ) -> Result<AssetPathId, Arc<AssetServerError>> {
+        self.load_async_inner(asset_path.clone(), force)
+            .await
+            .map_err(|error| {
+                let error = Arc::new(error);
+                self.send_load_failed_event(asset_path.to_owned(), error.clone());
+                error
+            })
+    }
+
+    async fn load_async_inner(
+        &self,
+        asset_path: AssetPath<'_>,
+        force: bool,
     ) -> Result<AssetPathId, AssetServerError> {
         let asset_path_id: AssetPathId = asset_path.get_id();
 
@@ -312,6 +511,7 @@ impl AssetServer {
             asset_path.path(),
             &self.server.asset_ref_counter.channel,
             self.asset_io(),
+            self,
             version,
         );
 
@@ -347,6 +547,10 @@ impl AssetServer {
         source_info.meta = Some(SourceMeta {
             assets: load_context.get_asset_metas(),
         });
+        self.server
+            .dependency_graph
+            .write()
+            .set_dependencies(&asset_path, load_context.dependencies());
 
         // load asset dependencies and prepare asset type hashmap
         for (label, loaded_asset) in &mut load_context.labeled_assets {
@@ -365,18 +569,49 @@ impl AssetServer {
         Ok(asset_path_id)
     }
 
+    /// Runs the [`AssetLoader`] for `path` without tracking the result, returning every asset it
+    /// produced by label. Used by [`LoadContext::load_direct`].
+    pub(crate) async fn load_direct_labeled_assets(
+        &self,
+        path: &Path,
+    ) -> Result<HashMap<Option<String>, BoxedLoadedAsset>, AssetServerError> {
+        let asset_loader = self.get_path_asset_loader(path)?;
+        let bytes = self.asset_io().load_path(path).await?;
+        let mut load_context = LoadContext::new(
+            path,
+            &self.server.asset_ref_counter.channel,
+            self.asset_io(),
+            self,
+            0,
+        );
+        asset_loader
+            .load(&bytes, &mut load_context)
+            .await
+            .map_err(AssetServerError::AssetLoaderError)?;
+        Ok(load_context.labeled_assets)
+    }
+
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load_untyped<'a, P: Into<AssetPath<'a>>>(&self, path: P) -> HandleUntyped {
         let handle_id = self.load_untracked(path.into(), false);
         self.get_handle_untyped(handle_id)
     }
 
-    /// Force an [`Asset`] to be reloaded.
+    /// Force an [`Asset`] to be reloaded, along with every asset that depends on it.
     ///
     /// This is useful for custom hot-reloading or for supporting `watch_for_changes`
     /// in custom [`AssetIo`] implementations.
     pub fn reload_asset<'a, P: Into<AssetPath<'a>>>(&self, path: P) {
-        self.load_untracked(path.into(), true);
+        let asset_path = path.into();
+        let dependents = self
+            .server
+            .dependency_graph
+            .read()
+            .transitive_dependents(asset_path.get_id().source_path_id());
+        self.load_untracked(asset_path, true);
+        for dependent in dependents {
+            self.load_untracked(dependent, true);
+        }
     }
 
     pub(crate) fn load_untracked(&self, asset_path: AssetPath<'_>, force: bool) -> HandleId {
@@ -400,6 +635,20 @@ impl AssetServer {
         asset_path.into()
     }
 
+    fn send_load_failed_event(&self, path: AssetPath<'static>, error: Arc<AssetServerError>) {
+        let dependency_chain = self
+            .server
+            .dependency_graph
+            .read()
+            .transitive_dependents(path.get_id().source_path_id());
+        // the receiver lives as long as the server, so this cannot fail
+        let _ = self.server.load_failed_sender.send(AssetLoadFailedEvent {
+            path,
+            error,
+            dependency_chain,
+        });
+    }
+
     #[must_use = "not using the returned strong handles may result in the unexpected release of the assets"]
     pub fn load_folder<P: AsRef<Path>>(
         &self,
@@ -541,6 +790,12 @@ impl AssetServer {
                         if let Some(source_info) = asset_sources.get_mut(&id.source_path_id()) {
                             source_info.committed_assets.remove(&id.label_id());
                             source_info.load_state = LoadState::Unloaded;
+                            if source_info.committed_assets.is_empty() {
+                                self.server
+                                    .dependency_graph
+                                    .write()
+                                    .remove_source(id.source_path_id());
+                            }
                         }
                     }
                     assets.remove(handle_id);
@@ -554,6 +809,20 @@ impl AssetServer {
     }
 }
 
+/// Combines load states the same way for single assets and groups: any failed, unloaded or
+/// not loaded asset takes precedence, then any asset that is still loading.
+fn combine_load_states(load_states: impl IntoIterator<Item = LoadState>) -> LoadState {
+    let mut load_state = LoadState::Loaded;
+    for state in load_states {
+        match state {
+            LoadState::Loaded => continue,
+            LoadState::Loading => load_state = LoadState::Loading,
+            LoadState::Failed | LoadState::NotLoaded | LoadState::Unloaded => return state,
+        }
+    }
+    load_state
+}
+
 fn free_unused_assets_system_impl(asset_server: &AssetServer) {
     asset_server.free_unused_assets();
     asset_server.mark_unused_assets();
@@ -563,12 +832,20 @@ pub fn free_unused_assets_system(asset_server: Res<AssetServer>) {
     free_unused_assets_system_impl(&asset_server);
 }
 
+/// Sends an [`AssetLoadFailedEvent`] for every load the [`AssetServer`] failed since last run.
+pub fn asset_load_failed_event_system(
+    asset_server: Res<AssetServer>,
+    mut events: EventWriter<AssetLoadFailedEvent>,
+) {
+    events.send_batch(asset_server.server.load_failed_receiver.try_iter());
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::{loader::LoadedAsset, update_asset_storage_system};
     use bevy_app::App;
-    use bevy_ecs::prelude::*;
+    use bevy_ecs::{event::Events, prelude::*};
     use bevy_reflect::TypeUuid;
     use bevy_utils::BoxedFuture;
 
@@ -607,6 +884,47 @@ mod test {
         }
     }
 
+    #[derive(Debug, TypeUuid)]
+    #[uuid = "19e8d8bd-10b8-4bd9-8a7b-1b0e5c27bd1b"]
+    struct ParentAsset;
+
+    /// Declares `fake.png` as a regular dependency.
+    struct FakeParentLoader;
+    impl AssetLoader for FakeParentLoader {
+        fn load<'a>(
+            &'a self,
+            _: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            ctx.set_default_asset(LoadedAsset::new(ParentAsset).with_dependency("fake.png".into()));
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["parent"]
+        }
+    }
+
+    /// Loads `fake.png` in-place while loading.
+    struct FakeDirectLoader;
+    impl AssetLoader for FakeDirectLoader {
+        fn load<'a>(
+            &'a self,
+            _: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let PngAsset = ctx.load_direct::<PngAsset, _>("fake.png").await?;
+                ctx.set_default_asset(LoadedAsset::new(ParentAsset));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["direct"]
+        }
+    }
+
     struct FakeMultipleDotLoader;
     impl AssetLoader for FakeMultipleDotLoader {
         fn load<'a>(
@@ -712,6 +1030,14 @@ mod test {
         asset_dir
     }
 
+    fn create_dir_and_files(files: &[&str]) -> tempfile::TempDir {
+        let asset_dir = tempfile::tempdir().unwrap();
+        for file in files {
+            std::fs::write(asset_dir.path().join(file), &[]).unwrap();
+        }
+        asset_dir
+    }
+
     #[test]
     fn test_missing_loader() {
         let dir = create_dir_and_file("file.not-a-real-extension");
@@ -722,9 +1048,9 @@ mod test {
 
         let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
             .unwrap_err();
-        assert!(match err {
+        assert!(match &*err {
             AssetServerError::MissingAssetLoader { extensions } => {
-                extensions == ["not-a-real-extension"]
+                *extensions == ["not-a-real-extension"]
             }
             _ => false,
         });
@@ -742,7 +1068,7 @@ mod test {
 
         let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
             .unwrap_err();
-        assert!(matches!(err, AssetServerError::AssetIoError(_)));
+        assert!(matches!(*err, AssetServerError::AssetIoError(_)));
 
         assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
     }
@@ -758,7 +1084,7 @@ mod test {
 
         let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
             .unwrap_err();
-        assert!(matches!(err, AssetServerError::AssetLoaderError(_)));
+        assert!(matches!(*err, AssetServerError::AssetLoaderError(_)));
 
         assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
     }
@@ -869,4 +1195,197 @@ mod test {
         let invalid_path = AssetPath::new("some/path.ext".into(), None);
         assert!(server.get_handle_path(invalid_path).is_none());
     }
+
+    #[test]
+    fn test_load_direct() {
+        let dir = create_dir_and_files(&["fake.png", "fake.direct", "fake.parent"]);
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(FakePngLoader);
+        asset_server.add_loader(FakeDirectLoader);
+        let _png_assets = asset_server.register_asset_type::<PngAsset>();
+        let _parent_assets = asset_server.register_asset_type::<ParentAsset>();
+
+        let path: AssetPath = "fake.direct".into();
+        assert!(futures_lite::future::block_on(asset_server.load_async(path, true)).is_ok());
+
+        // the directly loaded asset is not tracked by the server
+        let png_path: AssetPath = "fake.png".into();
+        assert_eq!(
+            asset_server.get_load_state(png_path.get_id()),
+            LoadState::NotLoaded
+        );
+
+        // a type mismatch is reported to the calling loader
+        asset_server.add_loader(FakeParentLoader);
+        let mut load_context = LoadContext::new(
+            Path::new("fake.direct"),
+            &asset_server.server.asset_ref_counter.channel,
+            asset_server.asset_io(),
+            &asset_server,
+            0,
+        );
+        let err =
+            futures_lite::future::block_on(load_context.load_direct::<PngAsset, _>("fake.parent"))
+                .unwrap_err();
+        assert!(matches!(err, AssetServerError::IncorrectHandleType));
+    }
+
+    #[test]
+    fn test_recursive_load_state() {
+        let dir = create_dir_and_files(&["fake.png", "fake.parent"]);
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(FakePngLoader);
+        asset_server.add_loader(FakeParentLoader);
+
+        let mut app = App::new();
+        app.insert_resource(asset_server.register_asset_type::<PngAsset>());
+        app.insert_resource(asset_server.register_asset_type::<ParentAsset>());
+        app.insert_resource(asset_server.clone());
+        app.add_system(update_asset_storage_system::<PngAsset>);
+        app.add_system(update_asset_storage_system::<ParentAsset>);
+
+        let png_path: AssetPath = "fake.png".into();
+        let parent_path: AssetPath = "fake.parent".into();
+        futures_lite::future::block_on(asset_server.load_async(png_path.clone(), true)).unwrap();
+        futures_lite::future::block_on(asset_server.load_async(parent_path.clone(), true)).unwrap();
+
+        assert_eq!(
+            asset_server.get_recursive_load_state(parent_path.get_id()),
+            LoadState::Loading
+        );
+
+        app.update();
+        assert_eq!(
+            asset_server.get_load_state(parent_path.get_id()),
+            LoadState::Loaded
+        );
+        assert_eq!(
+            asset_server.get_recursive_load_state(parent_path.get_id()),
+            LoadState::Loaded
+        );
+        assert_eq!(
+            asset_server.get_group_recursive_load_state([
+                parent_path.get_id().into(),
+                png_path.get_id().into()
+            ]),
+            LoadState::Loaded
+        );
+    }
+
+    #[test]
+    fn test_dependency_graph() {
+        let dir = create_dir_and_files(&["fake.png", "fake.parent", "fake.direct"]);
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(FakePngLoader);
+        asset_server.add_loader(FakeParentLoader);
+        asset_server.add_loader(FakeDirectLoader);
+        let _png_assets = asset_server.register_asset_type::<PngAsset>();
+        let _parent_assets = asset_server.register_asset_type::<ParentAsset>();
+
+        let png_path: AssetPath = "fake.png".into();
+        let parent_path: AssetPath = "fake.parent".into();
+        let direct_path: AssetPath = "fake.direct".into();
+        for path in [&parent_path, &direct_path] {
+            futures_lite::future::block_on(asset_server.load_async(path.clone(), true)).unwrap();
+        }
+
+        let ids =
+            |paths: Vec<AssetPath>| paths.iter().map(AssetPath::get_id).collect::<HashSet<_>>();
+        assert_eq!(
+            ids(asset_server.dependencies(parent_path.get_id())),
+            HashSet::from_iter([png_path.get_id()])
+        );
+        assert_eq!(
+            ids(asset_server.dependencies(direct_path.get_id())),
+            HashSet::from_iter([png_path.get_id()])
+        );
+        assert_eq!(
+            ids(asset_server.dependents(png_path.get_id())),
+            HashSet::from_iter([parent_path.get_id(), direct_path.get_id()])
+        );
+        assert!(asset_server.dependents(parent_path.get_id()).is_empty());
+    }
+
+    #[test]
+    fn test_load_failed_event() {
+        let dir = create_dir_and_files(&["fake.png", "fake.direct"]);
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(FakePngLoader);
+        asset_server.add_loader(FakeDirectLoader);
+        let _png_assets = asset_server.register_asset_type::<PngAsset>();
+
+        let mut app = App::new();
+        app.insert_resource(asset_server.register_asset_type::<ParentAsset>());
+        app.insert_resource(asset_server.clone());
+        app.add_event::<AssetLoadFailedEvent>();
+        app.add_system(asset_load_failed_event_system);
+
+        // the direct loader depends on `fake.png`, which then disappears and fails to load
+        let direct_path: AssetPath = "fake.direct".into();
+        futures_lite::future::block_on(asset_server.load_async(direct_path.clone(), true)).unwrap();
+        std::fs::remove_file(dir.path().join("fake.png")).unwrap();
+        let png_path: AssetPath = "fake.png".into();
+        let err = futures_lite::future::block_on(asset_server.load_async(png_path.clone(), true))
+            .unwrap_err();
+        assert!(matches!(*err, AssetServerError::AssetIoError(_)));
+
+        app.update();
+        let events = app.world.resource::<Events<AssetLoadFailedEvent>>();
+        let failed = events
+            .get_reader()
+            .iter(events)
+            .map(|event| {
+                (
+                    event.path.get_id(),
+                    matches!(*event.error, AssetServerError::AssetIoError(_)),
+                    event
+                        .dependency_chain
+                        .iter()
+                        .map(AssetPath::get_id)
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            failed,
+            vec![(png_path.get_id(), true, vec![direct_path.get_id()])]
+        );
+    }
+
+    #[test]
+    fn test_dependency_graph_pruned_on_free() {
+        let dir = create_dir_and_files(&["fake.png", "fake.direct"]);
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(FakePngLoader);
+        asset_server.add_loader(FakeDirectLoader);
+        let _png_assets = asset_server.register_asset_type::<PngAsset>();
+
+        let mut app = App::new();
+        app.insert_resource(asset_server.register_asset_type::<ParentAsset>());
+        app.insert_resource(asset_server.clone());
+        app.add_system(free_unused_assets_system.before("update"));
+        app.add_system(update_asset_storage_system::<ParentAsset>.label("update"));
+
+        let direct_path: AssetPath = "fake.direct".into();
+        let png_path: AssetPath = "fake.png".into();
+        let id = futures_lite::future::block_on(asset_server.load_async(direct_path.clone(), true))
+            .unwrap();
+        let handle = asset_server.get_handle_untyped(id);
+        app.update();
+        assert_eq!(asset_server.dependents(png_path.get_id()).len(), 1);
+
+        // the first update marks the asset as unused, the second one frees it
+        drop(handle);
+        app.update();
+        app.update();
+        assert_eq!(
+            asset_server.get_load_state(direct_path.get_id()),
+            LoadState::Unloaded
+        );
+        assert!(asset_server.dependencies(direct_path.get_id()).is_empty());
+        assert!(asset_server.dependents(png_path.get_id()).is_empty());
+        let graph = asset_server.server.dependency_graph.read();
+        assert!(graph.dependencies.is_empty());
+        assert!(graph.dependents.is_empty());
+    }
 }
@@ -6,6 +6,7 @@ mod file_asset_io;
 mod wasm_asset_io;
 
 mod metadata;
+mod pack_asset_io;
 
 #[cfg(target_os = "android")]
 pub use android_asset_io::*;
@@ -15,6 +16,7 @@ pub use file_asset_io::*;
 pub use wasm_asset_io::*;
 
 pub use metadata::*;
+pub use pack_asset_io::*;
 
 use anyhow::Result;
 use bevy_utils::BoxedFuture;
@@ -0,0 +1,496 @@
+use crate::{AssetIo, AssetIoError, FileType, Metadata};
+use anyhow::Result;
+use bevy_utils::{BoxedFuture, HashMap};
+use parking_lot::Mutex;
+use std::{
+    collections::BTreeSet,
+    fs::File,
+    io::{self, BufReader, Read, Seek, SeekFrom, Write},
+    path::{Component, Path, PathBuf},
+};
+
+/// The file extension used for asset packs.
+pub const ASSET_PACK_EXTENSION: &str = "pack";
+
+const MAGIC: &[u8; 8] = b"BEVYPACK";
+const VERSION: u32 = 1;
+
+/// How the data of an entry in an asset pack is stored.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PackCompression {
+    /// The entry is stored as is.
+    None,
+    /// The entry is compressed with Zstandard. Reading it requires the `zstd` feature.
+    Zstd,
+}
+
+impl PackCompression {
+    fn to_u8(self) -> u8 {
+        match self {
+            PackCompression::None => 0,
+            PackCompression::Zstd => 1,
+        }
+    }
+
+    fn from_u8(value: u8) -> io::Result<Self> {
+        match value {
+            0 => Ok(PackCompression::None),
+            1 => Ok(PackCompression::Zstd),
+            _ => Err(invalid_data(format!("unknown compression {}", value))),
+        }
+    }
+}
+
+struct PackWriterEntry {
+    path: String,
+    compression: PackCompression,
+    uncompressed_size: u64,
+    data: Vec<u8>,
+}
+
+/// Builds an asset pack: a single indexed archive that can be read with [`PackAssetIo`].
+///
+/// Compressed entries must be compressed by the caller, see [`AssetPackWriter::add_compressed`].
+#[derive(Default)]
+pub struct AssetPackWriter {
+    entries: Vec<PackWriterEntry>,
+}
+
+impl AssetPackWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an uncompressed entry at `path`, relative to the asset folder.
+    pub fn add<P: AsRef<Path>>(&mut self, path: P, bytes: Vec<u8>) {
+        let uncompressed_size = bytes.len() as u64;
+        self.add_compressed(path, PackCompression::None, bytes, uncompressed_size);
+    }
+
+    /// Adds an entry at `path`, relative to the asset folder, whose `bytes` are already
+    /// compressed with `compression`.
+    pub fn add_compressed<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        compression: PackCompression,
+        bytes: Vec<u8>,
+        uncompressed_size: u64,
+    ) {
+        let path = normalize_path(path.as_ref());
+        self.entries.retain(|entry| entry.path != path);
+        self.entries.push(PackWriterEntry {
+            path,
+            compression,
+            uncompressed_size,
+            data: bytes,
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Writes the index followed by the data of every entry.
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let index_size: u64 = self
+            .entries
+            .iter()
+            .map(|entry| 4 + entry.path.len() as u64 + 1 + 8 * 3)
+            .sum();
+        let mut offset = MAGIC.len() as u64 + 4 + 4 + index_size;
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(self.entries.len() as u32).to_le_bytes())?;
+        for entry in &self.entries {
+            writer.write_all(&(entry.path.len() as u32).to_le_bytes())?;
+            writer.write_all(entry.path.as_bytes())?;
+            writer.write_all(&[entry.compression.to_u8()])?;
+            writer.write_all(&offset.to_le_bytes())?;
+            writer.write_all(&(entry.data.len() as u64).to_le_bytes())?;
+            writer.write_all(&entry.uncompressed_size.to_le_bytes())?;
+            offset += entry.data.len() as u64;
+        }
+        for entry in &self.entries {
+            writer.write_all(&entry.data)?;
+        }
+        writer.flush()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PackEntry {
+    compression: PackCompression,
+    offset: u64,
+    size: u64,
+    uncompressed_size: u64,
+}
+
+trait PackReader: Read + Seek + Send + 'static {}
+
+impl<T: Read + Seek + Send + 'static> PackReader for T {}
+
+/// An [`AssetIo`] implementation that reads assets from an asset pack built with
+/// [`AssetPackWriter`], instead of from individual files.
+///
+/// The index is read once when the pack is opened; entry data is read on demand. Asset packs do
+/// not support watching for changes.
+pub struct PackAssetIo {
+    entries: HashMap<String, PackEntry>,
+    directories: HashMap<String, BTreeSet<String>>,
+    reader: Mutex<Box<dyn PackReader>>,
+    len: u64,
+}
+
+impl PackAssetIo {
+    /// Opens the asset pack at `path`.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, AssetIoError> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| {
+            if e.kind() == io::ErrorKind::NotFound {
+                AssetIoError::NotFound(path.to_owned())
+            } else {
+                e.into()
+            }
+        })?;
+        Self::from_reader(BufReader::new(file))
+    }
+
+    /// Reads an asset pack from any seekable source, such as an in-memory buffer.
+    pub fn from_reader<R: Read + Seek + Send + 'static>(
+        mut reader: R,
+    ) -> Result<Self, AssetIoError> {
+        let len = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::Start(0))?;
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("not an asset pack".to_string()).into());
+        }
+        let version = read_u32(&mut reader)?;
+        if version != VERSION {
+            return Err(invalid_data(format!("unsupported asset pack version {}", version)).into());
+        }
+
+        let entry_count = read_u32(&mut reader)?;
+        let mut entries = HashMap::default();
+        let mut directories: HashMap<String, BTreeSet<String>> = HashMap::default();
+        directories.insert(String::new(), BTreeSet::new());
+        for _ in 0..entry_count {
+            let path_len = read_u32(&mut reader)? as u64;
+            // a corrupted index must not make us allocate more than the pack holds
+            if path_len > len - reader.stream_position()? {
+                return Err(
+                    invalid_data("entry path lies outside of the asset pack".to_string()).into(),
+                );
+            }
+            let mut path = vec![0; path_len as usize];
+            reader.read_exact(&mut path)?;
+            let path = String::from_utf8(path)
+                .map_err(|_| invalid_data("entry path is not valid UTF-8".to_string()))?;
+            let mut compression = [0];
+            reader.read_exact(&mut compression)?;
+            let entry = PackEntry {
+                compression: PackCompression::from_u8(compression[0])?,
+                offset: read_u64(&mut reader)?,
+                size: read_u64(&mut reader)?,
+                uncompressed_size: read_u64(&mut reader)?,
+            };
+
+            // register the entry with each of its ancestor directories
+            let mut child = path.as_str();
+            while let Some((parent, _)) = child.rsplit_once('/') {
+                directories
+                    .entry(parent.to_string())
+                    .or_default()
+                    .insert(child.to_string());
+                child = parent;
+            }
+            directories
+                .entry(String::new())
+                .or_default()
+                .insert(child.to_string());
+            entries.insert(path, entry);
+        }
+
+        Ok(PackAssetIo {
+            entries,
+            directories,
+            reader: Mutex::new(Box::new(reader)),
+            len,
+        })
+    }
+
+    fn read_entry(&self, path: &Path) -> Result<Vec<u8>, AssetIoError> {
+        let entry = *self
+            .entries
+            .get(&normalize_path(path))
+            .ok_or_else(|| AssetIoError::NotFound(path.to_owned()))?;
+        // a corrupted index must not make us allocate more than the pack holds
+        if !matches!(entry.offset.checked_add(entry.size), Some(end) if end <= self.len) {
+            return Err(
+                invalid_data(format!("entry {:?} lies outside of the asset pack", path)).into(),
+            );
+        }
+
+        let mut data = vec![0; entry.size as usize];
+        {
+            let mut reader = self.reader.lock();
+            reader.seek(SeekFrom::Start(entry.offset))?;
+            reader.read_exact(&mut data)?;
+        }
+
+        match entry.compression {
+            PackCompression::None => Ok(data),
+            PackCompression::Zstd => decompress_zstd(&data, entry.uncompressed_size),
+        }
+    }
+}
+
+/// The largest compression ratio the decompression buffer is preallocated for.
+#[cfg(feature = "zstd")]
+const MAX_ZSTD_RATIO: u64 = 32;
+
+#[cfg(feature = "zstd")]
+fn decompress_zstd(data: &[u8], uncompressed_size: u64) -> Result<Vec<u8>, AssetIoError> {
+    let mut cursor = io::Cursor::new(data);
+    let decoder = ruzstd::StreamingDecoder::new(&mut cursor).map_err(invalid_data)?;
+    // the uncompressed size comes from the index, so only trust it as far as the compression
+    // ratio is plausible, and never read past it
+    let capacity = uncompressed_size.min(data.len() as u64 * MAX_ZSTD_RATIO);
+    let mut decompressed = Vec::with_capacity(capacity as usize);
+    decoder
+        .take(uncompressed_size.saturating_add(1))
+        .read_to_end(&mut decompressed)?;
+    if decompressed.len() as u64 != uncompressed_size {
+        return Err(invalid_data(format!(
+            "entry decompressed to {} bytes instead of {}",
+            decompressed.len(),
+            uncompressed_size
+        ))
+        .into());
+    }
+    Ok(decompressed)
+}
+
+#[cfg(not(feature = "zstd"))]
+fn decompress_zstd(_data: &[u8], _uncompressed_size: u64) -> Result<Vec<u8>, AssetIoError> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "reading Zstandard compressed asset pack entries requires the `zstd` feature",
+    )
+    .into())
+}
+
+impl AssetIo for PackAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move { self.read_entry(path) })
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        let children = self
+            .directories
+            .get(&normalize_path(path))
+            .ok_or_else(|| AssetIoError::NotFound(path.to_owned()))?;
+        Ok(Box::new(
+            children
+                .iter()
+                .map(PathBuf::from)
+                .collect::<Vec<_>>()
+                .into_iter(),
+        ))
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        let path_key = normalize_path(path);
+        if self.entries.contains_key(&path_key) {
+            Ok(Metadata::new(FileType::File))
+        } else if self.directories.contains_key(&path_key) {
+            Ok(Metadata::new(FileType::Directory))
+        } else {
+            Err(AssetIoError::NotFound(path.to_owned()))
+        }
+    }
+
+    fn watch_path_for_changes(&self, _path: &Path) -> Result<(), AssetIoError> {
+        Ok(())
+    }
+
+    fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+        bevy_log::warn!("Watching for changes is not supported when loading from an asset pack");
+        Ok(())
+    }
+}
+
+/// Converts a path relative to the asset folder to the `/`-separated form stored in packs.
+fn normalize_path(path: &Path) -> String {
+    let mut normalized = String::new();
+    for component in path.components() {
+        if let Component::Normal(component) = component {
+            if !normalized.is_empty() {
+                normalized.push('/');
+            }
+            normalized.push_str(&component.to_string_lossy());
+        }
+    }
+    normalized
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn pack(writer: &AssetPackWriter) -> PackAssetIo {
+        let mut bytes = Vec::new();
+        writer.write(&mut bytes).unwrap();
+        PackAssetIo::from_reader(Cursor::new(bytes)).unwrap()
+    }
+
+    #[test]
+    fn load_entries() {
+        let mut writer = AssetPackWriter::new();
+        writer.add("textures/a.png", b"a".to_vec());
+        writer.add("models/b.gltf", b"bb".to_vec());
+        writer.add("c.ron", Vec::new());
+        let asset_io = pack(&writer);
+
+        let load = |path: &str| futures_lite::future::block_on(asset_io.load_path(Path::new(path)));
+        assert_eq!(load("textures/a.png").unwrap(), b"a");
+        assert_eq!(load("models/b.gltf").unwrap(), b"bb");
+        assert!(load("c.ron").unwrap().is_empty());
+        assert!(matches!(
+            load("missing.png"),
+            Err(AssetIoError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn read_directories() {
+        let mut writer = AssetPackWriter::new();
+        writer.add("textures/a.png", Vec::new());
+        writer.add("textures/ui/b.png", Vec::new());
+        writer.add("c.ron", Vec::new());
+        let asset_io = pack(&writer);
+
+        let read = |path: &str| {
+            asset_io
+                .read_directory(Path::new(path))
+                .unwrap()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            read(""),
+            vec![PathBuf::from("c.ron"), PathBuf::from("textures")]
+        );
+        assert_eq!(
+            read("textures"),
+            vec![
+                PathBuf::from("textures/a.png"),
+                PathBuf::from("textures/ui")
+            ]
+        );
+        assert!(asset_io.is_dir(Path::new("textures/ui")));
+        assert!(asset_io.is_file(Path::new("textures/ui/b.png")));
+        assert!(!asset_io.is_dir(Path::new("textures/ui/b.png")));
+        assert!(asset_io.read_directory(Path::new("missing")).is_err());
+    }
+
+    #[test]
+    fn invalid_pack() {
+        assert!(PackAssetIo::from_reader(Cursor::new(b"not a pack".to_vec())).is_err());
+    }
+
+    #[test]
+    fn entry_out_of_bounds() {
+        let mut writer = AssetPackWriter::new();
+        writer.add("a.png", b"a".to_vec());
+        let mut bytes = Vec::new();
+        writer.write(&mut bytes).unwrap();
+        // overwrite the size of the only entry, after the header, path and offset
+        let size_offset = MAGIC.len() + 4 + 4 + 4 + "a.png".len() + 1 + 8;
+        bytes[size_offset..size_offset + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        let asset_io = PackAssetIo::from_reader(Cursor::new(bytes)).unwrap();
+
+        let result = futures_lite::future::block_on(asset_io.load_path(Path::new("a.png")));
+        assert!(matches!(result, Err(AssetIoError::Io(_))));
+    }
+
+    #[test]
+    fn corrupt_path_length() {
+        let mut writer = AssetPackWriter::new();
+        writer.add("a.png", b"a".to_vec());
+        let mut bytes = Vec::new();
+        writer.write(&mut bytes).unwrap();
+        // overwrite the path length of the only entry, after the header
+        let path_len_offset = MAGIC.len() + 4 + 4;
+        bytes[path_len_offset..path_len_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+
+        assert!(matches!(
+            PackAssetIo::from_reader(Cursor::new(bytes)),
+            Err(AssetIoError::Io(_))
+        ));
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn load_zstd_entry() {
+        // "hello asset pack" compressed with `zstd`
+        const COMPRESSED: &[u8] = &[
+            40, 181, 47, 253, 4, 104, 129, 0, 0, 104, 101, 108, 108, 111, 32, 97, 115, 115, 101,
+            116, 32, 112, 97, 99, 107, 240, 100, 253, 29,
+        ];
+        let mut writer = AssetPackWriter::new();
+        writer.add_compressed("hello.txt", PackCompression::Zstd, COMPRESSED.to_vec(), 16);
+        let asset_io = pack(&writer);
+
+        let bytes =
+            futures_lite::future::block_on(asset_io.load_path(Path::new("hello.txt"))).unwrap();
+        assert_eq!(bytes, b"hello asset pack");
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn corrupt_uncompressed_size() {
+        const COMPRESSED: &[u8] = &[
+            40, 181, 47, 253, 4, 104, 129, 0, 0, 104, 101, 108, 108, 111, 32, 97, 115, 115, 101,
+            116, 32, 112, 97, 99, 107, 240, 100, 253, 29,
+        ];
+        let mut writer = AssetPackWriter::new();
+        writer.add_compressed(
+            "huge.txt",
+            PackCompression::Zstd,
+            COMPRESSED.to_vec(),
+            u64::MAX,
+        );
+        writer.add_compressed("short.txt", PackCompression::Zstd, COMPRESSED.to_vec(), 4);
+        let asset_io = pack(&writer);
+
+        let load = |path: &str| futures_lite::future::block_on(asset_io.load_path(Path::new(path)));
+        assert!(matches!(load("huge.txt"), Err(AssetIoError::Io(_))));
+        assert!(matches!(load("short.txt"), Err(AssetIoError::Io(_))));
+    }
+}
@@ -61,6 +61,10 @@ impl Default for AssetServerSettings {
 
 /// Create an instance of the platform default `AssetIo`
 ///
+/// On desktop platforms, if the asset folder does not exist but an asset pack named after it
+/// does (for example `assets.pack`), a [`PackAssetIo`] reading from that pack is created instead
+/// of a [`FileAssetIo`].
+///
 /// This is useful when providing a custom `AssetIo` instance that needs to
 /// delegate to the default `AssetIo` for the platform.
 pub fn create_platform_default_asset_io(app: &mut App) -> Box<dyn AssetIo> {
@@ -68,6 +72,27 @@ pub fn create_platform_default_asset_io(app: &mut App) -> Box<dyn AssetIo> {
         .world
         .get_resource_or_insert_with(AssetServerSettings::default);
 
+    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+    {
+        // release builds can ship a single asset pack next to the executable instead of the
+        // asset folder
+        let root_path = FileAssetIo::get_root_path();
+        let pack_path = root_path.join(format!(
+            "{}.{}",
+            settings.asset_folder, ASSET_PACK_EXTENSION
+        ));
+        if !root_path.join(&settings.asset_folder).is_dir() && pack_path.is_file() {
+            match PackAssetIo::open(&pack_path) {
+                Ok(source) => return Box::new(source),
+                Err(err) => bevy_log::error!(
+                    "Failed to open asset pack {:?}, loading from the asset folder instead: {}",
+                    pack_path,
+                    err
+                ),
+            }
+        }
+    }
+
     #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
     let source = FileAssetIo::new(&settings.asset_folder, settings.watch_for_changes);
     #[cfg(target_arch = "wasm32")]
@@ -97,9 +122,14 @@ impl Plugin for AssetPlugin {
             SystemStage::parallel(),
         )
         .register_type::<HandleId>()
+        .add_event::<AssetLoadFailedEvent>()
         .add_system_to_stage(
             bevy_app::CoreStage::PreUpdate,
             asset_server::free_unused_assets_system,
+        )
+        .add_system_to_stage(
+            bevy_app::CoreStage::PreUpdate,
+            asset_server::asset_load_failed_event_system,
         );
 
         #[cfg(all(
@@ -1,6 +1,6 @@
 use crate::{
-    path::AssetPath, AssetIo, AssetIoError, AssetMeta, AssetServer, Assets, Handle, HandleId,
-    RefChangeChannel,
+    path::AssetPath, AssetIo, AssetIoError, AssetMeta, AssetServer, AssetServerError, Assets,
+    Handle, HandleId, RefChangeChannel,
 };
 use anyhow::Result;
 use bevy_ecs::system::{Res, ResMut};
@@ -80,7 +80,9 @@ impl<T: Asset> From<LoadedAsset<T>> for BoxedLoadedAsset {
 pub struct LoadContext<'a> {
     pub(crate) ref_change_channel: &'a RefChangeChannel,
     pub(crate) asset_io: &'a dyn AssetIo,
+    pub(crate) asset_server: &'a AssetServer,
     pub(crate) labeled_assets: HashMap<Option<String>, BoxedLoadedAsset>,
+    pub(crate) direct_dependencies: Vec<AssetPath<'static>>,
     pub(crate) path: &'a Path,
     pub(crate) version: usize,
 }
@@ -90,12 +92,15 @@ impl<'a> LoadContext<'a> {
         path: &'a Path,
         ref_change_channel: &'a RefChangeChannel,
         asset_io: &'a dyn AssetIo,
+        asset_server: &'a AssetServer,
         version: usize,
     ) -> Self {
         Self {
             ref_change_channel,
             asset_io,
+            asset_server,
             labeled_assets: Default::default(),
+            direct_dependencies: Vec::new(),
             version,
             path,
         }
@@ -128,6 +133,34 @@ impl<'a> LoadContext<'a> {
         self.asset_io.load_path(path.as_ref()).await
     }
 
+    /// Loads the asset at `path` in-place with its [`AssetLoader`] and returns its value.
+    ///
+    /// Unlike dependencies declared with [`LoadedAsset::with_dependency`], the returned asset can
+    /// be inspected while the current asset is being loaded. It is not added to any [`Assets`]
+    /// collection, and any other labeled assets produced by its loader are discarded.
+    ///
+    /// The asset is still recorded as a dependency of the asset being loaded, so that changes to
+    /// it trigger a reload of this asset.
+    pub async fn load_direct<'p, T: Asset, P: Into<AssetPath<'p>>>(
+        &mut self,
+        path: P,
+    ) -> Result<T, AssetServerError> {
+        let asset_path: AssetPath = path.into();
+        self.direct_dependencies.push(asset_path.to_owned());
+        let mut labeled_assets = self
+            .asset_server
+            .load_direct_labeled_assets(asset_path.path())
+            .await?;
+        let asset = labeled_assets
+            .remove(&asset_path.label().map(ToString::to_string))
+            .and_then(|asset| asset.value)
+            .ok_or_else(|| AssetServerError::MissingLabeledAsset(asset_path.to_owned()))?;
+        asset
+            .downcast::<T>()
+            .map(|asset| *asset)
+            .map_err(|_| AssetServerError::IncorrectHandleType)
+    }
+
     pub fn get_asset_metas(&self) -> Vec<AssetMeta> {
         let mut asset_metas = Vec::new();
         for (label, asset) in &self.labeled_assets {
@@ -143,6 +176,23 @@ impl<'a> LoadContext<'a> {
     pub fn asset_io(&self) -> &dyn AssetIo {
         self.asset_io
     }
+
+    /// Returns the paths of all assets the asset being loaded depends on, both declared with
+    /// [`LoadedAsset::with_dependency`] and loaded with [`LoadContext::load_direct`].
+    pub(crate) fn dependencies(&self) -> Vec<AssetPath<'static>> {
+        let mut dependencies: Vec<AssetPath<'static>> = Vec::new();
+        let labeled_dependencies = self
+            .labeled_assets
+            .values()
+            .flat_map(|asset| asset.dependencies.iter());
+        for dependency in labeled_dependencies.chain(self.direct_dependencies.iter()) {
+            let id = dependency.get_id();
+            if !dependencies.iter().any(|existing| existing.get_id() == id) {
+                dependencies.push(dependency.clone());
+            }
+        }
+        dependencies
+    }
 }
 
 /// The result of loading an asset of type `T`
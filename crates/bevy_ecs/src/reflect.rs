@@ -4,6 +4,7 @@ pub use crate::change_detection::ReflectMut;
 use crate::{
     component::Component,
     entity::{Entity, EntityMap, MapEntities, MapEntitiesError},
+    system::Resource,
     world::{FromWorld, World},
 };
 use bevy_reflect::{
@@ -123,15 +124,89 @@ impl<C: Component + Reflect + FromWorld> FromType<C> for ReflectComponent {
     }
 }
 
+/// A struct used to operate on reflected [`Resource`]s of a type.
+///
+/// A [`ReflectResource`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`].
+#[derive(Clone)]
+pub struct ReflectResource {
+    insert_resource: fn(&mut World, &dyn Reflect),
+    apply_resource: fn(&mut World, &dyn Reflect),
+    remove_resource: fn(&mut World),
+    reflect_resource: fn(&World) -> Option<&dyn Reflect>,
+    copy_resource: fn(&World, &mut World),
+}
+
+impl ReflectResource {
+    /// Insert a reflected resource, built from its [`FromWorld`] value with `resource` applied.
+    pub fn insert_resource(&self, world: &mut World, resource: &dyn Reflect) {
+        (self.insert_resource)(world, resource);
+    }
+
+    /// Apply `resource` to the existing resource of this type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no resource of this type in the world.
+    pub fn apply_resource(&self, world: &mut World, resource: &dyn Reflect) {
+        (self.apply_resource)(world, resource);
+    }
+
+    pub fn remove_resource(&self, world: &mut World) {
+        (self.remove_resource)(world);
+    }
+
+    pub fn reflect_resource<'a>(&self, world: &'a World) -> Option<&'a dyn Reflect> {
+        (self.reflect_resource)(world)
+    }
+
+    /// Copy the resource of this type from `source_world` into `destination_world`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no resource of this type in `source_world`.
+    pub fn copy_resource(&self, source_world: &World, destination_world: &mut World) {
+        (self.copy_resource)(source_world, destination_world);
+    }
+}
+
+impl<R: Resource + Reflect + FromWorld> FromType<R> for ReflectResource {
+    fn from_type() -> Self {
+        ReflectResource {
+            insert_resource: |world, reflected_resource| {
+                let mut resource = R::from_world(world);
+                resource.apply(reflected_resource);
+                world.insert_resource(resource);
+            },
+            apply_resource: |world, reflected_resource| {
+                let mut resource = world.resource_mut::<R>();
+                resource.apply(reflected_resource);
+            },
+            remove_resource: |world| {
+                world.remove_resource::<R>();
+            },
+            reflect_resource: |world| world.get_resource::<R>().map(|res| res as &dyn Reflect),
+            copy_resource: |source_world, destination_world| {
+                let source_resource = source_world.resource::<R>();
+                let mut destination_resource = R::from_world(destination_world);
+                destination_resource.apply(source_resource);
+                destination_world.insert_resource(destination_resource);
+            },
+        }
+    }
+}
+
 impl_reflect_value!(Entity(Hash, PartialEq, Serialize, Deserialize));
 impl_from_reflect_value!(Entity);
 
 #[derive(Clone)]
 pub struct ReflectMapEntities {
     map_entities: fn(&mut World, &EntityMap) -> Result<(), MapEntitiesError>,
+    map_specific_entities: fn(&mut World, &EntityMap, &[Entity]) -> Result<(), MapEntitiesError>,
 }
 
 impl ReflectMapEntities {
+    /// Maps the component on every entity that is a value of `entity_map`.
     pub fn map_entities(
         &self,
         world: &mut World,
@@ -139,6 +214,16 @@ impl ReflectMapEntities {
     ) -> Result<(), MapEntitiesError> {
         (self.map_entities)(world, entity_map)
     }
+
+    /// Maps the component on the given entities only.
+    pub fn map_specific_entities(
+        &self,
+        world: &mut World,
+        entity_map: &EntityMap,
+        entities: &[Entity],
+    ) -> Result<(), MapEntitiesError> {
+        (self.map_specific_entities)(world, entity_map, entities)
+    }
 }
 
 impl<C: Component + MapEntities> FromType<C> for ReflectMapEntities {
@@ -152,6 +237,14 @@ impl<C: Component + MapEntities> FromType<C> for ReflectMapEntities {
                 }
                 Ok(())
             },
+            map_specific_entities: |world, entity_map, entities| {
+                for &entity in entities {
+                    if let Some(mut component) = world.get_mut::<C>(entity) {
+                        component.map_entities(entity_map)?;
+                    }
+                }
+                Ok(())
+            },
         }
     }
 }
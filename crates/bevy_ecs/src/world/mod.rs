@@ -1297,6 +1297,29 @@ impl World {
         })
     }
 
+    /// Makes the current thread the only one allowed to access the non-send resources of this
+    /// [`World`], instead of the thread the world was created on.
+    ///
+    /// This allows a world to be owned by another thread, as when a sub app is updated on its
+    /// own thread. Non-send resources inserted afterwards must then be dropped on that thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the world contains non-send resources, as they belong to the previous thread.
+    pub fn bind_to_current_thread(&mut self) {
+        let resource_archetype = self.archetypes.resource();
+        for (component_id, column) in resource_archetype.unique_components().iter() {
+            let info = self.components.get_info(*component_id).unwrap();
+            if !info.is_send_and_sync() && !column.is_empty() {
+                panic!(
+                    "attempted to bind a world holding the NonSend resource {} to another thread",
+                    info.name()
+                );
+            }
+        }
+        self.main_thread_validator = MainThreadValidator::default();
+    }
+
     pub(crate) fn validate_non_send_access<T: 'static>(&self) {
         assert!(
             self.main_thread_validator.is_main_thread(),
@@ -1762,4 +1785,33 @@ mod tests {
             world.insert_resource_by_id(invalid_component_id, ptr);
         });
     }
+
+    #[test]
+    fn bind_to_other_thread() {
+        let mut world = World::new();
+        world.insert_resource(0u32);
+        let mut world = std::thread::spawn(move || {
+            world.bind_to_current_thread();
+            world.insert_non_send_resource(std::rc::Rc::new(1u32));
+            assert_eq!(**world.non_send_resource::<std::rc::Rc<u32>>(), 1);
+            world.remove_non_send_resource::<std::rc::Rc<u32>>();
+            world
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(*world.resource::<u32>(), 0);
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            world.insert_non_send_resource(2u64);
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic = "attempted to bind a world holding the NonSend resource"]
+    fn bind_world_with_non_send_resources() {
+        let mut world = World::new();
+        world.insert_non_send_resource(1u32);
+        world.bind_to_current_thread();
+    }
 }
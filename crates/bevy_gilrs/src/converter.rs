@@ -1,44 +1,98 @@
-use bevy_input::gamepad::{Gamepad, GamepadAxisType, GamepadButtonType};
+use bevy_input::gamepad::{
+    Gamepad, GamepadAxisType, GamepadButtonType, GamepadInfo, GamepadMappingSource,
+};
 
 pub fn convert_gamepad_id(gamepad_id: gilrs::GamepadId) -> Gamepad {
     Gamepad::new(gamepad_id.into())
 }
 
-pub fn convert_button(button: gilrs::Button) -> Option<GamepadButtonType> {
+pub fn convert_gamepad_info(gamepad: &gilrs::Gamepad) -> GamepadInfo {
+    let (vendor_id, product_id) = match convert_uuid(gamepad.uuid()) {
+        Some((vendor_id, product_id)) => (Some(vendor_id), Some(product_id)),
+        None => (None, None),
+    };
+    GamepadInfo {
+        name: gamepad.name().to_string(),
+        vendor_id,
+        product_id,
+        mapping_source: match gamepad.mapping_source() {
+            gilrs::MappingSource::SdlMappings => GamepadMappingSource::Mappings,
+            gilrs::MappingSource::Driver => GamepadMappingSource::Driver,
+            gilrs::MappingSource::None => GamepadMappingSource::Unmapped,
+        },
+    }
+}
+
+/// Extracts the vendor and product ids from a gamepad UUID.
+///
+/// Gilrs builds the UUID the way SDL builds its GUIDs: the little-endian vendor and product ids
+/// are followed by zeroed bytes. Other UUIDs, such as the nil UUID of XInput gamepads, don't
+/// contain the ids.
+fn convert_uuid(uuid: [u8; 16]) -> Option<(u16, u16)> {
+    let vendor_id = u16::from_le_bytes([uuid[4], uuid[5]]);
+    let product_id = u16::from_le_bytes([uuid[8], uuid[9]]);
+    if vendor_id != 0 && uuid[6..8] == [0, 0] && uuid[10..12] == [0, 0] {
+        Some((vendor_id, product_id))
+    } else {
+        None
+    }
+}
+
+pub fn convert_button(button: gilrs::Button, code: gilrs::ev::Code) -> GamepadButtonType {
     match button {
-        gilrs::Button::South => Some(GamepadButtonType::South),
-        gilrs::Button::East => Some(GamepadButtonType::East),
-        gilrs::Button::North => Some(GamepadButtonType::North),
-        gilrs::Button::West => Some(GamepadButtonType::West),
-        gilrs::Button::C => Some(GamepadButtonType::C),
-        gilrs::Button::Z => Some(GamepadButtonType::Z),
-        gilrs::Button::LeftTrigger => Some(GamepadButtonType::LeftTrigger),
-        gilrs::Button::LeftTrigger2 => Some(GamepadButtonType::LeftTrigger2),
-        gilrs::Button::RightTrigger => Some(GamepadButtonType::RightTrigger),
-        gilrs::Button::RightTrigger2 => Some(GamepadButtonType::RightTrigger2),
-        gilrs::Button::Select => Some(GamepadButtonType::Select),
-        gilrs::Button::Start => Some(GamepadButtonType::Start),
-        gilrs::Button::Mode => Some(GamepadButtonType::Mode),
-        gilrs::Button::LeftThumb => Some(GamepadButtonType::LeftThumb),
-        gilrs::Button::RightThumb => Some(GamepadButtonType::RightThumb),
-        gilrs::Button::DPadUp => Some(GamepadButtonType::DPadUp),
-        gilrs::Button::DPadDown => Some(GamepadButtonType::DPadDown),
-        gilrs::Button::DPadLeft => Some(GamepadButtonType::DPadLeft),
-        gilrs::Button::DPadRight => Some(GamepadButtonType::DPadRight),
-        gilrs::Button::Unknown => None,
+        gilrs::Button::South => GamepadButtonType::South,
+        gilrs::Button::East => GamepadButtonType::East,
+        gilrs::Button::North => GamepadButtonType::North,
+        gilrs::Button::West => GamepadButtonType::West,
+        gilrs::Button::C => GamepadButtonType::C,
+        gilrs::Button::Z => GamepadButtonType::Z,
+        gilrs::Button::LeftTrigger => GamepadButtonType::LeftTrigger,
+        gilrs::Button::LeftTrigger2 => GamepadButtonType::LeftTrigger2,
+        gilrs::Button::RightTrigger => GamepadButtonType::RightTrigger,
+        gilrs::Button::RightTrigger2 => GamepadButtonType::RightTrigger2,
+        gilrs::Button::Select => GamepadButtonType::Select,
+        gilrs::Button::Start => GamepadButtonType::Start,
+        gilrs::Button::Mode => GamepadButtonType::Mode,
+        gilrs::Button::LeftThumb => GamepadButtonType::LeftThumb,
+        gilrs::Button::RightThumb => GamepadButtonType::RightThumb,
+        gilrs::Button::DPadUp => GamepadButtonType::DPadUp,
+        gilrs::Button::DPadDown => GamepadButtonType::DPadDown,
+        gilrs::Button::DPadLeft => GamepadButtonType::DPadLeft,
+        gilrs::Button::DPadRight => GamepadButtonType::DPadRight,
+        gilrs::Button::Unknown => GamepadButtonType::Other(code.into_u32()),
     }
 }
 
-pub fn convert_axis(axis: gilrs::Axis) -> Option<GamepadAxisType> {
+pub fn convert_axis(axis: gilrs::Axis, code: gilrs::ev::Code) -> GamepadAxisType {
     match axis {
-        gilrs::Axis::LeftStickX => Some(GamepadAxisType::LeftStickX),
-        gilrs::Axis::LeftStickY => Some(GamepadAxisType::LeftStickY),
-        gilrs::Axis::LeftZ => Some(GamepadAxisType::LeftZ),
-        gilrs::Axis::RightStickX => Some(GamepadAxisType::RightStickX),
-        gilrs::Axis::RightStickY => Some(GamepadAxisType::RightStickY),
-        gilrs::Axis::RightZ => Some(GamepadAxisType::RightZ),
-        gilrs::Axis::DPadX => Some(GamepadAxisType::DPadX),
-        gilrs::Axis::DPadY => Some(GamepadAxisType::DPadY),
-        gilrs::Axis::Unknown => None,
+        gilrs::Axis::LeftStickX => GamepadAxisType::LeftStickX,
+        gilrs::Axis::LeftStickY => GamepadAxisType::LeftStickY,
+        gilrs::Axis::LeftZ => GamepadAxisType::LeftZ,
+        gilrs::Axis::RightStickX => GamepadAxisType::RightStickX,
+        gilrs::Axis::RightStickY => GamepadAxisType::RightStickY,
+        gilrs::Axis::RightZ => GamepadAxisType::RightZ,
+        gilrs::Axis::DPadX => GamepadAxisType::DPadX,
+        gilrs::Axis::DPadY => GamepadAxisType::DPadY,
+        gilrs::Axis::Unknown => GamepadAxisType::Other(code.into_u32()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::convert_uuid;
+
+    #[test]
+    fn uuid_vendor_product() {
+        // Xbox One controller over USB, as created by gilrs on Linux
+        let uuid = [
+            0x03, 0x00, 0x00, 0x00, 0x5e, 0x04, 0x00, 0x00, 0xea, 0x02, 0x00, 0x00, 0x01, 0x03,
+            0x00, 0x00,
+        ];
+        assert_eq!(convert_uuid(uuid), Some((0x045e, 0x02ea)));
+        assert_eq!(convert_uuid([0; 16]), None);
+
+        let mut not_sdl = uuid;
+        not_sdl[6] = 0x12;
+        assert_eq!(convert_uuid(not_sdl), None);
     }
 }
@@ -1,14 +1,14 @@
-use crate::converter::{convert_axis, convert_button, convert_gamepad_id};
+use crate::converter::{convert_axis, convert_button, convert_gamepad_id, convert_gamepad_info};
 use bevy_ecs::event::EventWriter;
 use bevy_ecs::system::{NonSend, NonSendMut};
 use bevy_input::{gamepad::GamepadEventRaw, prelude::*};
 use gilrs::{EventType, Gilrs};
 
 pub fn gilrs_event_startup_system(gilrs: NonSend<Gilrs>, mut events: EventWriter<GamepadEventRaw>) {
-    for (id, _) in gilrs.gamepads() {
+    for (id, gamepad) in gilrs.gamepads() {
         events.send(GamepadEventRaw::new(
             convert_gamepad_id(id),
-            GamepadEventType::Connected,
+            GamepadEventType::Connected(convert_gamepad_info(&gamepad)),
         ));
     }
 }
@@ -17,9 +17,10 @@ pub fn gilrs_event_system(mut gilrs: NonSendMut<Gilrs>, mut events: EventWriter<
     while let Some(gilrs_event) = gilrs.next_event() {
         match gilrs_event.event {
             EventType::Connected => {
+                let gamepad = gilrs.gamepad(gilrs_event.id);
                 events.send(GamepadEventRaw::new(
                     convert_gamepad_id(gilrs_event.id),
-                    GamepadEventType::Connected,
+                    GamepadEventType::Connected(convert_gamepad_info(&gamepad)),
                 ));
             }
             EventType::Disconnected => {
@@ -28,21 +29,17 @@ pub fn gilrs_event_system(mut gilrs: NonSendMut<Gilrs>, mut events: EventWriter<
                     GamepadEventType::Disconnected,
                 ));
             }
-            EventType::ButtonChanged(gilrs_button, value, _) => {
-                if let Some(button_type) = convert_button(gilrs_button) {
-                    events.send(GamepadEventRaw::new(
-                        convert_gamepad_id(gilrs_event.id),
-                        GamepadEventType::ButtonChanged(button_type, value),
-                    ));
-                }
+            EventType::ButtonChanged(gilrs_button, value, code) => {
+                events.send(GamepadEventRaw::new(
+                    convert_gamepad_id(gilrs_event.id),
+                    GamepadEventType::ButtonChanged(convert_button(gilrs_button, code), value),
+                ));
             }
-            EventType::AxisChanged(gilrs_axis, value, _) => {
-                if let Some(axis_type) = convert_axis(gilrs_axis) {
-                    events.send(GamepadEventRaw::new(
-                        convert_gamepad_id(gilrs_event.id),
-                        GamepadEventType::AxisChanged(axis_type, value),
-                    ));
-                }
+            EventType::AxisChanged(gilrs_axis, value, code) => {
+                events.send(GamepadEventRaw::new(
+                    convert_gamepad_id(gilrs_event.id),
+                    GamepadEventType::AxisChanged(convert_axis(gilrs_axis, code), value),
+                ));
             }
             _ => (),
         };
@@ -1,12 +1,15 @@
 mod converter;
 mod gilrs_system;
+mod rumble;
 
-use bevy_app::{App, CoreStage, Plugin, StartupStage};
+use bevy_app::{App, CoreStage, Plugin, PluginDependency, StartupStage};
 use bevy_ecs::schedule::ParallelSystemDescriptorCoercion;
-use bevy_input::InputSystem;
+use bevy_input::{InputBackendSystem, InputPlugin, InputSystem};
+use bevy_time::TimePlugin;
 use bevy_utils::tracing::error;
-use gilrs::GilrsBuilder;
+use gilrs::{ff, GilrsBuilder};
 use gilrs_system::{gilrs_event_startup_system, gilrs_event_system};
+use rumble::{gilrs_rumble_system, RunningRumbleEffects};
 
 #[derive(Default)]
 pub struct GilrsPlugin;
@@ -26,10 +29,21 @@ impl Plugin for GilrsPlugin {
                     )
                     .add_system_to_stage(
                         CoreStage::PreUpdate,
-                        gilrs_event_system.before(InputSystem),
-                    );
+                        gilrs_event_system
+                            .label(InputBackendSystem)
+                            .before(InputSystem),
+                    )
+                    .init_non_send_resource::<RunningRumbleEffects<ff::Effect>>()
+                    .add_system_to_stage(CoreStage::PostUpdate, gilrs_rumble_system);
             }
             Err(err) => error!("Failed to start Gilrs. {}", err),
         }
     }
+
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        vec![
+            PluginDependency::required::<InputPlugin>(),
+            PluginDependency::required::<TimePlugin>(),
+        ]
+    }
 }
@@ -0,0 +1,314 @@
+//! Handle user specified [`GamepadRumbleRequest`]s.
+use crate::converter::convert_gamepad_id;
+use bevy_ecs::{
+    event::EventReader,
+    system::{NonSendMut, Res},
+};
+use bevy_input::gamepad::{Gamepad, GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy_time::Time;
+use bevy_utils::{tracing::warn, Duration, HashMap};
+use gilrs::{
+    ff::{self, BaseEffect, BaseEffectType, Repeat, Replay, Ticks},
+    Gilrs,
+};
+use thiserror::Error;
+
+/// An error that occurred while starting a rumble.
+#[derive(Error, Debug)]
+pub(crate) enum RumbleError {
+    #[error("gamepad {0:?} is not connected")]
+    GamepadNotFound(Gamepad),
+    #[error(transparent)]
+    Gilrs(#[from] ff::Error),
+}
+
+/// A force-feedback backend able to rumble gamepads.
+///
+/// Abstracts over [`Gilrs`] so that the lifetime of rumbles can be tested without hardware.
+pub(crate) trait RumbleBackend {
+    /// A handle to a playing rumble.
+    type Effect;
+
+    /// Starts rumbling `gamepad` for `duration`.
+    fn play(
+        &mut self,
+        gamepad: Gamepad,
+        intensity: GamepadRumbleIntensity,
+        duration: Duration,
+    ) -> Result<Self::Effect, RumbleError>;
+
+    /// Stops a playing rumble.
+    fn stop(&mut self, effect: Self::Effect);
+}
+
+struct RunningRumble<E> {
+    /// The time since startup at which the rumble should stop, or `None` if it lasts until it
+    /// is stopped.
+    deadline: Option<Duration>,
+    effect: E,
+}
+
+/// The rumbles currently playing on each gamepad.
+pub(crate) struct RunningRumbleEffects<E> {
+    rumbles: HashMap<Gamepad, Vec<RunningRumble<E>>>,
+}
+
+impl<E> Default for RunningRumbleEffects<E> {
+    fn default() -> Self {
+        Self {
+            rumbles: HashMap::default(),
+        }
+    }
+}
+
+impl<E> RunningRumbleEffects<E> {
+    /// Stops the rumbles whose duration elapsed, then handles the new `requests`.
+    ///
+    /// `now` is the time since startup.
+    pub(crate) fn update<'a, B: RumbleBackend<Effect = E>>(
+        &mut self,
+        backend: &mut B,
+        now: Duration,
+        requests: impl IntoIterator<Item = &'a GamepadRumbleRequest>,
+    ) {
+        for rumbles in self.rumbles.values_mut() {
+            let (expired, running) = std::mem::take(rumbles)
+                .into_iter()
+                .partition(|rumble| matches!(rumble.deadline, Some(deadline) if deadline <= now));
+            *rumbles = running;
+            for rumble in expired {
+                backend.stop(rumble.effect);
+            }
+        }
+        self.rumbles.retain(|_, rumbles| !rumbles.is_empty());
+
+        for request in requests {
+            match *request {
+                GamepadRumbleRequest::Add {
+                    gamepad,
+                    intensity,
+                    duration,
+                } => match backend.play(gamepad, intensity, duration) {
+                    Ok(effect) => self
+                        .rumbles
+                        .entry(gamepad)
+                        .or_default()
+                        .push(RunningRumble {
+                            deadline: now.checked_add(duration),
+                            effect,
+                        }),
+                    Err(err) => warn!("Failed to rumble gamepad {:?}: {}", gamepad, err),
+                },
+                GamepadRumbleRequest::Stop { gamepad } => {
+                    for rumble in self.rumbles.remove(&gamepad).into_iter().flatten() {
+                        backend.stop(rumble.effect);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn magnitude(intensity: f32) -> u16 {
+    (intensity.clamp(0.0, 1.0) * u16::MAX as f32) as u16
+}
+
+impl RumbleBackend for Gilrs {
+    type Effect = ff::Effect;
+
+    fn play(
+        &mut self,
+        gamepad: Gamepad,
+        intensity: GamepadRumbleIntensity,
+        duration: Duration,
+    ) -> Result<ff::Effect, RumbleError> {
+        let (id, _) = self
+            .gamepads()
+            .find(|(id, _)| convert_gamepad_id(*id) == gamepad)
+            .ok_or(RumbleError::GamepadNotFound(gamepad))?;
+
+        let millis = u32::try_from(duration.as_millis()).ok();
+        let play_for = Ticks::from_ms(millis.unwrap_or(u32::MAX));
+        // Rumbles too long for gilrs to time play until they are stopped
+        let repeat = millis.map_or(Repeat::Infinitely, |_| Repeat::For(play_for));
+        let scheduling = Replay {
+            play_for,
+            ..Default::default()
+        };
+        let effect = ff::EffectBuilder::new()
+            .add_effect(BaseEffect {
+                kind: BaseEffectType::Strong {
+                    magnitude: magnitude(intensity.strong_motor),
+                },
+                scheduling,
+                ..Default::default()
+            })
+            .add_effect(BaseEffect {
+                kind: BaseEffectType::Weak {
+                    magnitude: magnitude(intensity.weak_motor),
+                },
+                scheduling,
+                ..Default::default()
+            })
+            .repeat(repeat)
+            .gamepads(&[id])
+            .finish(self)?;
+        effect.play()?;
+        Ok(effect)
+    }
+
+    fn stop(&mut self, effect: ff::Effect) {
+        if let Err(err) = effect.stop() {
+            warn!("Failed to stop rumble: {}", err);
+        }
+    }
+}
+
+/// Plays the [`GamepadRumbleRequest`]s sent this frame and stops the rumbles that are over.
+pub(crate) fn gilrs_rumble_system(
+    time: Res<Time>,
+    mut gilrs: NonSendMut<Gilrs>,
+    mut running: NonSendMut<RunningRumbleEffects<ff::Effect>>,
+    mut requests: EventReader<GamepadRumbleRequest>,
+) {
+    running.update(&mut *gilrs, time.time_since_startup(), requests.iter());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RumbleBackend, RumbleError, RunningRumbleEffects};
+    use bevy_input::gamepad::{Gamepad, GamepadRumbleIntensity, GamepadRumbleRequest};
+    use bevy_utils::Duration;
+
+    /// A backend recording the rumbles it plays, identified by the order they were started in.
+    #[derive(Default)]
+    struct MockBackend {
+        connected: Vec<Gamepad>,
+        played: usize,
+        playing: Vec<usize>,
+    }
+
+    impl RumbleBackend for MockBackend {
+        type Effect = usize;
+
+        fn play(
+            &mut self,
+            gamepad: Gamepad,
+            _intensity: GamepadRumbleIntensity,
+            _duration: Duration,
+        ) -> Result<usize, RumbleError> {
+            if !self.connected.contains(&gamepad) {
+                return Err(RumbleError::GamepadNotFound(gamepad));
+            }
+            let effect = self.played;
+            self.played += 1;
+            self.playing.push(effect);
+            Ok(effect)
+        }
+
+        fn stop(&mut self, effect: usize) {
+            self.playing.retain(|playing| *playing != effect);
+        }
+    }
+
+    fn add(gamepad: Gamepad, millis: u64) -> GamepadRumbleRequest {
+        GamepadRumbleRequest::Add {
+            gamepad,
+            intensity: GamepadRumbleIntensity::MAX,
+            duration: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn rumble_expires() {
+        let gamepad = Gamepad::new(0);
+        let mut backend = MockBackend {
+            connected: vec![gamepad],
+            ..Default::default()
+        };
+        let mut running = RunningRumbleEffects::default();
+
+        running.update(
+            &mut backend,
+            Duration::ZERO,
+            &[add(gamepad, 100), add(gamepad, 300)],
+        );
+        assert_eq!(backend.playing, vec![0, 1]);
+
+        running.update(&mut backend, Duration::from_millis(50), &[]);
+        assert_eq!(backend.playing, vec![0, 1]);
+
+        running.update(&mut backend, Duration::from_millis(100), &[]);
+        assert_eq!(backend.playing, vec![1]);
+
+        running.update(&mut backend, Duration::from_millis(300), &[]);
+        assert!(backend.playing.is_empty());
+    }
+
+    #[test]
+    fn rumble_stop() {
+        let gamepad = Gamepad::new(0);
+        let other = Gamepad::new(1);
+        let mut backend = MockBackend {
+            connected: vec![gamepad, other],
+            ..Default::default()
+        };
+        let mut running = RunningRumbleEffects::default();
+
+        running.update(
+            &mut backend,
+            Duration::ZERO,
+            &[add(gamepad, 100), add(other, 100), add(gamepad, 100)],
+        );
+        assert_eq!(backend.playing, vec![0, 1, 2]);
+
+        // A stop only affects the rumbles started before it
+        running.update(
+            &mut backend,
+            Duration::from_millis(10),
+            &[GamepadRumbleRequest::Stop { gamepad }, add(gamepad, 100)],
+        );
+        assert_eq!(backend.playing, vec![1, 3]);
+
+        // The new rumble lasts for its own duration
+        running.update(&mut backend, Duration::from_millis(100), &[]);
+        assert_eq!(backend.playing, vec![3]);
+        running.update(&mut backend, Duration::from_millis(110), &[]);
+        assert!(backend.playing.is_empty());
+    }
+
+    #[test]
+    fn rumble_without_deadline() {
+        let gamepad = Gamepad::new(0);
+        let mut backend = MockBackend {
+            connected: vec![gamepad],
+            ..Default::default()
+        };
+        let mut running = RunningRumbleEffects::default();
+
+        let forever = GamepadRumbleRequest::Add {
+            gamepad,
+            intensity: GamepadRumbleIntensity::MAX,
+            duration: Duration::MAX,
+        };
+        running.update(&mut backend, Duration::from_secs(1), &[forever]);
+        running.update(&mut backend, Duration::from_secs(1_000_000), &[]);
+        assert_eq!(backend.playing, vec![0]);
+
+        running.update(
+            &mut backend,
+            Duration::from_secs(1_000_001),
+            &[GamepadRumbleRequest::Stop { gamepad }],
+        );
+        assert!(backend.playing.is_empty());
+    }
+
+    #[test]
+    fn rumble_disconnected_gamepad() {
+        let mut backend = MockBackend::default();
+        let mut running = RunningRumbleEffects::default();
+
+        running.update(&mut backend, Duration::ZERO, &[add(Gamepad::new(0), 100)]);
+        assert!(backend.playing.is_empty());
+    }
+}
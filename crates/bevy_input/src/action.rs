@@ -0,0 +1,754 @@
+use crate::{
+    gamepad::{
+        gamepad_connection_system, Gamepad, GamepadAxis, GamepadAxisType, GamepadButton,
+        GamepadButtonType, Gamepads,
+    },
+    keyboard::{KeyCode, PhysicalKey},
+    mouse::MouseButton,
+    Axis, Input, InputSystem,
+};
+use bevy_app::{App, CoreStage, Plugin};
+use bevy_ecs::{
+    component::Component,
+    schedule::ParallelSystemDescriptorCoercion,
+    system::{Query, Res, ResMut},
+};
+use bevy_math::Vec2;
+use bevy_utils::HashMap;
+use std::{hash::Hash, marker::PhantomData};
+
+/// A game action that inputs can be bound to with an [`InputMap`].
+///
+/// This is implemented for every type that can be used as an action, which is usually a
+/// fieldless enum like `enum PlayerAction { Jump, Move }`.
+pub trait InputAction: Copy + Eq + Hash + Send + Sync + 'static {}
+
+impl<T: Copy + Eq + Hash + Send + Sync + 'static> InputAction for T {}
+
+/// A button of any input device.
+///
+/// Gamepad buttons are given by type, the [`Gamepad`] they are read from is chosen by the
+/// [`InputMap`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputButton {
+    Keyboard(KeyCode),
+    /// A key at a given position on the keyboard, whatever the keyboard layout.
+    PhysicalKey(PhysicalKey),
+    Mouse(MouseButton),
+    Gamepad(GamepadButtonType),
+}
+
+impl From<KeyCode> for InputButton {
+    fn from(key_code: KeyCode) -> Self {
+        InputButton::Keyboard(key_code)
+    }
+}
+
+impl From<PhysicalKey> for InputButton {
+    fn from(key: PhysicalKey) -> Self {
+        InputButton::PhysicalKey(key)
+    }
+}
+
+impl From<MouseButton> for InputButton {
+    fn from(button: MouseButton) -> Self {
+        InputButton::Mouse(button)
+    }
+}
+
+impl From<GamepadButtonType> for InputButton {
+    fn from(button_type: GamepadButtonType) -> Self {
+        InputButton::Gamepad(button_type)
+    }
+}
+
+/// The inputs an action can be bound to in an [`InputMap`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputBinding {
+    /// Pressed while all the buttons are pressed, with a value of `1.0`.
+    ///
+    /// A single button is a chord of one button.
+    Chord(Vec<InputButton>),
+    /// A gamepad axis, pressed while its value is outside of the dead zone.
+    GamepadAxis {
+        axis_type: GamepadAxisType,
+        dead_zone: f32,
+    },
+    /// Two buttons acting as an axis, with a value of `-1.0` while only `negative` is pressed, and
+    /// of `1.0` while only `positive` is pressed.
+    VirtualAxis {
+        negative: InputButton,
+        positive: InputButton,
+    },
+    /// Two gamepad axes acting as an axis pair, such as a stick, pressed while its length is
+    /// outside of the dead zone.
+    DualAxis {
+        x: GamepadAxisType,
+        y: GamepadAxisType,
+        dead_zone: f32,
+    },
+    /// Four buttons acting as an axis pair with a length of `1.0` while any of them is pressed,
+    /// such as the arrow keys.
+    VirtualDpad {
+        up: InputButton,
+        down: InputButton,
+        left: InputButton,
+        right: InputButton,
+    },
+}
+
+impl InputBinding {
+    /// A chord of the given buttons.
+    pub fn chord(buttons: impl IntoIterator<Item = impl Into<InputButton>>) -> Self {
+        InputBinding::Chord(buttons.into_iter().map(Into::into).collect())
+    }
+
+    /// The left stick of a gamepad, with the default dead zone.
+    pub fn left_stick() -> Self {
+        InputBinding::DualAxis {
+            x: GamepadAxisType::LeftStickX,
+            y: GamepadAxisType::LeftStickY,
+            dead_zone: 0.1,
+        }
+    }
+
+    /// The right stick of a gamepad, with the default dead zone.
+    pub fn right_stick() -> Self {
+        InputBinding::DualAxis {
+            x: GamepadAxisType::RightStickX,
+            y: GamepadAxisType::RightStickY,
+            dead_zone: 0.1,
+        }
+    }
+
+    /// The arrow keys.
+    pub fn arrow_keys() -> Self {
+        InputBinding::VirtualDpad {
+            up: KeyCode::Up.into(),
+            down: KeyCode::Down.into(),
+            left: KeyCode::Left.into(),
+            right: KeyCode::Right.into(),
+        }
+    }
+
+    /// The W, A, S and D keys.
+    pub fn wasd() -> Self {
+        InputBinding::VirtualDpad {
+            up: KeyCode::W.into(),
+            down: KeyCode::S.into(),
+            left: KeyCode::A.into(),
+            right: KeyCode::D.into(),
+        }
+    }
+
+    /// The keys at the position of W, A, S and D on a QWERTY keyboard, such as Z, Q, S and D on
+    /// an AZERTY keyboard.
+    pub fn physical_wasd() -> Self {
+        InputBinding::VirtualDpad {
+            up: PhysicalKey::KeyW.into(),
+            down: PhysicalKey::KeyS.into(),
+            left: PhysicalKey::KeyA.into(),
+            right: PhysicalKey::KeyD.into(),
+        }
+    }
+
+    /// The D-pad of a gamepad.
+    pub fn gamepad_dpad() -> Self {
+        InputBinding::VirtualDpad {
+            up: GamepadButtonType::DPadUp.into(),
+            down: GamepadButtonType::DPadDown.into(),
+            left: GamepadButtonType::DPadLeft.into(),
+            right: GamepadButtonType::DPadRight.into(),
+        }
+    }
+
+    /// The value of the binding and, for bindings acting as an axis pair, the pair.
+    fn read(&self, inputs: &InputSources) -> (f32, Option<Vec2>) {
+        let button = |button: &InputButton| inputs.pressed(button) as u8 as f32;
+        match self {
+            InputBinding::Chord(buttons) => {
+                let pressed = !buttons.is_empty() && buttons.iter().all(|b| inputs.pressed(b));
+                (pressed as u8 as f32, None)
+            }
+            InputBinding::GamepadAxis {
+                axis_type,
+                dead_zone,
+            } => {
+                let value = inputs.axis(*axis_type);
+                if value.abs() > *dead_zone {
+                    (value, None)
+                } else {
+                    (0.0, None)
+                }
+            }
+            InputBinding::VirtualAxis { negative, positive } => {
+                (button(positive) - button(negative), None)
+            }
+            InputBinding::DualAxis { x, y, dead_zone } => {
+                let mut pair = Vec2::new(inputs.axis(*x), inputs.axis(*y));
+                if pair.length() <= *dead_zone {
+                    pair = Vec2::ZERO;
+                }
+                (pair.length(), Some(pair))
+            }
+            InputBinding::VirtualDpad {
+                up,
+                down,
+                left,
+                right,
+            } => {
+                let pair = Vec2::new(button(right) - button(left), button(up) - button(down))
+                    .normalize_or_zero();
+                (pair.length(), Some(pair))
+            }
+        }
+    }
+}
+
+impl From<InputButton> for InputBinding {
+    fn from(button: InputButton) -> Self {
+        InputBinding::Chord(vec![button])
+    }
+}
+
+impl From<KeyCode> for InputBinding {
+    fn from(key_code: KeyCode) -> Self {
+        InputButton::from(key_code).into()
+    }
+}
+
+impl From<PhysicalKey> for InputBinding {
+    fn from(key: PhysicalKey) -> Self {
+        InputButton::from(key).into()
+    }
+}
+
+impl From<MouseButton> for InputBinding {
+    fn from(button: MouseButton) -> Self {
+        InputButton::from(button).into()
+    }
+}
+
+impl From<GamepadButtonType> for InputBinding {
+    fn from(button_type: GamepadButtonType) -> Self {
+        InputButton::from(button_type).into()
+    }
+}
+
+/// Binds actions of type `A` to inputs.
+///
+/// An action can have several bindings, and is pressed while any of them is. This can be used
+/// as a resource, for a single player, or as a component along with an [`ActionState`] on each
+/// player entity, with a different [`Gamepad`] for each player.
+///
+/// Bindings can be changed at any time, and with the `serialize` feature, an [`InputMap`] can be
+/// saved and loaded.
+///
+/// ## Usage
+///
+/// ```
+/// # use bevy_input::{action::{InputBinding, InputMap}, gamepad::GamepadButtonType, keyboard::KeyCode};
+/// #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// enum PlayerAction {
+///     Jump,
+///     Move,
+/// }
+///
+/// let mut input_map = InputMap::default();
+/// input_map
+///     .insert(PlayerAction::Jump, KeyCode::Space)
+///     .insert(PlayerAction::Jump, GamepadButtonType::South)
+///     .insert(PlayerAction::Move, InputBinding::physical_wasd())
+///     .insert(PlayerAction::Move, InputBinding::left_stick());
+/// ```
+#[derive(Debug, Clone, PartialEq, Component)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputMap<A: InputAction> {
+    bindings: HashMap<A, Vec<InputBinding>>,
+    gamepad: Option<Gamepad>,
+}
+
+impl<A: InputAction> Default for InputMap<A> {
+    fn default() -> Self {
+        Self {
+            bindings: Default::default(),
+            gamepad: None,
+        }
+    }
+}
+
+impl<A: InputAction> InputMap<A> {
+    /// Adds a binding for `action`.
+    pub fn insert(&mut self, action: A, binding: impl Into<InputBinding>) -> &mut Self {
+        let binding = binding.into();
+        let bindings = self.bindings.entry(action).or_default();
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+        self
+    }
+
+    /// Removes a binding of `action`, returning `true` if it was bound.
+    pub fn remove(&mut self, action: A, binding: &InputBinding) -> bool {
+        match self.bindings.get_mut(&action) {
+            Some(bindings) => {
+                let len = bindings.len();
+                bindings.retain(|b| b != binding);
+                bindings.len() != len
+            }
+            None => false,
+        }
+    }
+
+    /// Removes all the bindings of `action`.
+    pub fn clear_action(&mut self, action: A) {
+        self.bindings.remove(&action);
+    }
+
+    /// Returns the bindings of `action`.
+    pub fn bindings(&self, action: A) -> &[InputBinding] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns an iterator over the actions with bindings.
+    pub fn actions(&self) -> impl Iterator<Item = A> + '_ {
+        self.bindings.keys().copied()
+    }
+
+    /// Sets the only [`Gamepad`] whose inputs are read. When `None`, which is the default,
+    /// inputs are read from all the gamepads.
+    pub fn set_gamepad(&mut self, gamepad: Option<Gamepad>) -> &mut Self {
+        self.gamepad = gamepad;
+        self
+    }
+
+    /// Returns the only [`Gamepad`] whose inputs are read, if there is one.
+    pub fn gamepad(&self) -> Option<Gamepad> {
+        self.gamepad
+    }
+}
+
+/// The state of the actions of type `A`, updated from an [`InputMap`] at each frame start.
+///
+/// When the [`InputMap`] is a resource, this is a resource too. When it is a component, this
+/// must be a component of the same entity.
+///
+/// Actions can also be [pressed](ActionState::press) and [released](ActionState::release)
+/// manually, for example by an AI, on entities without an [`InputMap`]. They stay just pressed
+/// or just released until the next frame starts.
+#[derive(Debug, Clone, Component)]
+pub struct ActionState<A: InputAction> {
+    buttons: Input<A>,
+    values: HashMap<A, f32>,
+    axis_pairs: HashMap<A, Vec2>,
+}
+
+impl<A: InputAction> Default for ActionState<A> {
+    fn default() -> Self {
+        Self {
+            buttons: Default::default(),
+            values: Default::default(),
+            axis_pairs: Default::default(),
+        }
+    }
+}
+
+impl<A: InputAction> ActionState<A> {
+    /// Returns `true` if `action` is pressed.
+    pub fn pressed(&self, action: A) -> bool {
+        self.buttons.pressed(action)
+    }
+
+    /// Returns `true` if `action` has been pressed since the previous frame.
+    pub fn just_pressed(&self, action: A) -> bool {
+        self.buttons.just_pressed(action)
+    }
+
+    /// Returns `true` if `action` has been released since the previous frame.
+    pub fn just_released(&self, action: A) -> bool {
+        self.buttons.just_released(action)
+    }
+
+    /// Returns the value of `action`, between `-1.0` and `1.0`, or `0.0` if it isn't pressed.
+    ///
+    /// When several bindings of the action are pressed, the value with the largest magnitude is
+    /// returned. The value of an axis pair is its length.
+    pub fn value(&self, action: A) -> f32 {
+        self.values.get(&action).copied().unwrap_or_default()
+    }
+
+    /// Returns the axis pair of `action`, if it is bound to an axis pair.
+    ///
+    /// When several axis pairs of the action are pressed, the longest is returned.
+    pub fn axis_pair(&self, action: A) -> Option<Vec2> {
+        self.axis_pairs.get(&action).copied()
+    }
+
+    /// Presses `action`, with a value of `1.0`.
+    pub fn press(&mut self, action: A) {
+        self.buttons.press(action);
+        self.values.insert(action, 1.0);
+    }
+
+    /// Releases `action`.
+    pub fn release(&mut self, action: A) {
+        self.buttons.release(action);
+        self.values.remove(&action);
+        self.axis_pairs.remove(&action);
+    }
+
+    /// Clears the just pressed and just released actions, at the start of each frame.
+    fn clear(&mut self) {
+        self.buttons.clear();
+    }
+
+    /// Updates the state of each action from the inputs it is bound to.
+    fn update(&mut self, input_map: &InputMap<A>, inputs: &InputSources) {
+        let released: Vec<A> = self
+            .buttons
+            .get_pressed()
+            .filter(|action| !input_map.bindings.contains_key(action))
+            .copied()
+            .collect();
+        for action in released {
+            self.release(action);
+        }
+
+        for (action, bindings) in &input_map.bindings {
+            let mut value = 0.0f32;
+            let mut axis_pair: Option<Vec2> = None;
+            for binding in bindings {
+                let (binding_value, binding_pair) = binding.read(inputs);
+                if binding_value.abs() > value.abs() {
+                    value = binding_value;
+                }
+                if let Some(pair) = binding_pair {
+                    match axis_pair {
+                        Some(longest) if longest.length() >= pair.length() => {}
+                        _ => axis_pair = Some(pair),
+                    }
+                }
+            }
+
+            if value != 0.0 {
+                self.buttons.press(*action);
+                self.values.insert(*action, value.clamp(-1.0, 1.0));
+            } else {
+                self.buttons.release(*action);
+                self.values.remove(action);
+            }
+            match axis_pair {
+                Some(pair) => self.axis_pairs.insert(*action, pair),
+                None => self.axis_pairs.remove(action),
+            };
+        }
+    }
+}
+
+/// The inputs read by an [`InputMap`].
+struct InputSources<'a> {
+    keys: &'a Input<KeyCode>,
+    physical_keys: &'a Input<PhysicalKey>,
+    mouse_buttons: &'a Input<MouseButton>,
+    gamepad_buttons: &'a Input<GamepadButton>,
+    gamepad_axes: &'a Axis<GamepadAxis>,
+    gamepads: Vec<Gamepad>,
+}
+
+impl<'a> InputSources<'a> {
+    fn pressed(&self, button: &InputButton) -> bool {
+        match button {
+            InputButton::Keyboard(key_code) => self.keys.pressed(*key_code),
+            InputButton::PhysicalKey(key) => self.physical_keys.pressed(*key),
+            InputButton::Mouse(button) => self.mouse_buttons.pressed(*button),
+            InputButton::Gamepad(button_type) => self.gamepads.iter().any(|gamepad| {
+                self.gamepad_buttons
+                    .pressed(GamepadButton::new(*gamepad, *button_type))
+            }),
+        }
+    }
+
+    /// The value of the axis with the largest magnitude among the gamepads.
+    fn axis(&self, axis_type: GamepadAxisType) -> f32 {
+        self.gamepads
+            .iter()
+            .filter_map(|gamepad| self.gamepad_axes.get(GamepadAxis::new(*gamepad, axis_type)))
+            .fold(0.0, |value, axis| {
+                if axis.abs() > value.abs() {
+                    axis
+                } else {
+                    value
+                }
+            })
+    }
+}
+
+/// Clears every [`ActionState`] resource and component, then updates those with an
+/// [`InputMap`] from it.
+#[allow(clippy::too_many_arguments)]
+pub fn action_state_system<A: InputAction>(
+    keys: Res<Input<KeyCode>>,
+    physical_keys: Res<Input<PhysicalKey>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    gamepads: Res<Gamepads>,
+    input_map: Option<Res<InputMap<A>>>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    mut query: Query<(&mut ActionState<A>, Option<&InputMap<A>>)>,
+) {
+    let mut inputs = InputSources {
+        keys: &keys,
+        physical_keys: &physical_keys,
+        mouse_buttons: &mouse_buttons,
+        gamepad_buttons: &gamepad_buttons,
+        gamepad_axes: &gamepad_axes,
+        gamepads: Vec::new(),
+    };
+    let mut update = |input_map: &InputMap<A>, action_state: &mut ActionState<A>| {
+        inputs.gamepads = match input_map.gamepad {
+            Some(gamepad) => vec![gamepad],
+            None => gamepads.iter().copied().collect(),
+        };
+        action_state.update(input_map, &inputs);
+    };
+
+    if let Some(mut action_state) = action_state {
+        action_state.clear();
+        if let Some(input_map) = input_map {
+            update(&input_map, &mut action_state);
+        }
+    }
+    for (mut action_state, input_map) in query.iter_mut() {
+        action_state.clear();
+        if let Some(input_map) = input_map {
+            update(input_map, &mut action_state);
+        }
+    }
+}
+
+/// Updates the [`ActionState`] of actions of type `A` from their [`InputMap`].
+///
+/// This adds an [`ActionState`] resource, which is updated if there is an [`InputMap`] resource.
+/// The [`InputPlugin`](crate::InputPlugin) must be added first.
+pub struct InputActionPlugin<A>(PhantomData<fn() -> A>);
+
+impl<A> Default for InputActionPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: InputAction> Plugin for InputActionPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActionState<A>>().add_system_to_stage(
+            CoreStage::PreUpdate,
+            action_state_system::<A>
+                .after(InputSystem)
+                .after(gamepad_connection_system),
+        );
+    }
+
+    fn dependencies(&self) -> Vec<bevy_app::PluginDependency> {
+        vec![bevy_app::PluginDependency::required::<crate::InputPlugin>()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ActionState, InputActionPlugin, InputBinding, InputMap};
+    use crate::{
+        gamepad::{Gamepad, GamepadAxisType, GamepadButtonType},
+        keyboard::KeyCode,
+        simulation::{SimulateInput, SimulatedInput},
+        InputPlugin,
+    };
+    use bevy_app::App;
+    use bevy_math::Vec2;
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+    enum Action {
+        Jump,
+        Dash,
+        Move,
+        Throttle,
+    }
+
+    fn input_map() -> InputMap<Action> {
+        let mut input_map = InputMap::default();
+        input_map
+            .insert(Action::Jump, KeyCode::Space)
+            .insert(Action::Jump, GamepadButtonType::South)
+            .insert(
+                Action::Dash,
+                InputBinding::chord([KeyCode::LShift, KeyCode::D]),
+            )
+            .insert(Action::Move, InputBinding::wasd())
+            .insert(Action::Move, InputBinding::left_stick())
+            .insert(
+                Action::Throttle,
+                InputBinding::GamepadAxis {
+                    axis_type: GamepadAxisType::RightZ,
+                    dead_zone: 0.2,
+                },
+            );
+        input_map
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugin(InputPlugin)
+            .add_plugin(InputActionPlugin::<Action>::default())
+            .insert_resource(input_map());
+        app
+    }
+
+    #[test]
+    fn buttons_and_chords() {
+        let mut app = app();
+        app.simulate_input(SimulatedInput::KeyPress(KeyCode::Space))
+            .simulate_input(SimulatedInput::KeyPress(KeyCode::LShift))
+            .update();
+        let state = app.world.resource::<ActionState<Action>>();
+        assert!(state.just_pressed(Action::Jump));
+        assert_eq!(state.value(Action::Jump), 1.0);
+        assert!(!state.pressed(Action::Dash));
+
+        app.simulate_input(SimulatedInput::KeyPress(KeyCode::D))
+            .update();
+        let state = app.world.resource::<ActionState<Action>>();
+        assert!(state.pressed(Action::Jump));
+        assert!(!state.just_pressed(Action::Jump));
+        assert!(state.just_pressed(Action::Dash));
+        // D is also bound to moving right
+        assert_eq!(state.axis_pair(Action::Move), Some(Vec2::X));
+
+        app.simulate_input(SimulatedInput::KeyRelease(KeyCode::Space))
+            .update();
+        let state = app.world.resource::<ActionState<Action>>();
+        assert!(state.just_released(Action::Jump));
+        assert_eq!(state.value(Action::Jump), 0.0);
+    }
+
+    #[test]
+    fn gamepad_axes() {
+        let mut app = app();
+        let gamepad = Gamepad::new(0);
+        app.simulate_input(SimulatedInput::GamepadConnect(gamepad))
+            .simulate_input(SimulatedInput::GamepadAxis(
+                gamepad,
+                GamepadAxisType::LeftStickY,
+                -0.5,
+            ))
+            .simulate_input(SimulatedInput::GamepadAxis(
+                gamepad,
+                GamepadAxisType::RightZ,
+                0.1,
+            ))
+            .update();
+        let state = app.world.resource::<ActionState<Action>>();
+        assert_eq!(state.axis_pair(Action::Move), Some(Vec2::new(0.0, -0.5)));
+        assert_eq!(state.value(Action::Move), 0.5);
+        // In the dead zone
+        assert!(!state.pressed(Action::Throttle));
+    }
+
+    #[test]
+    fn per_player_components() {
+        let mut app = App::new();
+        app.add_plugin(InputPlugin)
+            .add_plugin(InputActionPlugin::<Action>::default());
+        let players: Vec<_> = (0..2)
+            .map(|id| {
+                let mut input_map = input_map();
+                input_map.set_gamepad(Some(Gamepad::new(id)));
+                app.world
+                    .spawn()
+                    .insert(input_map)
+                    .insert(ActionState::<Action>::default())
+                    .id()
+            })
+            .collect();
+
+        app.simulate_input(SimulatedInput::GamepadConnect(Gamepad::new(0)))
+            .simulate_input(SimulatedInput::GamepadConnect(Gamepad::new(1)))
+            .simulate_input(SimulatedInput::GamepadPress(
+                Gamepad::new(1),
+                GamepadButtonType::South,
+            ))
+            .update();
+
+        let jumped = |app: &App, player| {
+            app.world
+                .get::<ActionState<Action>>(player)
+                .unwrap()
+                .pressed(Action::Jump)
+        };
+        assert!(!jumped(&app, players[0]));
+        assert!(jumped(&app, players[1]));
+    }
+
+    #[test]
+    fn manual_presses() {
+        let mut app = App::new();
+        app.add_plugin(InputPlugin)
+            .add_plugin(InputActionPlugin::<Action>::default());
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(Action::Jump);
+        let entity = app.world.spawn().insert(action_state).id();
+        app.world
+            .resource_mut::<ActionState<Action>>()
+            .press(Action::Dash);
+
+        app.update();
+        let state = app.world.get::<ActionState<Action>>(entity).unwrap();
+        assert!(state.pressed(Action::Jump));
+        assert!(!state.just_pressed(Action::Jump));
+        let state = app.world.resource::<ActionState<Action>>();
+        assert!(state.pressed(Action::Dash));
+        assert!(!state.just_pressed(Action::Dash));
+
+        app.world
+            .get_mut::<ActionState<Action>>(entity)
+            .unwrap()
+            .release(Action::Jump);
+        app.update();
+        let state = app.world.get::<ActionState<Action>>(entity).unwrap();
+        assert!(!state.pressed(Action::Jump));
+        assert!(!state.just_released(Action::Jump));
+    }
+
+    #[test]
+    fn rebind() {
+        let mut app = app();
+        {
+            let mut input_map = app.world.resource_mut::<InputMap<Action>>();
+            assert!(input_map.remove(Action::Jump, &KeyCode::Space.into()));
+            input_map.insert(Action::Jump, KeyCode::Return);
+        }
+        app.simulate_input(SimulatedInput::KeyPress(KeyCode::Space))
+            .update();
+        assert!(!app
+            .world
+            .resource::<ActionState<Action>>()
+            .pressed(Action::Jump));
+        app.simulate_input(SimulatedInput::KeyPress(KeyCode::Return))
+            .update();
+        assert!(app
+            .world
+            .resource::<ActionState<Action>>()
+            .pressed(Action::Jump));
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serialize_input_map() {
+        let input_map = input_map();
+        let serialized = ron::to_string(&input_map).unwrap();
+        let deserialized: InputMap<Action> = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, input_map);
+    }
+}
@@ -51,6 +51,11 @@ where
     pub fn remove(&mut self, input_device: T) -> Option<f32> {
         self.axis_data.remove(&input_device)
     }
+
+    /// Returns an iterator over the input devices with position data.
+    pub fn devices(&self) -> impl ExactSizeIterator<Item = &T> {
+        self.axis_data.keys()
+    }
 }
 
 #[cfg(test)]
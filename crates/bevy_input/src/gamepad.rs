@@ -1,7 +1,7 @@
 use crate::{Axis, Input};
 use bevy_ecs::event::{EventReader, EventWriter};
 use bevy_ecs::system::{Res, ResMut};
-use bevy_utils::{tracing::info, HashMap, HashSet};
+use bevy_utils::{tracing::info, Duration, HashMap};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
@@ -15,28 +15,72 @@ impl Gamepad {
     }
 }
 
+/// How the backend maps the buttons and axes of a [`Gamepad`] to a standard layout.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamepadMappingSource {
+    /// The gamepad is known to a mapping database, such as SDL's.
+    Mappings,
+    /// The driver of the gamepad provides a standard layout.
+    Driver,
+    /// The gamepad is not mapped, most of its inputs are reported as
+    /// [`GamepadButtonType::Other`] and [`GamepadAxisType::Other`].
+    Unmapped,
+}
+
+impl Default for GamepadMappingSource {
+    fn default() -> Self {
+        GamepadMappingSource::Unmapped
+    }
+}
+
+/// Metadata of a connected [`Gamepad`], as reported by the gamepad backend.
+///
+/// Can be used to identify the model of a gamepad, for instance to show the matching button glyphs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamepadInfo {
+    /// The name of the gamepad, e.g. "Xbox Wireless Controller".
+    pub name: String,
+    /// The USB vendor id of the gamepad, if known.
+    pub vendor_id: Option<u16>,
+    /// The USB product id of the gamepad, if known.
+    pub product_id: Option<u16>,
+    pub mapping_source: GamepadMappingSource,
+}
+
 #[derive(Default)]
-/// Container of unique connected [`Gamepad`]s
+/// Container of unique connected [`Gamepad`]s and their [`GamepadInfo`]
 ///
 /// [`Gamepad`]s are registered and deregistered in [`gamepad_connection_system`]
 pub struct Gamepads {
-    gamepads: HashSet<Gamepad>,
+    gamepads: HashMap<Gamepad, GamepadInfo>,
 }
 
 impl Gamepads {
     /// Returns true if the [Gamepads] contains a [Gamepad].
     pub fn contains(&self, gamepad: &Gamepad) -> bool {
-        self.gamepads.contains(gamepad)
+        self.gamepads.contains_key(gamepad)
     }
 
     /// Iterates over registered [Gamepad]s
     pub fn iter(&self) -> impl Iterator<Item = &Gamepad> + '_ {
-        self.gamepads.iter()
+        self.gamepads.keys()
+    }
+
+    /// Returns the [`GamepadInfo`] of a registered [Gamepad].
+    pub fn info(&self, gamepad: &Gamepad) -> Option<&GamepadInfo> {
+        self.gamepads.get(gamepad)
+    }
+
+    /// Returns the name of a registered [Gamepad].
+    pub fn name(&self, gamepad: &Gamepad) -> Option<&str> {
+        self.info(gamepad).map(|info| info.name.as_str())
     }
 
     /// Registers [Gamepad].
-    fn register(&mut self, gamepad: Gamepad) {
-        self.gamepads.insert(gamepad);
+    fn register(&mut self, gamepad: Gamepad, info: GamepadInfo) {
+        self.gamepads.insert(gamepad, info);
     }
 
     /// Deregisters [Gamepad.
@@ -48,7 +92,7 @@ impl Gamepads {
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub enum GamepadEventType {
-    Connected,
+    Connected(GamepadInfo),
     Disconnected,
     ButtonChanged(GamepadButtonType, f32),
     AxisChanged(GamepadAxisType, f32),
@@ -108,6 +152,9 @@ pub enum GamepadButtonType {
     DPadDown,
     DPadLeft,
     DPadRight,
+    /// A button the backend doesn't map to a standard one, such as a touchpad or a paddle,
+    /// identified by its platform-specific code.
+    Other(u32),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -137,6 +184,9 @@ pub enum GamepadAxisType {
     RightZ,
     DPadX,
     DPadY,
+    /// An axis the backend doesn't map to a standard one, identified by its platform-specific
+    /// code.
+    Other(u32),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -152,6 +202,97 @@ impl GamepadAxis {
     }
 }
 
+/// The intensity of each motor of a rumbling [`Gamepad`], between `0.0` and `1.0`.
+///
+/// Most gamepads have a low-frequency "strong" motor and a high-frequency "weak" motor.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamepadRumbleIntensity {
+    /// Intensity of the strong, low-frequency motor.
+    pub strong_motor: f32,
+    /// Intensity of the weak, high-frequency motor.
+    pub weak_motor: f32,
+}
+
+impl GamepadRumbleIntensity {
+    /// Rumble both motors at full intensity.
+    pub const MAX: Self = GamepadRumbleIntensity {
+        strong_motor: 1.0,
+        weak_motor: 1.0,
+    };
+
+    /// Rumble only the strong motor at full intensity.
+    pub const STRONG_MAX: Self = GamepadRumbleIntensity {
+        strong_motor: 1.0,
+        weak_motor: 0.0,
+    };
+
+    /// Rumble only the weak motor at full intensity.
+    pub const WEAK_MAX: Self = GamepadRumbleIntensity {
+        strong_motor: 0.0,
+        weak_motor: 1.0,
+    };
+
+    /// Rumble only the strong motor at the given intensity.
+    pub fn strong_motor(intensity: f32) -> Self {
+        Self {
+            strong_motor: intensity,
+            weak_motor: 0.0,
+        }
+    }
+
+    /// Rumble only the weak motor at the given intensity.
+    pub fn weak_motor(intensity: f32) -> Self {
+        Self {
+            strong_motor: 0.0,
+            weak_motor: intensity,
+        }
+    }
+}
+
+/// An event requesting a [`Gamepad`] to start or stop rumbling.
+///
+/// Requests are handled by the gamepad backend, such as `bevy_gilrs`. Gamepads that don't support
+/// force-feedback ignore them.
+///
+/// ```
+/// # use bevy_input::gamepad::{Gamepad, GamepadRumbleIntensity, GamepadRumbleRequest};
+/// # use bevy_ecs::event::EventWriter;
+/// # use bevy_utils::Duration;
+/// fn rumble_system(mut rumble_requests: EventWriter<GamepadRumbleRequest>) {
+///     rumble_requests.send(GamepadRumbleRequest::Add {
+///         gamepad: Gamepad::new(0),
+///         intensity: GamepadRumbleIntensity::strong_motor(0.5),
+///         duration: Duration::from_millis(500),
+///     });
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamepadRumbleRequest {
+    /// Add a rumble to the given gamepad for the given duration.
+    ///
+    /// Simultaneous rumbles on the same gamepad add up, up to the maximum intensity of the motors.
+    Add {
+        gamepad: Gamepad,
+        intensity: GamepadRumbleIntensity,
+        duration: Duration,
+    },
+    /// Stop all running rumbles on the given gamepad.
+    Stop { gamepad: Gamepad },
+}
+
+impl GamepadRumbleRequest {
+    /// The gamepad this request targets.
+    pub fn gamepad(&self) -> Gamepad {
+        match self {
+            GamepadRumbleRequest::Add { gamepad, .. } | GamepadRumbleRequest::Stop { gamepad } => {
+                *gamepad
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct GamepadSettings {
     pub default_button_settings: ButtonSettings,
@@ -304,10 +445,10 @@ pub fn gamepad_connection_system(
     mut gamepad_event: EventReader<GamepadEvent>,
 ) {
     for event in gamepad_event.iter() {
-        match event.event_type {
-            GamepadEventType::Connected => {
-                gamepads.register(event.gamepad);
-                info!("{:?} Connected", event.gamepad);
+        match &event.event_type {
+            GamepadEventType::Connected(info) => {
+                gamepads.register(event.gamepad, info.clone());
+                info!("{:?} Connected: {}", event.gamepad, info.name);
             }
             GamepadEventType::Disconnected => {
                 gamepads.deregister(&event.gamepad);
@@ -329,7 +470,7 @@ pub fn gamepad_event_system(
     button_input.clear();
     for event in raw_events.iter() {
         match event.event_type {
-            GamepadEventType::Connected => {
+            GamepadEventType::Connected(_) => {
                 events.send(GamepadEvent::new(event.gamepad, event.event_type.clone()));
                 for button_type in &ALL_BUTTON_TYPES {
                     let gamepad_button = GamepadButton::new(event.gamepad, *button_type);
@@ -342,13 +483,24 @@ pub fn gamepad_event_system(
             }
             GamepadEventType::Disconnected => {
                 events.send(GamepadEvent::new(event.gamepad, event.event_type.clone()));
-                for button_type in &ALL_BUTTON_TYPES {
-                    let gamepad_button = GamepadButton::new(event.gamepad, *button_type);
+                // Other buttons and axes aren't known in advance, so look for any of the gamepad
+                let gamepad_buttons: Vec<GamepadButton> = button_input
+                    .get_pressed()
+                    .chain(button_axis.devices())
+                    .filter(|button| button.gamepad == event.gamepad)
+                    .copied()
+                    .collect();
+                for gamepad_button in gamepad_buttons {
                     button_input.reset(gamepad_button);
                     button_axis.remove(gamepad_button);
                 }
-                for axis_type in &ALL_AXIS_TYPES {
-                    axis.remove(GamepadAxis::new(event.gamepad, *axis_type));
+                let gamepad_axes: Vec<GamepadAxis> = axis
+                    .devices()
+                    .filter(|gamepad_axis| gamepad_axis.gamepad == event.gamepad)
+                    .copied()
+                    .collect();
+                for gamepad_axis in gamepad_axes {
+                    axis.remove(gamepad_axis);
                 }
             }
             GamepadEventType::AxisChanged(axis_type, value) => {
@@ -425,7 +577,14 @@ const ALL_AXIS_TYPES: [GamepadAxisType; 8] = [
 
 #[cfg(test)]
 mod tests {
-    use super::{AxisSettings, ButtonAxisSettings, ButtonSettings};
+    use super::{
+        AxisSettings, ButtonAxisSettings, ButtonSettings, Gamepad, GamepadAxis, GamepadAxisType,
+        GamepadButton, GamepadButtonType, GamepadEventRaw, GamepadEventType, GamepadInfo,
+        GamepadMappingSource, Gamepads,
+    };
+    use crate::{Axis, Input, InputPlugin};
+    use bevy_app::App;
+    use bevy_ecs::event::Events;
 
     fn test_button_axis_settings_filter(
         settings: ButtonAxisSettings,
@@ -601,4 +760,107 @@ mod tests {
             assert_eq!(expected, actual, "Testing is released for value: {}", value);
         }
     }
+
+    #[test]
+    fn test_gamepad_info_and_other_buttons() {
+        let mut app = App::new();
+        app.add_plugin(InputPlugin);
+        let gamepad = Gamepad::new(0);
+        let info = GamepadInfo {
+            name: "DualSense Wireless Controller".to_string(),
+            vendor_id: Some(0x054c),
+            product_id: Some(0x0ce6),
+            mapping_source: GamepadMappingSource::Mappings,
+        };
+        let touchpad = GamepadButton::new(gamepad, GamepadButtonType::Other(0x1_013e));
+
+        app.world
+            .resource_mut::<Events<GamepadEventRaw>>()
+            .send(GamepadEventRaw::new(
+                gamepad,
+                GamepadEventType::Connected(info.clone()),
+            ));
+        app.world
+            .resource_mut::<Events<GamepadEventRaw>>()
+            .send(GamepadEventRaw::new(
+                gamepad,
+                GamepadEventType::ButtonChanged(touchpad.button_type, 1.0),
+            ));
+        app.update();
+
+        let gamepads = app.world.resource::<Gamepads>();
+        assert_eq!(gamepads.info(&gamepad), Some(&info));
+        assert_eq!(
+            gamepads.name(&gamepad),
+            Some("DualSense Wireless Controller")
+        );
+        assert!(app
+            .world
+            .resource::<Input<GamepadButton>>()
+            .pressed(touchpad));
+
+        app.world
+            .resource_mut::<Events<GamepadEventRaw>>()
+            .send(GamepadEventRaw::new(
+                gamepad,
+                GamepadEventType::Disconnected,
+            ));
+        app.update();
+
+        assert_eq!(app.world.resource::<Gamepads>().info(&gamepad), None);
+        assert!(!app
+            .world
+            .resource::<Input<GamepadButton>>()
+            .pressed(touchpad));
+    }
+
+    #[test]
+    fn test_disconnect_removes_all_axes() {
+        let mut app = App::new();
+        app.add_plugin(InputPlugin);
+        let gamepad = Gamepad::new(0);
+        let other_gamepad = Gamepad::new(1);
+        let other_button = GamepadButtonType::Other(7);
+        let other_axis = GamepadAxisType::Other(3);
+
+        let mut raw_events = app.world.resource_mut::<Events<GamepadEventRaw>>();
+        for gamepad in [gamepad, other_gamepad] {
+            raw_events.send(GamepadEventRaw::new(
+                gamepad,
+                GamepadEventType::Connected(GamepadInfo::default()),
+            ));
+            raw_events.send(GamepadEventRaw::new(
+                gamepad,
+                GamepadEventType::ButtonChanged(other_button, 0.5),
+            ));
+            raw_events.send(GamepadEventRaw::new(
+                gamepad,
+                GamepadEventType::AxisChanged(other_axis, 0.5),
+            ));
+        }
+        app.update();
+
+        app.world
+            .resource_mut::<Events<GamepadEventRaw>>()
+            .send(GamepadEventRaw::new(
+                gamepad,
+                GamepadEventType::Disconnected,
+            ));
+        app.update();
+
+        let axis = app.world.resource::<Axis<GamepadAxis>>();
+        let button_axis = app.world.resource::<Axis<GamepadButton>>();
+        assert!(axis.devices().all(|axis| axis.gamepad == other_gamepad));
+        assert!(button_axis
+            .devices()
+            .all(|button| button.gamepad == other_gamepad));
+        assert_eq!(
+            axis.get(GamepadAxis::new(other_gamepad, other_axis)),
+            Some(0.5)
+        );
+        assert_eq!(
+            button_axis.get(GamepadButton::new(other_gamepad, other_button)),
+            Some(0.5)
+        );
+    }
 }
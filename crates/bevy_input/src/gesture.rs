@@ -0,0 +1,484 @@
+use crate::{
+    touch::{Touch, Touches},
+    InputPlugin, InputSystem,
+};
+use bevy_app::{App, CoreStage, Plugin, PluginDependency};
+use bevy_ecs::{
+    event::EventWriter,
+    schedule::ParallelSystemDescriptorCoercion,
+    system::{Local, Res},
+};
+use bevy_math::Vec2;
+use bevy_time::{Time, TimePlugin};
+use bevy_utils::Duration;
+
+/// A gesture recognized from the [`Touches`] by the [`gesture_system`].
+///
+/// Positions are in the coordinates of [`TouchInput`](crate::touch::TouchInput)s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum GestureEvent {
+    /// A single finger touched the screen briefly without moving.
+    Tap { position: Vec2 },
+    /// A [`GestureEvent::Tap`] quickly followed another one at the same place.
+    ///
+    /// The second tap is also sent as a [`GestureEvent::Tap`], before this event.
+    DoubleTap { position: Vec2 },
+    /// A single finger has been touching the screen for a while without moving.
+    ///
+    /// Sent once, while the finger is still down. Releasing the finger then doesn't send a tap.
+    LongPress { position: Vec2 },
+    /// A single finger quickly moved across the screen before being released.
+    Swipe {
+        start: Vec2,
+        end: Vec2,
+        direction: SwipeDirection,
+    },
+    /// Two fingers moved apart or closer together.
+    ///
+    /// Sent every frame the distance between the fingers changes once the pinch has been
+    /// recognized.
+    Pinch {
+        /// The point between the two fingers.
+        center: Vec2,
+        /// The ratio between the current and the previous distance between the fingers, above
+        /// `1.0` when zooming in.
+        scale: f32,
+    },
+    /// Two fingers rotated around each other.
+    ///
+    /// Sent every frame the angle between the fingers changes once the rotation has been
+    /// recognized.
+    Rotate {
+        /// The point between the two fingers.
+        center: Vec2,
+        /// The angle in radians the fingers rotated by since the previous event.
+        angle: f32,
+    },
+}
+
+/// The main direction of a [`GestureEvent::Swipe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum SwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl SwipeDirection {
+    /// The direction of a movement, in window coordinates where the y axis points up.
+    fn of(delta: Vec2) -> Self {
+        if delta.x.abs() >= delta.y.abs() {
+            if delta.x < 0.0 {
+                SwipeDirection::Left
+            } else {
+                SwipeDirection::Right
+            }
+        } else if delta.y < 0.0 {
+            SwipeDirection::Down
+        } else {
+            SwipeDirection::Up
+        }
+    }
+}
+
+/// Thresholds used to recognize [`GestureEvent`]s.
+///
+/// Distances are in the units of touch positions, which are logical pixels.
+#[derive(Debug, Clone)]
+pub struct GestureSettings {
+    /// The longest a finger can touch the screen for a tap.
+    pub tap_max_duration: Duration,
+    /// The furthest a finger can move during a tap or a long press.
+    pub tap_max_distance: f32,
+    /// The longest time between two taps of a double tap.
+    pub double_tap_max_interval: Duration,
+    /// How long a finger has to touch the screen for a long press.
+    pub long_press_duration: Duration,
+    /// The shortest distance a finger has to move for a swipe.
+    pub swipe_min_distance: f32,
+    /// The longest a finger can touch the screen for a swipe.
+    pub swipe_max_duration: Duration,
+    /// How much the distance between two fingers has to change, relative to the initial
+    /// distance, to start a pinch.
+    pub pinch_min_scale: f32,
+    /// How much two fingers have to rotate, in radians, to start a rotation.
+    pub rotate_min_angle: f32,
+}
+
+impl Default for GestureSettings {
+    fn default() -> Self {
+        GestureSettings {
+            tap_max_duration: Duration::from_millis(300),
+            tap_max_distance: 10.0,
+            double_tap_max_interval: Duration::from_millis(300),
+            long_press_duration: Duration::from_millis(500),
+            swipe_min_distance: 50.0,
+            swipe_max_duration: Duration::from_millis(500),
+            pinch_min_scale: 0.1,
+            rotate_min_angle: 0.1,
+        }
+    }
+}
+
+/// The finger that started a sequence of touches.
+struct PrimaryTouch {
+    id: u64,
+    start_time: Duration,
+    start_position: Vec2,
+    /// Set once another finger touched the screen, which rules out single finger gestures.
+    multi_touch: bool,
+    long_pressed: bool,
+}
+
+/// Two fingers touching the screen.
+struct TwoFingers {
+    ids: (u64, u64),
+    /// The distance between the fingers when the last pinch was sent or before the pinch started.
+    pinch_reference: f32,
+    pinching: bool,
+    /// The vector between the fingers when the last rotation was sent or before the rotation
+    /// started.
+    rotate_reference: Vec2,
+    rotating: bool,
+}
+
+/// Recognizes [`GestureEvent`]s from the state of the [`Touches`] each frame.
+#[derive(Default)]
+pub struct GestureRecognizer {
+    primary: Option<PrimaryTouch>,
+    two_fingers: Option<TwoFingers>,
+    /// The time and position of the last tap that may start a double tap.
+    last_tap: Option<(Duration, Vec2)>,
+}
+
+impl GestureRecognizer {
+    /// Updates the recognizer with the `touches` of the current frame, `now` being the time since
+    /// startup, and calls `send` with the recognized gestures.
+    pub fn update(
+        &mut self,
+        touches: &Touches,
+        now: Duration,
+        settings: &GestureSettings,
+        mut send: impl FnMut(GestureEvent),
+    ) {
+        self.update_primary(touches, now, settings, &mut send);
+        self.update_two_fingers(touches, settings, &mut send);
+    }
+
+    fn update_primary(
+        &mut self,
+        touches: &Touches,
+        now: Duration,
+        settings: &GestureSettings,
+        send: &mut impl FnMut(GestureEvent),
+    ) {
+        if self.primary.is_none() {
+            if let Some(touch) = touches.iter_just_pressed().next() {
+                self.primary = Some(PrimaryTouch {
+                    id: touch.id(),
+                    start_time: now,
+                    start_position: touch.start_position(),
+                    multi_touch: false,
+                    long_pressed: false,
+                });
+            }
+        }
+        let primary = match &mut self.primary {
+            Some(primary) => primary,
+            None => return,
+        };
+        if touches.iter().count() > 1 {
+            primary.multi_touch = true;
+        }
+        let duration = now.saturating_sub(primary.start_time);
+
+        if let Some(touch) = touches.get_pressed(primary.id) {
+            if !primary.multi_touch
+                && !primary.long_pressed
+                && duration >= settings.long_press_duration
+                && touch.position().distance(primary.start_position) <= settings.tap_max_distance
+            {
+                primary.long_pressed = true;
+                send(GestureEvent::LongPress {
+                    position: touch.position(),
+                });
+            }
+        } else if let Some(touch) = touches.get_released(primary.id) {
+            if !primary.multi_touch && !primary.long_pressed {
+                let end = touch.position();
+                let distance = end.distance(primary.start_position);
+                if distance <= settings.tap_max_distance && duration <= settings.tap_max_duration {
+                    send(GestureEvent::Tap { position: end });
+                    match self.last_tap {
+                        Some((time, position))
+                            if now.saturating_sub(time) <= settings.double_tap_max_interval
+                                && end.distance(position) <= settings.tap_max_distance =>
+                        {
+                            send(GestureEvent::DoubleTap { position: end });
+                            self.last_tap = None;
+                        }
+                        _ => self.last_tap = Some((now, end)),
+                    }
+                } else if distance >= settings.swipe_min_distance
+                    && duration <= settings.swipe_max_duration
+                {
+                    send(GestureEvent::Swipe {
+                        start: primary.start_position,
+                        end,
+                        direction: SwipeDirection::of(end - primary.start_position),
+                    });
+                }
+            }
+        }
+
+        // The sequence of touches ends once every finger has been lifted
+        if touches.iter().next().is_none() {
+            self.primary = None;
+        }
+    }
+
+    fn update_two_fingers(
+        &mut self,
+        touches: &Touches,
+        settings: &GestureSettings,
+        send: &mut impl FnMut(GestureEvent),
+    ) {
+        let mut pressed: Vec<&Touch> = touches.iter().collect();
+        if pressed.len() != 2 {
+            self.two_fingers = None;
+            return;
+        }
+        pressed.sort_by_key(|touch| touch.id());
+        let (first, second) = (pressed[0], pressed[1]);
+        let vector = second.position() - first.position();
+        let center = (first.position() + second.position()) / 2.0;
+
+        let two_fingers = match &mut self.two_fingers {
+            Some(two_fingers) if two_fingers.ids == (first.id(), second.id()) => two_fingers,
+            _ => {
+                self.two_fingers = Some(TwoFingers {
+                    ids: (first.id(), second.id()),
+                    pinch_reference: vector.length(),
+                    pinching: false,
+                    rotate_reference: vector,
+                    rotating: false,
+                });
+                return;
+            }
+        };
+        if vector.length() <= f32::EPSILON || two_fingers.pinch_reference <= f32::EPSILON {
+            return;
+        }
+
+        let scale = vector.length() / two_fingers.pinch_reference;
+        two_fingers.pinching |= (scale - 1.0).abs() >= settings.pinch_min_scale;
+        if two_fingers.pinching && scale != 1.0 {
+            send(GestureEvent::Pinch { center, scale });
+            two_fingers.pinch_reference = vector.length();
+        }
+
+        let angle = two_fingers.rotate_reference.angle_between(vector);
+        two_fingers.rotating |= angle.abs() >= settings.rotate_min_angle;
+        if two_fingers.rotating && angle != 0.0 {
+            send(GestureEvent::Rotate { center, angle });
+            two_fingers.rotate_reference = vector;
+        }
+    }
+}
+
+/// Sends the [`GestureEvent`]s recognized from the [`Touches`].
+///
+/// By default, runs during `CoreStage::PreUpdate` when added via [`GesturePlugin`].
+pub fn gesture_system(
+    touches: Res<Touches>,
+    time: Res<Time>,
+    settings: Res<GestureSettings>,
+    mut recognizer: Local<GestureRecognizer>,
+    mut gesture_events: EventWriter<GestureEvent>,
+) {
+    recognizer.update(&touches, time.time_since_startup(), &settings, |event| {
+        gesture_events.send(event);
+    });
+}
+
+/// Adds touch gesture recognition to an App, sending [`GestureEvent`]s.
+///
+/// The thresholds can be configured by inserting a [`GestureSettings`] resource.
+#[derive(Default)]
+pub struct GesturePlugin;
+
+impl Plugin for GesturePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<GestureEvent>()
+            .init_resource::<GestureSettings>()
+            .add_system_to_stage(CoreStage::PreUpdate, gesture_system.after(InputSystem));
+    }
+
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        vec![
+            PluginDependency::required::<InputPlugin>(),
+            PluginDependency::required::<TimePlugin>(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GestureEvent, GestureRecognizer, GestureSettings, SwipeDirection};
+    use crate::touch::{TouchInput, TouchPhase, Touches};
+    use bevy_math::Vec2;
+    use bevy_utils::Duration;
+
+    /// Drives a [`GestureRecognizer`] frame by frame.
+    #[derive(Default)]
+    struct Harness {
+        touches: Touches,
+        recognizer: GestureRecognizer,
+        settings: GestureSettings,
+    }
+
+    impl Harness {
+        fn frame(&mut self, millis: u64, inputs: &[(u64, TouchPhase, Vec2)]) -> Vec<GestureEvent> {
+            self.touches.update();
+            for &(id, phase, position) in inputs {
+                self.touches.process_touch_event(&TouchInput {
+                    phase,
+                    position,
+                    force: None,
+                    id,
+                });
+            }
+            let mut events = Vec::new();
+            self.recognizer.update(
+                &self.touches,
+                Duration::from_millis(millis),
+                &self.settings,
+                |event| events.push(event),
+            );
+            events
+        }
+    }
+
+    #[test]
+    fn tap_and_double_tap() {
+        let mut harness = Harness::default();
+        let position = Vec2::new(100.0, 100.0);
+        let moved = Vec2::new(103.0, 100.0);
+
+        assert!(harness
+            .frame(0, &[(1, TouchPhase::Started, position)])
+            .is_empty());
+        assert_eq!(
+            harness.frame(100, &[(1, TouchPhase::Ended, moved)]),
+            vec![GestureEvent::Tap { position: moved }]
+        );
+        harness.frame(200, &[(2, TouchPhase::Started, position)]);
+        assert_eq!(
+            harness.frame(250, &[(2, TouchPhase::Ended, position)]),
+            vec![
+                GestureEvent::Tap { position },
+                GestureEvent::DoubleTap { position }
+            ]
+        );
+
+        // Too slow for a tap
+        harness.frame(1000, &[(3, TouchPhase::Started, position)]);
+        assert!(harness
+            .frame(1400, &[(3, TouchPhase::Ended, position)])
+            .is_empty());
+    }
+
+    #[test]
+    fn long_press() {
+        let mut harness = Harness::default();
+        let position = Vec2::new(100.0, 100.0);
+
+        harness.frame(0, &[(1, TouchPhase::Started, position)]);
+        assert!(harness.frame(400, &[]).is_empty());
+        assert_eq!(
+            harness.frame(500, &[]),
+            vec![GestureEvent::LongPress { position }]
+        );
+        assert!(harness.frame(600, &[]).is_empty());
+        assert!(harness
+            .frame(700, &[(1, TouchPhase::Ended, position)])
+            .is_empty());
+    }
+
+    #[test]
+    fn swipe() {
+        let mut harness = Harness::default();
+        let start = Vec2::new(100.0, 100.0);
+        let end = Vec2::new(110.0, 180.0);
+
+        harness.frame(0, &[(1, TouchPhase::Started, start)]);
+        harness.frame(100, &[(1, TouchPhase::Moved, Vec2::new(105.0, 140.0))]);
+        assert_eq!(
+            harness.frame(200, &[(1, TouchPhase::Ended, end)]),
+            vec![GestureEvent::Swipe {
+                start,
+                end,
+                direction: SwipeDirection::Up
+            }]
+        );
+    }
+
+    #[test]
+    fn pinch_and_rotate() {
+        let mut harness = Harness::default();
+
+        harness.frame(
+            0,
+            &[
+                (1, TouchPhase::Started, Vec2::new(-100.0, 0.0)),
+                (2, TouchPhase::Started, Vec2::new(100.0, 0.0)),
+            ],
+        );
+        // Below the thresholds
+        assert!(harness
+            .frame(10, &[(2, TouchPhase::Moved, Vec2::new(110.0, 0.0))])
+            .is_empty());
+        assert_eq!(
+            harness.frame(
+                20,
+                &[
+                    (1, TouchPhase::Moved, Vec2::new(-150.0, 0.0)),
+                    (2, TouchPhase::Moved, Vec2::new(150.0, 0.0)),
+                ]
+            ),
+            vec![GestureEvent::Pinch {
+                center: Vec2::ZERO,
+                scale: 1.5
+            }]
+        );
+        // Fingers not moving
+        assert!(harness.frame(30, &[]).is_empty());
+
+        let events = harness.frame(
+            40,
+            &[
+                (1, TouchPhase::Moved, Vec2::new(0.0, -150.0)),
+                (2, TouchPhase::Moved, Vec2::new(0.0, 150.0)),
+            ],
+        );
+        assert_eq!(events.len(), 1);
+        match events[0] {
+            GestureEvent::Rotate { center, angle } => {
+                assert_eq!(center, Vec2::ZERO);
+                assert!((angle - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+            }
+            event => panic!("Expected a rotation, got {:?}", event),
+        }
+
+        // No single finger gesture once two fingers touched the screen
+        assert!(harness
+            .frame(50, &[(1, TouchPhase::Ended, Vec2::new(0.0, -150.0))])
+            .is_empty());
+        assert!(harness
+            .frame(60, &[(2, TouchPhase::Ended, Vec2::new(0.0, 150.0))])
+            .is_empty());
+    }
+}
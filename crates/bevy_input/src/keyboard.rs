@@ -1,5 +1,6 @@
 use crate::{ButtonState, Input};
 use bevy_ecs::{event::EventReader, system::ResMut};
+use bevy_utils::HashMap;
 
 /// A keyboard input event.
 ///
@@ -11,16 +12,26 @@ use bevy_ecs::{event::EventReader, system::ResMut};
 /// The event is consumed inside of the [`keyboard_input_system`](crate::keyboard::keyboard_input_system)
 /// to update the [`Input<KeyCode>`](crate::Input<KeyCode>) resource.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeyboardInput {
     /// The scan code of the key.
     pub scan_code: u32,
     /// The key code of the key.
+    ///
+    /// Key codes depend on the keyboard layout, and are [`None`] for keys Bevy doesn't know in the
+    /// current layout. Prefer [`KeyboardInput::physical_key`] for bindings depending on the
+    /// position of keys.
     pub key_code: Option<KeyCode>,
+    /// The position of the key, independent of the keyboard layout.
+    pub physical_key: PhysicalKey,
+    /// The meaning of the key in the current keyboard layout.
+    pub logical_key: LogicalKey,
     /// The press state of the key.
     pub state: ButtonState,
 }
 
-/// Updates the [`Input<KeyCode>`] resource with the latest [`KeyboardInput`] events.
+/// Updates the [`Input<KeyCode>`] and [`Input<PhysicalKey>`] resources with the latest
+/// [`KeyboardInput`] events, and learns the [`KeyLabels`] of the pressed keys.
 ///
 /// ## Differences
 ///
@@ -28,21 +39,35 @@ pub struct KeyboardInput {
 /// the latter has convenient functions like [`Input::pressed`], [`Input::just_pressed`] and [`Input::just_released`].
 pub fn keyboard_input_system(
     mut keyboard_input: ResMut<Input<KeyCode>>,
+    mut physical_keyboard_input: ResMut<Input<PhysicalKey>>,
+    mut key_labels: ResMut<KeyLabels>,
     mut keyboard_input_events: EventReader<KeyboardInput>,
 ) {
     keyboard_input.clear();
+    physical_keyboard_input.clear();
     for event in keyboard_input_events.iter() {
-        if let KeyboardInput {
-            key_code: Some(key_code),
+        let KeyboardInput {
+            key_code,
+            physical_key,
+            logical_key,
             state,
             ..
-        } = event
-        {
+        } = event;
+        if let Some(key_code) = key_code {
             match state {
                 ButtonState::Pressed => keyboard_input.press(*key_code),
                 ButtonState::Released => keyboard_input.release(*key_code),
             }
         }
+        if !matches!(physical_key, PhysicalKey::Unidentified(_)) {
+            match state {
+                ButtonState::Pressed => physical_keyboard_input.press(*physical_key),
+                ButtonState::Released => physical_keyboard_input.release(*physical_key),
+            }
+            if let (ButtonState::Pressed, LogicalKey::Character(text)) = (state, logical_key) {
+                key_labels.set_label(*physical_key, text.to_uppercase());
+            }
+        }
     }
 }
 
@@ -407,3 +432,434 @@ pub enum KeyCode {
     /// The `Cut` key.
     Cut,
 }
+
+/// The position of a key on the keyboard, independent of the keyboard layout.
+///
+/// Keys are named after the character they produce on a US QWERTY layout, so
+/// [`PhysicalKey::KeyW`] is the key labelled `Z` on an AZERTY keyboard. This makes physical keys
+/// suited for bindings that depend on the position of keys, such as movement with WASD. The
+/// label of a key in the current layout can be found with [`KeyLabels`].
+///
+/// ## Usage
+///
+/// It is used as the generic `T` value of an [`Input`](crate::Input) to create a
+/// `Res<Input<PhysicalKey>>`, updated inside of the
+/// [`keyboard_input_system`](crate::keyboard::keyboard_input_system).
+#[derive(Debug, Hash, Ord, PartialOrd, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum PhysicalKey {
+    KeyA,
+    KeyB,
+    KeyC,
+    KeyD,
+    KeyE,
+    KeyF,
+    KeyG,
+    KeyH,
+    KeyI,
+    KeyJ,
+    KeyK,
+    KeyL,
+    KeyM,
+    KeyN,
+    KeyO,
+    KeyP,
+    KeyQ,
+    KeyR,
+    KeyS,
+    KeyT,
+    KeyU,
+    KeyV,
+    KeyW,
+    KeyX,
+    KeyY,
+    KeyZ,
+
+    Digit0,
+    Digit1,
+    Digit2,
+    Digit3,
+    Digit4,
+    Digit5,
+    Digit6,
+    Digit7,
+    Digit8,
+    Digit9,
+
+    Backquote,
+    Minus,
+    Equal,
+    BracketLeft,
+    BracketRight,
+    Backslash,
+    Semicolon,
+    Quote,
+    Comma,
+    Period,
+    Slash,
+    /// The key between the left shift and Z on ISO keyboards.
+    IntlBackslash,
+
+    Escape,
+
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+
+    PrintScreen,
+    ScrollLock,
+    Pause,
+
+    Insert,
+    Home,
+    Delete,
+    End,
+    PageUp,
+    PageDown,
+
+    ArrowLeft,
+    ArrowUp,
+    ArrowRight,
+    ArrowDown,
+
+    Backspace,
+    Tab,
+    Enter,
+    Space,
+    CapsLock,
+
+    ShiftLeft,
+    ShiftRight,
+    ControlLeft,
+    ControlRight,
+    AltLeft,
+    AltRight,
+    SuperLeft,
+    SuperRight,
+    ContextMenu,
+
+    NumLock,
+    Numpad0,
+    Numpad1,
+    Numpad2,
+    Numpad3,
+    Numpad4,
+    Numpad5,
+    Numpad6,
+    Numpad7,
+    Numpad8,
+    Numpad9,
+    NumpadAdd,
+    NumpadSubtract,
+    NumpadMultiply,
+    NumpadDivide,
+    NumpadDecimal,
+    NumpadEnter,
+    NumpadEqual,
+
+    /// A key without a known position, identified by its platform-specific scan code.
+    ///
+    /// Unidentified keys are not tracked by the `Input<PhysicalKey>` resource.
+    Unidentified(u32),
+}
+
+impl PhysicalKey {
+    /// The label of the key on a US QWERTY keyboard, or [`None`] if the key is
+    /// [unidentified](PhysicalKey::Unidentified).
+    pub fn qwerty_label(&self) -> Option<&'static str> {
+        Some(match self {
+            PhysicalKey::KeyA => "A",
+            PhysicalKey::KeyB => "B",
+            PhysicalKey::KeyC => "C",
+            PhysicalKey::KeyD => "D",
+            PhysicalKey::KeyE => "E",
+            PhysicalKey::KeyF => "F",
+            PhysicalKey::KeyG => "G",
+            PhysicalKey::KeyH => "H",
+            PhysicalKey::KeyI => "I",
+            PhysicalKey::KeyJ => "J",
+            PhysicalKey::KeyK => "K",
+            PhysicalKey::KeyL => "L",
+            PhysicalKey::KeyM => "M",
+            PhysicalKey::KeyN => "N",
+            PhysicalKey::KeyO => "O",
+            PhysicalKey::KeyP => "P",
+            PhysicalKey::KeyQ => "Q",
+            PhysicalKey::KeyR => "R",
+            PhysicalKey::KeyS => "S",
+            PhysicalKey::KeyT => "T",
+            PhysicalKey::KeyU => "U",
+            PhysicalKey::KeyV => "V",
+            PhysicalKey::KeyW => "W",
+            PhysicalKey::KeyX => "X",
+            PhysicalKey::KeyY => "Y",
+            PhysicalKey::KeyZ => "Z",
+            PhysicalKey::Digit0 => "0",
+            PhysicalKey::Digit1 => "1",
+            PhysicalKey::Digit2 => "2",
+            PhysicalKey::Digit3 => "3",
+            PhysicalKey::Digit4 => "4",
+            PhysicalKey::Digit5 => "5",
+            PhysicalKey::Digit6 => "6",
+            PhysicalKey::Digit7 => "7",
+            PhysicalKey::Digit8 => "8",
+            PhysicalKey::Digit9 => "9",
+            PhysicalKey::Backquote => "`",
+            PhysicalKey::Minus => "-",
+            PhysicalKey::Equal => "=",
+            PhysicalKey::BracketLeft => "[",
+            PhysicalKey::BracketRight => "]",
+            PhysicalKey::Backslash => "\\",
+            PhysicalKey::Semicolon => ";",
+            PhysicalKey::Quote => "'",
+            PhysicalKey::Comma => ",",
+            PhysicalKey::Period => ".",
+            PhysicalKey::Slash => "/",
+            PhysicalKey::IntlBackslash => "\\",
+            PhysicalKey::Escape => "Esc",
+            PhysicalKey::F1 => "F1",
+            PhysicalKey::F2 => "F2",
+            PhysicalKey::F3 => "F3",
+            PhysicalKey::F4 => "F4",
+            PhysicalKey::F5 => "F5",
+            PhysicalKey::F6 => "F6",
+            PhysicalKey::F7 => "F7",
+            PhysicalKey::F8 => "F8",
+            PhysicalKey::F9 => "F9",
+            PhysicalKey::F10 => "F10",
+            PhysicalKey::F11 => "F11",
+            PhysicalKey::F12 => "F12",
+            PhysicalKey::PrintScreen => "Print Screen",
+            PhysicalKey::ScrollLock => "Scroll Lock",
+            PhysicalKey::Pause => "Pause",
+            PhysicalKey::Insert => "Insert",
+            PhysicalKey::Home => "Home",
+            PhysicalKey::Delete => "Delete",
+            PhysicalKey::End => "End",
+            PhysicalKey::PageUp => "Page Up",
+            PhysicalKey::PageDown => "Page Down",
+            PhysicalKey::ArrowLeft => "Left",
+            PhysicalKey::ArrowUp => "Up",
+            PhysicalKey::ArrowRight => "Right",
+            PhysicalKey::ArrowDown => "Down",
+            PhysicalKey::Backspace => "Backspace",
+            PhysicalKey::Tab => "Tab",
+            PhysicalKey::Enter => "Enter",
+            PhysicalKey::Space => "Space",
+            PhysicalKey::CapsLock => "Caps Lock",
+            PhysicalKey::ShiftLeft => "Left Shift",
+            PhysicalKey::ShiftRight => "Right Shift",
+            PhysicalKey::ControlLeft => "Left Ctrl",
+            PhysicalKey::ControlRight => "Right Ctrl",
+            PhysicalKey::AltLeft => "Left Alt",
+            PhysicalKey::AltRight => "Right Alt",
+            PhysicalKey::SuperLeft => "Left Super",
+            PhysicalKey::SuperRight => "Right Super",
+            PhysicalKey::ContextMenu => "Menu",
+            PhysicalKey::NumLock => "Num Lock",
+            PhysicalKey::Numpad0 => "Num 0",
+            PhysicalKey::Numpad1 => "Num 1",
+            PhysicalKey::Numpad2 => "Num 2",
+            PhysicalKey::Numpad3 => "Num 3",
+            PhysicalKey::Numpad4 => "Num 4",
+            PhysicalKey::Numpad5 => "Num 5",
+            PhysicalKey::Numpad6 => "Num 6",
+            PhysicalKey::Numpad7 => "Num 7",
+            PhysicalKey::Numpad8 => "Num 8",
+            PhysicalKey::Numpad9 => "Num 9",
+            PhysicalKey::NumpadAdd => "Num +",
+            PhysicalKey::NumpadSubtract => "Num -",
+            PhysicalKey::NumpadMultiply => "Num *",
+            PhysicalKey::NumpadDivide => "Num /",
+            PhysicalKey::NumpadDecimal => "Num .",
+            PhysicalKey::NumpadEnter => "Num Enter",
+            PhysicalKey::NumpadEqual => "Num =",
+            PhysicalKey::Unidentified(_) => return None,
+        })
+    }
+}
+
+/// The meaning of a key in the current keyboard layout.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum LogicalKey {
+    /// A key producing text, with the text it produced.
+    Character(String),
+    /// A key that doesn't produce text, such as a modifier or an arrow key.
+    Named(KeyCode),
+    /// A key the backend couldn't identify in the current layout.
+    Unidentified,
+}
+
+impl From<KeyCode> for LogicalKey {
+    fn from(key_code: KeyCode) -> Self {
+        let character = match key_code {
+            KeyCode::Key1 => '1',
+            KeyCode::Key2 => '2',
+            KeyCode::Key3 => '3',
+            KeyCode::Key4 => '4',
+            KeyCode::Key5 => '5',
+            KeyCode::Key6 => '6',
+            KeyCode::Key7 => '7',
+            KeyCode::Key8 => '8',
+            KeyCode::Key9 => '9',
+            KeyCode::Key0 => '0',
+            KeyCode::A => 'a',
+            KeyCode::B => 'b',
+            KeyCode::C => 'c',
+            KeyCode::D => 'd',
+            KeyCode::E => 'e',
+            KeyCode::F => 'f',
+            KeyCode::G => 'g',
+            KeyCode::H => 'h',
+            KeyCode::I => 'i',
+            KeyCode::J => 'j',
+            KeyCode::K => 'k',
+            KeyCode::L => 'l',
+            KeyCode::M => 'm',
+            KeyCode::N => 'n',
+            KeyCode::O => 'o',
+            KeyCode::P => 'p',
+            KeyCode::Q => 'q',
+            KeyCode::R => 'r',
+            KeyCode::S => 's',
+            KeyCode::T => 't',
+            KeyCode::U => 'u',
+            KeyCode::V => 'v',
+            KeyCode::W => 'w',
+            KeyCode::X => 'x',
+            KeyCode::Y => 'y',
+            KeyCode::Z => 'z',
+            KeyCode::Space => ' ',
+            KeyCode::Apostrophe => '\'',
+            KeyCode::Backslash => '\\',
+            KeyCode::Comma => ',',
+            KeyCode::Equals => '=',
+            KeyCode::Grave => '`',
+            KeyCode::LBracket => '[',
+            KeyCode::Minus => '-',
+            KeyCode::Period => '.',
+            KeyCode::RBracket => ']',
+            KeyCode::Semicolon => ';',
+            KeyCode::Slash => '/',
+            _ => return LogicalKey::Named(key_code),
+        };
+        LogicalKey::Character(character.to_string())
+    }
+}
+
+/// The labels of the [`PhysicalKey`]s in the current keyboard layout, to display key bindings.
+///
+/// The labels are learned from the [`KeyboardInput`] events: pressing a key producing text
+/// records the text as the label of the key. Until then, and for keys that don't produce text, the
+/// label is the one of the key on a US QWERTY keyboard.
+///
+/// ## Updating
+///
+/// The resource is updated inside of the [`keyboard_input_system`](crate::keyboard::keyboard_input_system),
+/// and can be updated by the backend if it knows the keyboard layout.
+#[derive(Debug, Clone, Default)]
+pub struct KeyLabels {
+    labels: HashMap<PhysicalKey, String>,
+}
+
+impl KeyLabels {
+    /// Returns the label of `key` in the current layout.
+    pub fn label(&self, key: PhysicalKey) -> String {
+        match (self.labels.get(&key), key.qwerty_label()) {
+            (Some(label), _) => label.clone(),
+            (None, Some(label)) => label.to_string(),
+            (None, None) => format!("{:?}", key),
+        }
+    }
+
+    /// Sets the label of `key` in the current layout.
+    pub fn set_label(&mut self, key: PhysicalKey, label: impl Into<String>) {
+        self.labels.insert(key, label.into());
+    }
+
+    /// Forgets the learned labels, for instance after the keyboard layout changed.
+    pub fn clear(&mut self) {
+        self.labels.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyCode, KeyLabels, KeyboardInput, LogicalKey, PhysicalKey};
+    use crate::{ButtonState, Input, InputPlugin};
+    use bevy_app::App;
+    use bevy_ecs::event::Events;
+
+    fn send_key(app: &mut App, physical_key: PhysicalKey, key_code: KeyCode, state: ButtonState) {
+        app.world
+            .resource_mut::<Events<KeyboardInput>>()
+            .send(KeyboardInput {
+                scan_code: 0,
+                key_code: Some(key_code),
+                physical_key,
+                logical_key: key_code.into(),
+                state,
+            });
+    }
+
+    #[test]
+    fn physical_keys_and_labels() {
+        let mut app = App::new();
+        app.add_plugin(InputPlugin);
+        assert_eq!(
+            app.world.resource::<KeyLabels>().label(PhysicalKey::KeyQ),
+            "Q"
+        );
+
+        // The key at the position of Q produces an A on an AZERTY keyboard
+        send_key(
+            &mut app,
+            PhysicalKey::KeyQ,
+            KeyCode::A,
+            ButtonState::Pressed,
+        );
+        app.update();
+        assert!(app
+            .world
+            .resource::<Input<PhysicalKey>>()
+            .just_pressed(PhysicalKey::KeyQ));
+        assert!(app.world.resource::<Input<KeyCode>>().pressed(KeyCode::A));
+        assert_eq!(
+            app.world.resource::<KeyLabels>().label(PhysicalKey::KeyQ),
+            "A"
+        );
+        assert_eq!(
+            app.world
+                .resource::<KeyLabels>()
+                .label(PhysicalKey::Unidentified(42)),
+            "Unidentified(42)"
+        );
+
+        send_key(
+            &mut app,
+            PhysicalKey::KeyQ,
+            KeyCode::A,
+            ButtonState::Released,
+        );
+        app.update();
+        assert!(app
+            .world
+            .resource::<Input<PhysicalKey>>()
+            .just_released(PhysicalKey::KeyQ));
+
+        assert_eq!(
+            LogicalKey::from(KeyCode::LShift),
+            LogicalKey::Named(KeyCode::LShift)
+        );
+    }
+}
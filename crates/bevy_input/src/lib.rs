@@ -1,8 +1,11 @@
+pub mod action;
 mod axis;
 pub mod gamepad;
+pub mod gesture;
 mod input;
 pub mod keyboard;
 pub mod mouse;
+pub mod simulation;
 pub mod touch;
 
 pub use axis::*;
@@ -12,11 +15,12 @@ pub use input::*;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
+        action::{ActionState, InputActionPlugin, InputBinding, InputMap},
         gamepad::{
             Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, GamepadEvent,
-            GamepadEventType, Gamepads,
+            GamepadEventType, GamepadInfo, GamepadRumbleIntensity, GamepadRumbleRequest, Gamepads,
         },
-        keyboard::KeyCode,
+        keyboard::{KeyCode, KeyLabels, PhysicalKey},
         mouse::MouseButton,
         touch::{TouchInput, Touches},
         Axis, Input,
@@ -24,14 +28,14 @@ pub mod prelude {
 }
 
 use bevy_app::prelude::*;
-use keyboard::{keyboard_input_system, KeyCode, KeyboardInput};
+use keyboard::{keyboard_input_system, KeyCode, KeyLabels, KeyboardInput, PhysicalKey};
 use mouse::{mouse_button_input_system, MouseButton, MouseButtonInput, MouseMotion, MouseWheel};
 use prelude::Gamepads;
 use touch::{touch_screen_input_system, TouchInput, Touches};
 
 use gamepad::{
     gamepad_connection_system, gamepad_event_system, GamepadAxis, GamepadButton, GamepadEvent,
-    GamepadEventRaw, GamepadSettings,
+    GamepadEventRaw, GamepadRumbleRequest, GamepadSettings,
 };
 
 /// Adds keyboard and mouse input to an App
@@ -41,12 +45,19 @@ pub struct InputPlugin;
 #[derive(Debug, PartialEq, Eq, Clone, Hash, SystemLabel)]
 pub struct InputSystem;
 
+/// Label for the systems of input backends, which send raw input events in
+/// [`CoreStage::PreUpdate`] before they are processed by the [`InputSystem`]s.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemLabel)]
+pub struct InputBackendSystem;
+
 impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
         app
             // keyboard
             .add_event::<KeyboardInput>()
             .init_resource::<Input<KeyCode>>()
+            .init_resource::<Input<PhysicalKey>>()
+            .init_resource::<KeyLabels>()
             .add_system_to_stage(
                 CoreStage::PreUpdate,
                 keyboard_input_system.label(InputSystem),
@@ -63,6 +74,7 @@ impl Plugin for InputPlugin {
             // gamepad
             .add_event::<GamepadEvent>()
             .add_event::<GamepadEventRaw>()
+            .add_event::<GamepadRumbleRequest>()
             .init_resource::<GamepadSettings>()
             .init_resource::<Gamepads>()
             .init_resource::<Input<GamepadButton>>()
@@ -83,6 +95,17 @@ impl Plugin for InputPlugin {
                 CoreStage::PreUpdate,
                 touch_screen_input_system.label(InputSystem),
             );
+
+        #[cfg(feature = "bevy_ci_testing")]
+        {
+            let ci_testing_inputs: simulation::CiTestingInputs = bevy_app::load_ci_testing_config();
+            app.insert_resource(ci_testing_inputs).add_system_to_stage(
+                CoreStage::PreUpdate,
+                simulation::ci_testing_input_system
+                    .label(InputBackendSystem)
+                    .before(InputSystem),
+            );
+        }
     }
 }
 
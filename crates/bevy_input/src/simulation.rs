@@ -0,0 +1,296 @@
+use crate::{
+    gamepad::{
+        Gamepad, GamepadAxisType, GamepadButtonType, GamepadEventRaw, GamepadEventType, GamepadInfo,
+    },
+    keyboard::{KeyCode, KeyboardInput, LogicalKey, PhysicalKey},
+    mouse::{MouseButton, MouseButtonInput},
+    touch::{TouchInput, TouchPhase},
+    ButtonState,
+};
+use bevy_app::App;
+use bevy_ecs::{event::Events, world::World};
+use bevy_math::Vec2;
+
+/// An input that can be injected into an [`App`], as if it came from a device.
+///
+/// The input is sent as the corresponding raw event, so it is visible in the [`Input`](crate::Input),
+/// [`Axis`](crate::Axis) and [`Touches`](crate::touch::Touches) resources after the next update.
+///
+/// With the `bevy_ci_testing` feature, inputs can be scripted in the `inputs` field of the CI
+/// testing configuration, see [`CiTestingInputs`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum SimulatedInput {
+    KeyPress(KeyCode),
+    KeyRelease(KeyCode),
+    PhysicalKeyPress(PhysicalKey),
+    PhysicalKeyRelease(PhysicalKey),
+    MousePress(MouseButton),
+    MouseRelease(MouseButton),
+    GamepadConnect(Gamepad),
+    GamepadDisconnect(Gamepad),
+    GamepadPress(Gamepad, GamepadButtonType),
+    GamepadRelease(Gamepad, GamepadButtonType),
+    GamepadAxis(Gamepad, GamepadAxisType, f32),
+    Touch {
+        id: u64,
+        phase: TouchPhase,
+        position: Vec2,
+    },
+}
+
+impl SimulatedInput {
+    /// Sends the event corresponding to this input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event type has not been added to the world, which the
+    /// [`InputPlugin`](crate::InputPlugin) does.
+    pub fn send(self, world: &mut World) {
+        match self {
+            SimulatedInput::KeyPress(key_code) => send_key(world, key_code, ButtonState::Pressed),
+            SimulatedInput::KeyRelease(key_code) => {
+                send_key(world, key_code, ButtonState::Released);
+            }
+            SimulatedInput::PhysicalKeyPress(key) => {
+                send_physical_key(world, key, ButtonState::Pressed);
+            }
+            SimulatedInput::PhysicalKeyRelease(key) => {
+                send_physical_key(world, key, ButtonState::Released);
+            }
+            SimulatedInput::MousePress(button) => {
+                send_mouse_button(world, button, ButtonState::Pressed);
+            }
+            SimulatedInput::MouseRelease(button) => {
+                send_mouse_button(world, button, ButtonState::Released);
+            }
+            SimulatedInput::GamepadConnect(gamepad) => {
+                send_gamepad(
+                    world,
+                    gamepad,
+                    GamepadEventType::Connected(GamepadInfo::default()),
+                );
+            }
+            SimulatedInput::GamepadDisconnect(gamepad) => {
+                send_gamepad(world, gamepad, GamepadEventType::Disconnected);
+            }
+            SimulatedInput::GamepadPress(gamepad, button) => {
+                send_gamepad(world, gamepad, GamepadEventType::ButtonChanged(button, 1.0));
+            }
+            SimulatedInput::GamepadRelease(gamepad, button) => {
+                send_gamepad(world, gamepad, GamepadEventType::ButtonChanged(button, 0.0));
+            }
+            SimulatedInput::GamepadAxis(gamepad, axis, value) => {
+                send_gamepad(world, gamepad, GamepadEventType::AxisChanged(axis, value));
+            }
+            SimulatedInput::Touch {
+                id,
+                phase,
+                position,
+            } => world.resource_mut::<Events<TouchInput>>().send(TouchInput {
+                phase,
+                position,
+                force: None,
+                id,
+            }),
+        }
+    }
+}
+
+fn send_key(world: &mut World, key_code: KeyCode, state: ButtonState) {
+    world
+        .resource_mut::<Events<KeyboardInput>>()
+        .send(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            physical_key: PhysicalKey::Unidentified(0),
+            logical_key: key_code.into(),
+            state,
+        });
+}
+
+fn send_physical_key(world: &mut World, physical_key: PhysicalKey, state: ButtonState) {
+    world
+        .resource_mut::<Events<KeyboardInput>>()
+        .send(KeyboardInput {
+            scan_code: 0,
+            key_code: None,
+            physical_key,
+            logical_key: LogicalKey::Unidentified,
+            state,
+        });
+}
+
+fn send_mouse_button(world: &mut World, button: MouseButton, state: ButtonState) {
+    world
+        .resource_mut::<Events<MouseButtonInput>>()
+        .send(MouseButtonInput { button, state });
+}
+
+fn send_gamepad(world: &mut World, gamepad: Gamepad, event_type: GamepadEventType) {
+    world
+        .resource_mut::<Events<GamepadEventRaw>>()
+        .send(GamepadEventRaw::new(gamepad, event_type));
+}
+
+/// Extension trait to inject [`SimulatedInput`]s into an [`App`].
+pub trait SimulateInput {
+    /// Sends `input`, which is processed during the next update.
+    fn simulate_input(&mut self, input: SimulatedInput) -> &mut Self;
+}
+
+impl SimulateInput for App {
+    fn simulate_input(&mut self, input: SimulatedInput) -> &mut Self {
+        input.send(&mut self.world);
+        self
+    }
+}
+
+/// The [`SimulatedInput`]s scripted in the `inputs` field of the CI testing configuration, each
+/// with the frame on which it is sent.
+#[cfg(feature = "bevy_ci_testing")]
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct CiTestingInputs {
+    #[serde(default)]
+    pub inputs: Vec<(u32, SimulatedInput)>,
+}
+
+/// Sends the [`CiTestingInputs`] of the current frame.
+#[cfg(feature = "bevy_ci_testing")]
+pub fn ci_testing_input_system(
+    mut current_frame: bevy_ecs::system::Local<u32>,
+    ci_testing_inputs: bevy_ecs::system::Res<CiTestingInputs>,
+    mut commands: bevy_ecs::system::Commands,
+) {
+    for &(frame, input) in &ci_testing_inputs.inputs {
+        if frame == *current_frame {
+            commands.add(move |world: &mut World| input.send(world));
+        }
+    }
+    *current_frame += 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SimulateInput, SimulatedInput};
+    use crate::{
+        gamepad::{Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType},
+        keyboard::KeyCode,
+        mouse::MouseButton,
+        touch::{TouchPhase, Touches},
+        Axis, Input, InputPlugin,
+    };
+    use bevy_app::App;
+    use bevy_math::Vec2;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugin(InputPlugin);
+        app
+    }
+
+    #[test]
+    fn simulate_buttons() {
+        let mut app = app();
+        app.simulate_input(SimulatedInput::KeyPress(KeyCode::Space))
+            .simulate_input(SimulatedInput::MousePress(MouseButton::Left))
+            .update();
+        assert!(app
+            .world
+            .resource::<Input<KeyCode>>()
+            .just_pressed(KeyCode::Space));
+        assert!(app
+            .world
+            .resource::<Input<MouseButton>>()
+            .pressed(MouseButton::Left));
+
+        app.update();
+        assert!(app
+            .world
+            .resource::<Input<KeyCode>>()
+            .pressed(KeyCode::Space));
+        assert!(!app
+            .world
+            .resource::<Input<KeyCode>>()
+            .just_pressed(KeyCode::Space));
+
+        app.simulate_input(SimulatedInput::KeyRelease(KeyCode::Space))
+            .update();
+        assert!(app
+            .world
+            .resource::<Input<KeyCode>>()
+            .just_released(KeyCode::Space));
+    }
+
+    #[test]
+    fn simulate_gamepad() {
+        let mut app = app();
+        let gamepad = Gamepad::new(0);
+        app.simulate_input(SimulatedInput::GamepadConnect(gamepad))
+            .simulate_input(SimulatedInput::GamepadPress(
+                gamepad,
+                GamepadButtonType::South,
+            ))
+            .simulate_input(SimulatedInput::GamepadAxis(
+                gamepad,
+                GamepadAxisType::LeftStickX,
+                1.0,
+            ))
+            .update();
+
+        assert!(app
+            .world
+            .resource::<Input<GamepadButton>>()
+            .pressed(GamepadButton::new(gamepad, GamepadButtonType::South)));
+        assert_eq!(
+            app.world
+                .resource::<Axis<GamepadAxis>>()
+                .get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX)),
+            Some(1.0)
+        );
+    }
+
+    #[test]
+    fn simulate_touch() {
+        let mut app = app();
+        app.simulate_input(SimulatedInput::Touch {
+            id: 3,
+            phase: TouchPhase::Started,
+            position: Vec2::new(10.0, 20.0),
+        })
+        .update();
+
+        let touches = app.world.resource::<Touches>();
+        let touch = touches.get_pressed(3).unwrap();
+        assert_eq!(touch.position(), Vec2::new(10.0, 20.0));
+    }
+
+    #[cfg(feature = "bevy_ci_testing")]
+    #[test]
+    fn ci_testing_inputs() {
+        use super::CiTestingInputs;
+
+        let config = r#"(
+            exit_after: Some(60),
+            events: [(30, Screenshot)],
+            inputs: [
+                (10, KeyPress(Space)),
+                (12, GamepadAxis((id: 0), LeftStickX, 0.5)),
+            ],
+        )"#;
+        let config: CiTestingInputs = ron::from_str(config).unwrap();
+        assert_eq!(
+            config.inputs,
+            vec![
+                (10, SimulatedInput::KeyPress(KeyCode::Space)),
+                (
+                    12,
+                    SimulatedInput::GamepadAxis(Gamepad::new(0), GamepadAxisType::LeftStickX, 0.5)
+                ),
+            ]
+        );
+
+        let config = "(inputs: [(10, KeyPress(NotAKey))])";
+        assert!(ron::from_str::<CiTestingInputs>(config).is_err());
+    }
+}
@@ -27,6 +27,7 @@ use bevy_utils::HashMap;
 /// This event is the translated version of the `WindowEvent::Touch` from the `winit` crate.
 /// It is available to the end user and can be used for game logic.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct TouchInput {
     /// The phase of the touch input.
     pub phase: TouchPhase,
@@ -43,6 +44,7 @@ pub struct TouchInput {
 
 /// A force description of a [`Touch`](crate::touch::Touch) input.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub enum ForceTouch {
     /// On iOS, the force is calibrated so that the same number corresponds to
     /// roughly the same amount of pressure on the screen regardless of the
@@ -281,7 +283,7 @@ impl Touches {
 
     /// Processes a [`TouchInput`] event by updating the `pressed`, `just_pressed`,
     /// `just_released`, and `just_cancelled` collections.
-    fn process_touch_event(&mut self, event: &TouchInput) {
+    pub(crate) fn process_touch_event(&mut self, event: &TouchInput) {
         match event.phase {
             TouchPhase::Started => {
                 self.pressed.insert(event.id, event.into());
@@ -314,7 +316,7 @@ impl Touches {
     /// touch input is not moving for a single frame and would therefore be marked as
     /// not pressed, because this function is called on every single frame no matter
     /// if there was an event or not.
-    fn update(&mut self) {
+    pub(crate) fn update(&mut self) {
         self.just_pressed.clear();
         self.just_released.clear();
         self.just_cancelled.clear();
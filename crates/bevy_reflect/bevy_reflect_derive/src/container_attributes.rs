@@ -19,6 +19,9 @@ const DEBUG_ATTR: &str = "Debug";
 const PARTIAL_EQ_ATTR: &str = "PartialEq";
 const HASH_ATTR: &str = "Hash";
 
+// Registers inherent methods that can be called by name: `#[reflect(methods(foo, bar))]`
+const METHODS_ATTR: &str = "methods";
+
 // The traits listed below are not considered "special" (i.e. they use the `ReflectMyTrait` syntax)
 // but useful to know exist nonetheless
 pub(crate) const REFLECT_DEFAULT: &str = "ReflectDefault";
@@ -103,12 +106,30 @@ impl Default for TraitImpl {
 ///
 /// > __Note:__ Registering a custom function only works for special traits.
 ///
+/// Registering inherent methods so they can be called by name:
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(methods(area))]
+/// struct Rect {
+///   width: f32,
+///   height: f32,
+/// }
+///
+/// impl Rect {
+///   fn area(&self) -> f32 {
+///     self.width * self.height
+///   }
+/// }
+/// ```
+///
 #[derive(Default)]
 pub(crate) struct ReflectTraits {
     debug: TraitImpl,
     hash: TraitImpl,
     partial_eq: TraitImpl,
     idents: Vec<Ident>,
+    methods: Vec<Ident>,
 }
 
 impl ReflectTraits {
@@ -143,6 +164,18 @@ impl ReflectTraits {
                         continue;
                     };
 
+                    if ident == METHODS_ATTR {
+                        // Handles `#[reflect( methods(foo, bar) )]`
+                        for method in list.nested.iter() {
+                            if let NestedMeta::Meta(Meta::Path(path)) = method {
+                                if let Some(ident) = path.get_ident() {
+                                    traits.methods.push(ident.clone());
+                                }
+                            }
+                        }
+                        continue;
+                    }
+
                     let list_meta = list.nested.iter().next();
                     if let Some(NestedMeta::Meta(Meta::Path(path))) = list_meta {
                         if let Some(segment) = path.segments.iter().next() {
@@ -175,6 +208,11 @@ impl ReflectTraits {
         &self.idents
     }
 
+    /// The inherent methods registered with `#[reflect(methods(...))]`.
+    pub fn methods(&self) -> &[Ident] {
+        &self.methods
+    }
+
     /// Returns the implementation of `Reflect::reflect_hash` as a `TokenStream`.
     ///
     /// If `Hash` was not registered, returns `None`.
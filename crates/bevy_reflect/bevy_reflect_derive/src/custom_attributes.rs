@@ -0,0 +1,115 @@
+//! Contains code related to custom attributes for reflected types.
+//!
+//! A custom attribute is an arbitrary expression attached to a type or field with an `@`
+//! inside the derive helper attribute for `Reflect`, like `#[reflect(@0.0..1.0)]`.
+//! The value of the expression is stored in the `TypeInfo` of the type.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{Attribute, Expr, NestedMeta, Path, Token};
+
+/// The custom attributes defined on a reflected type or field.
+#[derive(Default)]
+pub(crate) struct CustomAttributes {
+    attributes: Vec<Expr>,
+}
+
+impl CustomAttributes {
+    /// Returns true if no custom attributes were defined.
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+
+    /// Returns the `CustomAttributes` value holding these attributes as a `TokenStream`.
+    pub fn to_tokens(&self, bevy_reflect_path: &Path) -> TokenStream {
+        let attributes = &self.attributes;
+        quote! {
+            #bevy_reflect_path::CustomAttributes::default()
+                #(.with_attribute(#attributes))*
+        }
+    }
+}
+
+/// Parses the contents of a `#[reflect(...)]` attribute.
+///
+/// Custom attributes (`@expr`) are added to `custom_attributes`, and the remaining entries
+/// are returned so they can be handled as usual.
+pub(crate) fn parse_reflect_args(
+    attr: &Attribute,
+    custom_attributes: &mut CustomAttributes,
+) -> Result<Punctuated<NestedMeta, Comma>, syn::Error> {
+    attr.parse_args_with(|input: ParseStream| {
+        let mut nested_metas = Punctuated::new();
+        while !input.is_empty() {
+            if input.peek(Token![@]) {
+                input.parse::<Token![@]>()?;
+                custom_attributes.attributes.push(input.parse::<Expr>()?);
+            } else {
+                nested_metas.push(input.parse::<NestedMeta>()?);
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Comma>()?;
+        }
+        Ok(nested_metas)
+    })
+}
+
+/// Returns the doc comments in the given attributes, or `None` if there are none.
+///
+/// The leading space `///` comments usually start with is stripped from each line.
+pub(crate) fn get_docs(attrs: &[Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(lit),
+                ..
+            })) => Some(lit.value()),
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').map(str::to_string).unwrap_or(line))
+        .collect::<Vec<_>>();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+/// Returns the builder call attaching the custom attributes and docs to a `TypeInfo` or field info.
+///
+/// Docs are only included with the `documentation` feature.
+pub(crate) fn impl_info_modifiers(
+    custom_attributes: &CustomAttributes,
+    docs: &Option<String>,
+    bevy_reflect_path: &Path,
+) -> TokenStream {
+    let custom_attributes = if custom_attributes.is_empty() {
+        None
+    } else {
+        let custom_attributes = custom_attributes.to_tokens(bevy_reflect_path);
+        Some(quote! {
+            .with_custom_attributes(#custom_attributes)
+        })
+    };
+    let docs = match docs {
+        Some(docs) if cfg!(feature = "documentation") => Some(quote! {
+            .with_docs(Some(#docs))
+        }),
+        _ => None,
+    };
+    if custom_attributes.is_none() && docs.is_none() {
+        return quote! {};
+    }
+    quote! {
+        .with_metadata(#bevy_reflect_path::InfoMetadata::default() #custom_attributes #docs)
+    }
+}
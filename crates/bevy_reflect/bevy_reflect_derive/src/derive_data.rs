@@ -1,8 +1,9 @@
 use crate::container_attributes::ReflectTraits;
+use crate::custom_attributes::{get_docs, parse_reflect_args, CustomAttributes};
 use crate::field_attributes::{parse_field_attrs, ReflectFieldAttr};
 use crate::utility::get_bevy_reflect_path;
 use crate::{REFLECT_ATTRIBUTE_NAME, REFLECT_VALUE_ATTRIBUTE_NAME};
-use syn::{Data, DataStruct, DeriveInput, Field, Fields, Generics, Ident, Meta, Path};
+use syn::{Data, DataStruct, DeriveInput, Field, Fields, Generics, Ident, Path};
 
 pub(crate) enum DeriveType {
     Struct,
@@ -19,6 +20,8 @@ pub(crate) struct StructField<'a> {
     pub attrs: ReflectFieldAttr,
     /// The index of this field within the struct.
     pub index: usize,
+    /// The doc comments on the field.
+    pub docs: Option<String>,
 }
 
 /// Data used by derive macros for `Reflect` and `FromReflect`
@@ -42,6 +45,8 @@ pub(crate) struct ReflectDeriveData<'a> {
     type_name: &'a Ident,
     generics: &'a Generics,
     fields: Vec<StructField<'a>>,
+    custom_attributes: CustomAttributes,
+    docs: Option<String>,
     bevy_reflect_path: Path,
 }
 
@@ -53,27 +58,29 @@ impl<'a> ReflectDeriveData<'a> {
             generics: &input.generics,
             fields: Vec::new(),
             traits: ReflectTraits::default(),
+            custom_attributes: CustomAttributes::default(),
+            docs: get_docs(&input.attrs),
             bevy_reflect_path: get_bevy_reflect_path(),
         };
 
         // Should indicate whether `#[reflect_value]` was used
         let mut force_reflect_value = false;
 
-        for attribute in input.attrs.iter().filter_map(|attr| attr.parse_meta().ok()) {
-            let meta_list = if let Meta::List(meta_list) = attribute {
-                meta_list
-            } else {
+        for attribute in input.attrs.iter() {
+            let is_reflect_value = attribute.path.is_ident(REFLECT_VALUE_ATTRIBUTE_NAME);
+            if !is_reflect_value && !attribute.path.is_ident(REFLECT_ATTRIBUTE_NAME) {
+                continue;
+            }
+            // Only the list form (`#[reflect(...)]`) is meaningful on containers
+            if attribute.tokens.is_empty() {
                 continue;
-            };
-
-            if let Some(ident) = meta_list.path.get_ident() {
-                if ident == REFLECT_ATTRIBUTE_NAME {
-                    output.traits = ReflectTraits::from_nested_metas(&meta_list.nested);
-                } else if ident == REFLECT_VALUE_ATTRIBUTE_NAME {
-                    force_reflect_value = true;
-                    output.traits = ReflectTraits::from_nested_metas(&meta_list.nested);
-                }
             }
+
+            let nested_metas = parse_reflect_args(attribute, &mut output.custom_attributes)?;
+            if is_reflect_value {
+                force_reflect_value = true;
+            }
+            output.traits = ReflectTraits::from_nested_metas(&nested_metas);
         }
 
         let fields = match &input.data {
@@ -127,6 +134,7 @@ impl<'a> ReflectDeriveData<'a> {
                     index,
                     attrs,
                     data: field,
+                    docs: get_docs(&field.attrs),
                 }
             })
             .collect::<Vec<StructField>>();
@@ -180,18 +188,45 @@ impl<'a> ReflectDeriveData<'a> {
         &self.fields
     }
 
+    /// The custom attributes defined on this struct.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
+    /// The doc comments on this struct.
+    pub fn docs(&self) -> &Option<String> {
+        &self.docs
+    }
+
     /// The cached `bevy_reflect` path.
     pub fn bevy_reflect_path(&self) -> &Path {
         &self.bevy_reflect_path
     }
 
     /// Returns the `GetTypeRegistration` impl as a `TokenStream`.
+    ///
+    /// This also submits the type for automatic registration.
     pub fn get_type_registration(&self) -> proc_macro2::TokenStream {
-        crate::registration::impl_get_type_registration(
+        let field_types = match self.derive_type {
+            DeriveType::Value => Vec::new(),
+            _ => self.active_types(),
+        };
+        let get_type_registration_impl = crate::registration::impl_get_type_registration(
             self.type_name,
             &self.bevy_reflect_path,
             self.traits.idents(),
+            self.traits.methods(),
             self.generics,
-        )
+            &field_types,
+        );
+        let auto_register = crate::registration::impl_auto_register(
+            self.type_name,
+            &self.bevy_reflect_path,
+            self.generics,
+        );
+        quote::quote! {
+            #get_type_registration_impl
+            #auto_register
+        }
     }
 }
@@ -4,6 +4,7 @@
 //! as opposed to an entire struct or enum. An example of such an attribute is
 //! the derive helper attribute for `Reflect`, which looks like: `#[reflect(ignore)]`.
 
+use crate::custom_attributes::{parse_reflect_args, CustomAttributes};
 use crate::REFLECT_ATTRIBUTE_NAME;
 use quote::ToTokens;
 use syn::spanned::Spanned;
@@ -19,6 +20,8 @@ pub(crate) struct ReflectFieldAttr {
     pub ignore: bool,
     /// Sets the default behavior of this field.
     pub default: DefaultBehavior,
+    /// The custom attributes of this field, such as `#[reflect(@0.0..1.0)]`.
+    pub custom_attributes: CustomAttributes,
 }
 
 /// Controls how the default value is determined for a field.
@@ -49,12 +52,19 @@ pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr,
         .iter()
         .filter(|a| a.path.is_ident(REFLECT_ATTRIBUTE_NAME));
     for attr in attrs {
-        let meta = attr.parse_meta()?;
-        if let Err(err) = parse_meta(&mut args, &meta) {
-            if let Some(ref mut error) = errors {
-                error.combine(err);
+        let nested_metas = parse_reflect_args(attr, &mut args.custom_attributes)?;
+        for nested_meta in nested_metas.iter() {
+            let meta = if let NestedMeta::Meta(meta) = nested_meta {
+                meta
             } else {
-                errors = Some(err);
+                continue;
+            };
+            if let Err(err) = parse_meta(&mut args, meta) {
+                if let Some(ref mut error) = errors {
+                    error.combine(err);
+                } else {
+                    errors = Some(err);
+                }
             }
         }
     }
@@ -1,4 +1,5 @@
 use crate::container_attributes::ReflectTraits;
+use crate::custom_attributes::{impl_info_modifiers, CustomAttributes};
 use crate::ReflectDeriveData;
 use proc_macro::TokenStream;
 use proc_macro2::Ident;
@@ -36,6 +37,12 @@ pub(crate) fn impl_struct(derive_data: &ReflectDeriveData) -> TokenStream {
         .active_fields()
         .map(|field| field.data.ty.clone())
         .collect::<Vec<_>>();
+    let field_modifiers = impl_field_modifiers(derive_data);
+    let info_modifiers = impl_info_modifiers(
+        derive_data.custom_attributes(),
+        derive_data.docs(),
+        bevy_reflect_path,
+    );
     let field_count = field_idents.len();
     let field_indices = (0..field_count).collect::<Vec<usize>>();
 
@@ -57,9 +64,9 @@ pub(crate) fn impl_struct(derive_data: &ReflectDeriveData) -> TokenStream {
         derive_data.generics(),
         quote! {
            let fields: [#bevy_reflect_path::NamedField; #field_count] = [
-                #(#bevy_reflect_path::NamedField::new::<#field_types, _>(#field_names),)*
+                #(#bevy_reflect_path::NamedField::new::<#field_types, _>(#field_names)#field_modifiers,)*
             ];
-            let info = #bevy_reflect_path::StructInfo::new::<Self>(&fields);
+            let info = #bevy_reflect_path::StructInfo::new::<Self>(&fields)#info_modifiers;
             #bevy_reflect_path::TypeInfo::Struct(info)
         },
         bevy_reflect_path,
@@ -209,6 +216,12 @@ pub(crate) fn impl_tuple_struct(derive_data: &ReflectDeriveData) -> TokenStream
         .active_fields()
         .map(|field| field.data.ty.clone())
         .collect::<Vec<_>>();
+    let field_modifiers = impl_field_modifiers(derive_data);
+    let info_modifiers = impl_info_modifiers(
+        derive_data.custom_attributes(),
+        derive_data.docs(),
+        bevy_reflect_path,
+    );
     let field_count = field_idents.len();
     let field_indices = (0..field_count).collect::<Vec<usize>>();
 
@@ -230,9 +243,9 @@ pub(crate) fn impl_tuple_struct(derive_data: &ReflectDeriveData) -> TokenStream
         derive_data.generics(),
         quote! {
             let fields: [#bevy_reflect_path::UnnamedField; #field_count] = [
-                #(#bevy_reflect_path::UnnamedField::new::<#field_types>(#field_indices),)*
+                #(#bevy_reflect_path::UnnamedField::new::<#field_types>(#field_indices)#field_modifiers,)*
             ];
-            let info = #bevy_reflect_path::TupleStructInfo::new::<Self>(&fields);
+            let info = #bevy_reflect_path::TupleStructInfo::new::<Self>(&fields)#info_modifiers;
             #bevy_reflect_path::TypeInfo::TupleStruct(info)
         },
         bevy_reflect_path,
@@ -344,6 +357,20 @@ pub(crate) fn impl_tuple_struct(derive_data: &ReflectDeriveData) -> TokenStream
     })
 }
 
+/// Returns the custom attribute and docs modifiers for each active field.
+fn impl_field_modifiers(derive_data: &ReflectDeriveData) -> Vec<proc_macro2::TokenStream> {
+    derive_data
+        .active_fields()
+        .map(|field| {
+            impl_info_modifiers(
+                &field.attrs.custom_attributes,
+                &field.docs,
+                derive_data.bevy_reflect_path(),
+            )
+        })
+        .collect()
+}
+
 /// Implements `GetTypeRegistration` and `Reflect` for the given type data.
 pub(crate) fn impl_value(
     type_name: &Ident,
@@ -351,16 +378,19 @@ pub(crate) fn impl_value(
     get_type_registration_impl: proc_macro2::TokenStream,
     bevy_reflect_path: &Path,
     reflect_traits: &ReflectTraits,
+    custom_attributes: &CustomAttributes,
+    docs: &Option<String>,
 ) -> TokenStream {
     let hash_fn = reflect_traits.get_hash_impl(bevy_reflect_path);
     let partial_eq_fn = reflect_traits.get_partial_eq_impl(bevy_reflect_path);
     let debug_fn = reflect_traits.get_debug_impl();
+    let info_modifiers = impl_info_modifiers(custom_attributes, docs, bevy_reflect_path);
 
     let typed_impl = impl_typed(
         type_name,
         generics,
         quote! {
-            let info = #bevy_reflect_path::ValueInfo::new::<Self>();
+            let info = #bevy_reflect_path::ValueInfo::new::<Self>()#info_modifiers;
             #bevy_reflect_path::TypeInfo::Value(info)
         },
         bevy_reflect_path,
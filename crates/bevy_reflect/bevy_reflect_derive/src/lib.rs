@@ -15,6 +15,7 @@
 extern crate proc_macro;
 
 mod container_attributes;
+mod custom_attributes;
 mod derive_data;
 mod field_attributes;
 mod from_reflect;
@@ -25,6 +26,7 @@ mod trait_reflection;
 mod type_uuid;
 mod utility;
 
+use crate::custom_attributes::CustomAttributes;
 use crate::derive_data::ReflectDeriveData;
 use derive_data::DeriveType;
 use proc_macro::TokenStream;
@@ -53,6 +55,8 @@ pub fn derive_reflect(input: TokenStream) -> TokenStream {
             derive_data.get_type_registration(),
             derive_data.bevy_reflect_path(),
             derive_data.traits(),
+            derive_data.custom_attributes(),
+            derive_data.docs(),
         ),
     }
 }
@@ -107,7 +111,9 @@ pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
         ty,
         &bevy_reflect_path,
         registration_data,
+        reflect_traits.methods(),
         &reflect_value_def.generics,
+        &[],
     );
     impls::impl_value(
         ty,
@@ -115,6 +121,8 @@ pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
         get_type_registration_impl,
         &bevy_reflect_path,
         &reflect_traits,
+        &CustomAttributes::default(),
+        &None,
     )
 }
 
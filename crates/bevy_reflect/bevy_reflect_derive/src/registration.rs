@@ -1,25 +1,95 @@
 //! Contains code related specifically to Bevy's type registration.
 
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::quote;
-use syn::{Generics, Path};
+use syn::{Generics, Path, Type};
 
-/// Creates the `GetTypeRegistration` impl for the given type data.
+/// Creates the `GetTypeRegistration` impl for the given type data and methods.
+///
+/// The impl also registers the types of the given fields as dependencies. Field types that
+/// mention a generic parameter are required to implement `GetTypeRegistration`, while concrete
+/// field types are only registered if they happen to implement it.
 pub(crate) fn impl_get_type_registration(
     type_name: &Ident,
     bevy_reflect_path: &Path,
     registration_data: &[Ident],
+    methods: &[Ident],
     generics: &Generics,
+    field_types: &[Type],
 ) -> proc_macro2::TokenStream {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let type_params: Vec<&Ident> = generics.type_params().map(|param| &param.ident).collect();
+    let (generic_fields, concrete_fields): (Vec<&Type>, Vec<&Type>) = field_types
+        .iter()
+        .partition(|ty| mentions_any(quote!(#ty), &type_params));
+
+    let mut where_clause = where_clause
+        .cloned()
+        .unwrap_or_else(|| syn::parse_quote!(where));
+    for ty in &generic_fields {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#ty: #bevy_reflect_path::GetTypeRegistration));
+    }
+
+    let method_registration = if methods.is_empty() {
+        quote! {}
+    } else {
+        let method_names = methods.iter().map(|method| method.to_string());
+        quote! {
+            let mut methods = #bevy_reflect_path::func::ReflectMethods::default();
+            #(methods.insert(
+                #method_names,
+                #bevy_reflect_path::func::IntoFunction::into_function(<#type_name #ty_generics>::#methods),
+            );)*
+            registration.insert::<#bevy_reflect_path::func::ReflectMethods>(methods);
+        }
+    };
+
     quote! {
         #[allow(unused_mut)]
         impl #impl_generics #bevy_reflect_path::GetTypeRegistration for #type_name #ty_generics #where_clause {
             fn get_type_registration() -> #bevy_reflect_path::TypeRegistration {
                 let mut registration = #bevy_reflect_path::TypeRegistration::of::<#type_name #ty_generics>();
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<#type_name #ty_generics>::from_type());)*
+                #method_registration
                 registration
             }
+
+            #[allow(unused_variables)]
+            fn register_type_dependencies(registry: &mut #bevy_reflect_path::TypeRegistry) {
+                #[allow(unused_imports)]
+                use #bevy_reflect_path::__macro_exports::{RegisterDependency, RegisterDependencyFallback};
+                #(registry.register_dependency::<#generic_fields>();)*
+                #((&#bevy_reflect_path::__macro_exports::TypeDependency::<#concrete_fields>::new()).register_dependency(registry);)*
+            }
+        }
+    }
+}
+
+/// Submits the type for automatic registration, which only takes effect when `bevy_reflect`
+/// is built with the `auto_register` feature.
+///
+/// Generic types are skipped, since only their concrete instances can be registered.
+pub(crate) fn impl_auto_register(
+    type_name: &Ident,
+    bevy_reflect_path: &Path,
+    generics: &Generics,
+) -> proc_macro2::TokenStream {
+    if generics.params.is_empty() {
+        quote! {
+            #bevy_reflect_path::__auto_register_type!(#type_name);
         }
+    } else {
+        quote! {}
     }
 }
+
+fn mentions_any(tokens: TokenStream, idents: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.iter().any(|param| **param == ident),
+        TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        _ => false,
+    })
+}
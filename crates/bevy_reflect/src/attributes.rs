@@ -0,0 +1,120 @@
+use crate::Reflect;
+use bevy_utils::HashMap;
+use std::any::TypeId;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// The metadata that deriving `Reflect` attaches to the [`TypeInfo`](crate::TypeInfo) of a
+/// type and to each of its fields.
+///
+/// This holds the [`CustomAttributes`] added with `#[reflect(@...)]` and, with the
+/// `documentation` feature, the doc comments.
+#[derive(Clone, Debug, Default)]
+pub struct InfoMetadata {
+    custom_attributes: Arc<CustomAttributes>,
+    #[cfg(feature = "documentation")]
+    docs: Option<&'static str>,
+}
+
+impl InfoMetadata {
+    /// Sets the custom attributes.
+    pub fn with_custom_attributes(mut self, custom_attributes: CustomAttributes) -> Self {
+        self.custom_attributes = Arc::new(custom_attributes);
+        self
+    }
+
+    /// Sets the doc comments.
+    #[cfg(feature = "documentation")]
+    pub fn with_docs(mut self, docs: Option<&'static str>) -> Self {
+        self.docs = docs;
+        self
+    }
+
+    /// The custom attributes, added with `#[reflect(@...)]`.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
+    /// The doc comments, if there are any.
+    #[cfg(feature = "documentation")]
+    pub fn docs(&self) -> Option<&'static str> {
+        self.docs
+    }
+}
+
+/// A collection of custom attributes attached to a type or field.
+///
+/// Custom attributes are arbitrary [`Reflect`] values, added with `#[reflect(@...)]`
+/// when deriving `Reflect`, and looked up by their type at runtime. Only one attribute of
+/// each type is stored: a later attribute of the same type replaces an earlier one.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, Typed, TypeInfo};
+/// # use std::ops::Range;
+/// #[derive(Reflect)]
+/// struct Tooltip(String);
+///
+/// #[derive(Reflect)]
+/// struct Slider {
+///     #[reflect(@0.0..1.0_f32, @Tooltip("How far along the slider is".to_string()))]
+///     value: f32,
+/// }
+///
+/// let info = match Slider::type_info() {
+///     TypeInfo::Struct(info) => info,
+///     _ => unreachable!(),
+/// };
+/// let attributes = info.field("value").unwrap().metadata().custom_attributes();
+/// assert_eq!(attributes.get::<Range<f32>>(), Some(&(0.0..1.0)));
+/// assert_eq!(attributes.get::<Tooltip>().unwrap().0, "How far along the slider is");
+/// ```
+#[derive(Default)]
+pub struct CustomAttributes {
+    attributes: HashMap<TypeId, Box<dyn Reflect>>,
+}
+
+impl CustomAttributes {
+    /// Adds an attribute, replacing any attribute of the same type.
+    pub fn with_attribute<T: Reflect>(mut self, value: T) -> Self {
+        self.attributes.insert(TypeId::of::<T>(), Box::new(value));
+        self
+    }
+
+    /// Returns the attribute of type `T`, if there is one.
+    pub fn get<T: Reflect>(&self) -> Option<&T> {
+        self.get_by_id(TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Returns the attribute with the given [`TypeId`], if there is one.
+    pub fn get_by_id(&self, type_id: TypeId) -> Option<&dyn Reflect> {
+        self.attributes.get(&type_id).map(|value| value.as_ref())
+    }
+
+    /// Returns `true` if there is an attribute of type `T`.
+    pub fn contains<T: Reflect>(&self) -> bool {
+        self.attributes.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns an iterator over the attributes.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Reflect> {
+        self.attributes.values().map(|value| value.as_ref())
+    }
+
+    /// The number of attributes.
+    pub fn len(&self) -> usize {
+        self.attributes.len()
+    }
+
+    /// Returns `true` if there are no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+}
+
+impl Debug for CustomAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,637 @@
+use crate::{List, Map, Reflect, ReflectMut, ReflectRef, Struct};
+use thiserror::Error;
+
+/// The structural difference between two reflected values, as computed by [`diff`].
+///
+/// Applying a diff to the old value with [`apply_diff`] turns it into the new value.
+#[derive(Debug)]
+pub enum ReflectDiff {
+    /// Both values are equal.
+    Unchanged,
+    /// The value should be replaced entirely, because it is a [`ReflectRef::Value`] or because
+    /// the shapes of the two values don't match.
+    Replace(Box<dyn Reflect>),
+    /// The changed fields of a struct, by name.
+    Struct(Vec<(String, ReflectDiff)>),
+    /// The changed fields of a tuple struct, by index.
+    TupleStruct(Vec<(usize, ReflectDiff)>),
+    /// The changed fields of a tuple, by index.
+    Tuple(Vec<(usize, ReflectDiff)>),
+    /// The changed elements of an array, by index.
+    Array(Vec<(usize, ReflectDiff)>),
+    /// The operations turning the old list into the new one, to be applied in order.
+    List(Vec<ListDiffOp>),
+    /// The operations turning the old map into the new one.
+    Map(Vec<MapDiffOp>),
+}
+
+impl ReflectDiff {
+    /// Returns `true` if this diff doesn't change anything.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, ReflectDiff::Unchanged)
+    }
+}
+
+/// An edit of a [`List`], part of a [`ReflectDiff::List`].
+///
+/// Indices refer to the list as left by the previous operations.
+#[derive(Debug)]
+pub enum ListDiffOp {
+    /// Insert an element at the given index.
+    Insert {
+        index: usize,
+        value: Box<dyn Reflect>,
+    },
+    /// Remove the element at the given index.
+    Remove { index: usize },
+    /// Apply a diff to the element at the given index.
+    Modify { index: usize, diff: ReflectDiff },
+}
+
+/// An edit of a [`Map`], part of a [`ReflectDiff::Map`].
+#[derive(Debug)]
+pub enum MapDiffOp {
+    /// Insert a new entry.
+    Insert {
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    },
+    /// Remove the entry with the given key.
+    Remove { key: Box<dyn Reflect> },
+    /// Apply a diff to the value of the entry with the given key.
+    Modify {
+        key: Box<dyn Reflect>,
+        diff: ReflectDiff,
+    },
+}
+
+/// An error returned by [`apply_diff`] when a diff doesn't fit the value it is applied to.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ApplyDiffError {
+    #[error("expected a {expected} but found {type_name}")]
+    MismatchedKinds {
+        expected: &'static str,
+        type_name: String,
+    },
+    #[error("{type_name} has no field named {name}")]
+    MissingField { type_name: String, name: String },
+    #[error("index {index} is out of bounds for {type_name}")]
+    IndexOutOfBounds { type_name: String, index: usize },
+    #[error("{type_name} has no entry for the modified key")]
+    MissingKey { type_name: String },
+}
+
+/// The largest number of element pairs compared to diff two lists by their longest common
+/// subsequence. Larger lists are diffed index by index instead.
+const MAX_LIST_DIFF_COMPARISONS: usize = 1 << 20;
+
+/// Computes the difference between `old` and `new`.
+///
+/// The values are compared structurally through [`Reflect::reflect_ref`]. Leaf values are
+/// compared with [`Reflect::reflect_partial_eq`] and replaced when they differ, while lists are
+/// diffed into a sequence of insertions, removals and modifications of their elements.
+///
+/// Lists are matched by their longest common subsequence, which takes time and memory
+/// proportional to the product of their lengths. Past about a million element pairs, elements
+/// are instead compared at the same index, and the tail is removed or inserted.
+pub fn diff(old: &dyn Reflect, new: &dyn Reflect) -> ReflectDiff {
+    if old.type_name() != new.type_name() {
+        return ReflectDiff::Replace(new.clone_value());
+    }
+
+    match (old.reflect_ref(), new.reflect_ref()) {
+        (ReflectRef::Struct(old), ReflectRef::Struct(new)) => diff_struct(old, new),
+        (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new)) => {
+            if old.field_len() != new.field_len() {
+                return ReflectDiff::Replace(new.clone_value());
+            }
+            let changes = diff_fields(old.iter_fields().zip(new.iter_fields()));
+            wrap_changes(changes, ReflectDiff::TupleStruct)
+        }
+        (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) => {
+            if old.field_len() != new.field_len() {
+                return ReflectDiff::Replace(new.clone_value());
+            }
+            let changes = diff_fields(old.iter_fields().zip(new.iter_fields()));
+            wrap_changes(changes, ReflectDiff::Tuple)
+        }
+        (ReflectRef::Array(old), ReflectRef::Array(new)) => {
+            if old.len() != new.len() {
+                return ReflectDiff::Replace(new.clone_value());
+            }
+            let changes = diff_fields(old.iter().zip(new.iter()));
+            wrap_changes(changes, ReflectDiff::Array)
+        }
+        (ReflectRef::List(old), ReflectRef::List(new)) => diff_list(old, new),
+        (ReflectRef::Map(old), ReflectRef::Map(new)) => diff_map(old, new),
+        (ReflectRef::Value(old), ReflectRef::Value(new)) => {
+            if old.reflect_partial_eq(new) == Some(true) {
+                ReflectDiff::Unchanged
+            } else {
+                ReflectDiff::Replace(new.clone_value())
+            }
+        }
+        _ => ReflectDiff::Replace(new.clone_value()),
+    }
+}
+
+fn wrap_changes<T>(changes: Vec<T>, wrap: impl FnOnce(Vec<T>) -> ReflectDiff) -> ReflectDiff {
+    if changes.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        wrap(changes)
+    }
+}
+
+fn diff_fields<'a>(
+    fields: impl Iterator<Item = (&'a dyn Reflect, &'a dyn Reflect)>,
+) -> Vec<(usize, ReflectDiff)> {
+    fields
+        .enumerate()
+        .map(|(index, (old, new))| (index, diff(old, new)))
+        .filter(|(_, diff)| !diff.is_unchanged())
+        .collect()
+}
+
+fn diff_struct(old: &dyn Struct, new: &dyn Struct) -> ReflectDiff {
+    if old.field_len() != new.field_len() {
+        return ReflectDiff::Replace(new.clone_value());
+    }
+
+    let mut changes = Vec::new();
+    for (index, new_field) in new.iter_fields().enumerate() {
+        let name = new.name_at(index).unwrap();
+        match old.field(name) {
+            Some(old_field) => {
+                let field_diff = diff(old_field, new_field);
+                if !field_diff.is_unchanged() {
+                    changes.push((name.to_string(), field_diff));
+                }
+            }
+            None => return ReflectDiff::Replace(new.clone_value()),
+        }
+    }
+    wrap_changes(changes, ReflectDiff::Struct)
+}
+
+fn diff_list(old: &dyn List, new: &dyn List) -> ReflectDiff {
+    let old_len = old.len();
+    let new_len = new.len();
+    if old_len.saturating_mul(new_len) > MAX_LIST_DIFF_COMPARISONS {
+        return diff_list_by_index(old, new);
+    }
+    let equal = |i: usize, j: usize| {
+        old.get(i)
+            .unwrap()
+            .reflect_partial_eq(new.get(j).unwrap())
+            .unwrap_or(false)
+    };
+
+    // Longest common subsequence of equal elements, where `lcs[i][j]` is the length of the
+    // subsequence common to `old[i..]` and `new[j..]`.
+    let mut lcs = vec![vec![0usize; new_len + 1]; old_len + 1];
+    for i in (0..old_len).rev() {
+        for j in (0..new_len).rev() {
+            lcs[i][j] = if equal(i, j) {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let mut matches = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old_len && j < new_len {
+        if equal(i, j) {
+            matches.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    matches.push((old_len, new_len));
+
+    // Between two kept elements, the removed and inserted elements are paired up into
+    // modifications first, so that changing an element keeps it in place.
+    let mut ops = Vec::new();
+    let (mut old_index, mut new_index, mut position) = (0, 0, 0);
+    for (matched_old, matched_new) in matches {
+        let removed = matched_old - old_index;
+        let inserted = matched_new - new_index;
+        for offset in 0..removed.min(inserted) {
+            let element_diff = diff(
+                old.get(old_index + offset).unwrap(),
+                new.get(new_index + offset).unwrap(),
+            );
+            if !element_diff.is_unchanged() {
+                ops.push(ListDiffOp::Modify {
+                    index: position,
+                    diff: element_diff,
+                });
+            }
+            position += 1;
+        }
+        for _ in inserted..removed {
+            ops.push(ListDiffOp::Remove { index: position });
+        }
+        for offset in removed..inserted {
+            ops.push(ListDiffOp::Insert {
+                index: position,
+                value: new.get(new_index + offset).unwrap().clone_value(),
+            });
+            position += 1;
+        }
+        // Skip over the kept element.
+        position += 1;
+        old_index = matched_old + 1;
+        new_index = matched_new + 1;
+    }
+    wrap_changes(ops, ReflectDiff::List)
+}
+
+fn diff_list_by_index(old: &dyn List, new: &dyn List) -> ReflectDiff {
+    let common_len = old.len().min(new.len());
+    let mut ops = Vec::new();
+    for (index, (old_element, new_element)) in old.iter().zip(new.iter()).enumerate() {
+        let element_diff = diff(old_element, new_element);
+        if !element_diff.is_unchanged() {
+            ops.push(ListDiffOp::Modify {
+                index,
+                diff: element_diff,
+            });
+        }
+    }
+    for index in (common_len..old.len()).rev() {
+        ops.push(ListDiffOp::Remove { index });
+    }
+    for index in common_len..new.len() {
+        ops.push(ListDiffOp::Insert {
+            index,
+            value: new.get(index).unwrap().clone_value(),
+        });
+    }
+    wrap_changes(ops, ReflectDiff::List)
+}
+
+fn diff_map(old: &dyn Map, new: &dyn Map) -> ReflectDiff {
+    let mut ops = Vec::new();
+    for (key, _) in old.iter() {
+        if new.get(key).is_none() {
+            ops.push(MapDiffOp::Remove {
+                key: key.clone_value(),
+            });
+        }
+    }
+    for (key, new_value) in new.iter() {
+        match old.get(key) {
+            Some(old_value) => {
+                let value_diff = diff(old_value, new_value);
+                if !value_diff.is_unchanged() {
+                    ops.push(MapDiffOp::Modify {
+                        key: key.clone_value(),
+                        diff: value_diff,
+                    });
+                }
+            }
+            None => ops.push(MapDiffOp::Insert {
+                key: key.clone_value(),
+                value: new_value.clone_value(),
+            }),
+        }
+    }
+    wrap_changes(ops, ReflectDiff::Map)
+}
+
+/// Patches `value` in place with a diff computed by [`diff`].
+///
+/// # Panics
+///
+/// Panics if a replaced or inserted value cannot be converted into the type it is written to,
+/// like [`Reflect::apply`] does, or if a list or map doesn't support inserting or removing
+/// elements.
+pub fn apply_diff(value: &mut dyn Reflect, diff: &ReflectDiff) -> Result<(), ApplyDiffError> {
+    match diff {
+        ReflectDiff::Unchanged => {}
+        ReflectDiff::Replace(new_value) => {
+            if let Err(new_value) = value.set(new_value.clone_value()) {
+                value.apply(&*new_value);
+            }
+        }
+        ReflectDiff::Struct(changes) => {
+            let value = match value.reflect_mut() {
+                ReflectMut::Struct(value) => value,
+                _ => return Err(mismatched_kinds("struct", value)),
+            };
+            for (name, field_diff) in changes {
+                let type_name = value.type_name().to_string();
+                let field = value
+                    .field_mut(name)
+                    .ok_or_else(|| ApplyDiffError::MissingField {
+                        type_name,
+                        name: name.clone(),
+                    })?;
+                apply_diff(field, field_diff)?;
+            }
+        }
+        ReflectDiff::TupleStruct(changes) => {
+            let value = match value.reflect_mut() {
+                ReflectMut::TupleStruct(value) => value,
+                _ => return Err(mismatched_kinds("tuple struct", value)),
+            };
+            for (index, field_diff) in changes {
+                let type_name = value.type_name().to_string();
+                let field = value
+                    .field_mut(*index)
+                    .ok_or_else(|| out_of_bounds(type_name, *index))?;
+                apply_diff(field, field_diff)?;
+            }
+        }
+        ReflectDiff::Tuple(changes) => {
+            let value = match value.reflect_mut() {
+                ReflectMut::Tuple(value) => value,
+                _ => return Err(mismatched_kinds("tuple", value)),
+            };
+            for (index, field_diff) in changes {
+                let type_name = value.type_name().to_string();
+                let field = value
+                    .field_mut(*index)
+                    .ok_or_else(|| out_of_bounds(type_name, *index))?;
+                apply_diff(field, field_diff)?;
+            }
+        }
+        ReflectDiff::Array(changes) => {
+            let value = match value.reflect_mut() {
+                ReflectMut::Array(value) => value,
+                _ => return Err(mismatched_kinds("array", value)),
+            };
+            for (index, element_diff) in changes {
+                let type_name = value.type_name().to_string();
+                let element = value
+                    .get_mut(*index)
+                    .ok_or_else(|| out_of_bounds(type_name, *index))?;
+                apply_diff(element, element_diff)?;
+            }
+        }
+        ReflectDiff::List(ops) => {
+            let value = match value.reflect_mut() {
+                ReflectMut::List(value) => value,
+                _ => return Err(mismatched_kinds("list", value)),
+            };
+            for op in ops {
+                apply_list_op(value, op)?;
+            }
+        }
+        ReflectDiff::Map(ops) => {
+            let value = match value.reflect_mut() {
+                ReflectMut::Map(value) => value,
+                _ => return Err(mismatched_kinds("map", value)),
+            };
+            for op in ops {
+                match op {
+                    MapDiffOp::Insert { key, value: entry } => {
+                        value.insert_boxed(key.clone_value(), entry.clone_value());
+                    }
+                    MapDiffOp::Remove { key } => {
+                        value.remove(&**key);
+                    }
+                    MapDiffOp::Modify { key, diff } => {
+                        let type_name = value.type_name().to_string();
+                        let entry = value
+                            .get_mut(&**key)
+                            .ok_or(ApplyDiffError::MissingKey { type_name })?;
+                        apply_diff(entry, diff)?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn apply_list_op(list: &mut dyn List, op: &ListDiffOp) -> Result<(), ApplyDiffError> {
+    match op {
+        ListDiffOp::Insert { index, value } => {
+            if *index > list.len() {
+                return Err(out_of_bounds(list.type_name().to_string(), *index));
+            }
+            list.insert(*index, value.clone_value());
+        }
+        ListDiffOp::Remove { index } => {
+            if *index >= list.len() {
+                return Err(out_of_bounds(list.type_name().to_string(), *index));
+            }
+            list.remove(*index);
+        }
+        ListDiffOp::Modify { index, diff } => {
+            let type_name = list.type_name().to_string();
+            let element = list
+                .get_mut(*index)
+                .ok_or_else(|| out_of_bounds(type_name, *index))?;
+            apply_diff(element, diff)?;
+        }
+    }
+    Ok(())
+}
+
+fn mismatched_kinds(expected: &'static str, value: &dyn Reflect) -> ApplyDiffError {
+    ApplyDiffError::MismatchedKinds {
+        expected,
+        type_name: value.type_name().to_string(),
+    }
+}
+
+fn out_of_bounds(type_name: String, index: usize) -> ApplyDiffError {
+    ApplyDiffError::IndexOutOfBounds { type_name, index }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{
+        serde::{ReflectDiffDeserializer, ReflectDiffSerializer},
+        FromReflect, Reflect, TypeRegistry,
+    };
+    use ::serde::de::DeserializeSeed;
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+    struct Item {
+        name: String,
+        count: u32,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Inventory {
+        owner: (String, u8),
+        slots: [u32; 3],
+        items: Vec<Item>,
+        tags: HashMap<String, u32>,
+        weight: f32,
+    }
+
+    fn item(name: &str, count: u32) -> Item {
+        Item {
+            name: name.to_string(),
+            count,
+        }
+    }
+
+    fn inventory() -> Inventory {
+        let mut tags = HashMap::default();
+        tags.insert("a".to_string(), 1);
+        tags.insert("b".to_string(), 2);
+        Inventory {
+            owner: ("alice".to_string(), 3),
+            slots: [1, 2, 3],
+            items: vec![item("sword", 1), item("potion", 4), item("arrow", 20)],
+            tags,
+            weight: 1.5,
+        }
+    }
+
+    #[test]
+    fn diff_equal_values() {
+        let value = inventory();
+        assert!(diff(&value, &value.clone()).is_unchanged());
+    }
+
+    #[test]
+    fn diff_only_changed_fields() {
+        let old = inventory();
+        let mut new = old.clone();
+        new.weight = 2.0;
+        new.slots[1] = 5;
+
+        let changes = match diff(&old, &new) {
+            ReflectDiff::Struct(changes) => changes,
+            diff => panic!("expected a struct diff, got {:?}", diff),
+        };
+        let names: Vec<&str> = changes.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["slots", "weight"]);
+        assert!(matches!(&changes[0].1, ReflectDiff::Array(elements) if elements.len() == 1));
+    }
+
+    #[test]
+    fn diff_list_operations() {
+        let old = vec![1u32, 2, 3, 4];
+        let new = vec![0u32, 1, 3, 5, 4];
+
+        let ops = match diff(&old, &new) {
+            ReflectDiff::List(ops) => ops,
+            diff => panic!("expected a list diff, got {:?}", diff),
+        };
+        assert_eq!(ops.len(), 3);
+        assert!(matches!(ops[0], ListDiffOp::Insert { index: 0, .. }));
+        assert!(matches!(ops[1], ListDiffOp::Remove { index: 2 }));
+        assert!(matches!(ops[2], ListDiffOp::Insert { index: 3, .. }));
+
+        let ops = match diff(&vec![1u32, 2, 3], &vec![1u32, 5, 3]) {
+            ReflectDiff::List(ops) => ops,
+            diff => panic!("expected a list diff, got {:?}", diff),
+        };
+        assert_eq!(ops.len(), 1);
+        assert!(matches!(ops[0], ListDiffOp::Modify { index: 1, .. }));
+
+        let diff = diff(&old, &new);
+        let mut patched = old;
+        apply_diff(&mut patched, &diff).unwrap();
+        assert_eq!(patched, new);
+    }
+
+    #[test]
+    fn diff_large_lists_by_index() {
+        let old: Vec<u32> = (0..2000).collect();
+        let mut new: Vec<u32> = (0..1000).collect();
+        new[5] = 5000;
+
+        let ops = match diff(&old, &new) {
+            ReflectDiff::List(ops) => ops,
+            diff => panic!("expected a list diff, got {:?}", diff),
+        };
+        assert_eq!(ops.len(), 1001);
+        assert!(matches!(ops[0], ListDiffOp::Modify { index: 5, .. }));
+        assert!(matches!(ops[1], ListDiffOp::Remove { index: 1999 }));
+
+        let diff = diff(&old, &new);
+        let mut patched = old;
+        apply_diff(&mut patched, &diff).unwrap();
+        assert_eq!(patched, new);
+    }
+
+    #[test]
+    fn apply_diff_round_trip() {
+        let old = inventory();
+        let mut new = old.clone();
+        new.owner.1 = 4;
+        new.items.remove(0);
+        new.items[0].count = 2;
+        new.items.push(item("shield", 1));
+        new.tags.remove("a");
+        new.tags.insert("b".to_string(), 7);
+        new.tags.insert("c".to_string(), 3);
+
+        let mut patched = old.clone();
+        apply_diff(&mut patched, &diff(&old, &new)).unwrap();
+        assert_eq!(patched, new);
+
+        // Diffs can also be applied backwards.
+        let mut reverted = new.clone();
+        apply_diff(&mut reverted, &diff(&new, &old)).unwrap();
+        assert_eq!(reverted, old);
+    }
+
+    #[test]
+    fn apply_diff_to_map_of_structs() {
+        let mut old = HashMap::default();
+        old.insert("sword".to_string(), item("sword", 1));
+        let mut new = old.clone();
+        new.insert("bow".to_string(), item("bow", 2));
+        new.get_mut("sword").unwrap().count = 3;
+
+        let mut patched = old.clone();
+        apply_diff(&mut patched, &diff(&old, &new)).unwrap();
+        assert_eq!(patched, new);
+    }
+
+    #[test]
+    fn serialize_diff() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<String>();
+        registry.register::<Item>();
+
+        let old = inventory();
+        let mut new = old.clone();
+        new.owner.0 = "bob".to_string();
+        new.items.insert(1, item("bow", 1));
+        new.tags.insert("c".to_string(), 3);
+        new.tags.remove("a");
+
+        let diff = diff(&old, &new);
+        let serialized = ron::to_string(&ReflectDiffSerializer::new(&diff, &registry)).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = ReflectDiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut patched = old;
+        apply_diff(&mut patched, &deserialized).unwrap();
+        assert_eq!(patched, new);
+    }
+
+    #[test]
+    fn apply_diff_to_wrong_kind() {
+        let diff = diff(&vec![1u32], &vec![2u32, 3]);
+        let mut value = inventory();
+        assert_eq!(
+            apply_diff(&mut value, &diff),
+            Err(ApplyDiffError::MismatchedKinds {
+                expected: "list",
+                type_name: std::any::type_name::<Inventory>().to_string(),
+            })
+        );
+    }
+}
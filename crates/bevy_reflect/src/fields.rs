@@ -1,4 +1,4 @@
-use crate::Reflect;
+use crate::{InfoMetadata, Reflect};
 use std::any::{Any, TypeId};
 use std::borrow::Cow;
 
@@ -8,6 +8,7 @@ pub struct NamedField {
     name: Cow<'static, str>,
     type_name: &'static str,
     type_id: TypeId,
+    metadata: InfoMetadata,
 }
 
 impl NamedField {
@@ -17,9 +18,21 @@ impl NamedField {
             name: name.into(),
             type_name: std::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
+            metadata: InfoMetadata::default(),
         }
     }
 
+    /// Attaches the metadata of the named field, from the attributes and doc comments on it.
+    pub fn with_metadata(mut self, metadata: InfoMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// The custom attributes and docs of this named field.
+    pub fn metadata(&self) -> &InfoMetadata {
+        &self.metadata
+    }
+
     /// The name of the field.
     pub fn name(&self) -> &Cow<'static, str> {
         &self.name
@@ -49,6 +62,7 @@ pub struct UnnamedField {
     index: usize,
     type_name: &'static str,
     type_id: TypeId,
+    metadata: InfoMetadata,
 }
 
 impl UnnamedField {
@@ -57,9 +71,21 @@ impl UnnamedField {
             index,
             type_name: std::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
+            metadata: InfoMetadata::default(),
         }
     }
 
+    /// Attaches the metadata of the tuple field, from the attributes and doc comments on it.
+    pub fn with_metadata(mut self, metadata: InfoMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// The custom attributes and docs of this tuple field.
+    pub fn metadata(&self) -> &InfoMetadata {
+        &self.metadata
+    }
+
     /// Returns the index of the field.
     pub fn index(&self) -> usize {
         self.index
@@ -0,0 +1,182 @@
+use crate::func::{ArgInfo, FunctionError, Ownership};
+use crate::{FromReflect, Reflect};
+use std::collections::VecDeque;
+
+/// A single argument passed to a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+pub enum Arg<'a> {
+    /// An argument passed by value.
+    Owned(Box<dyn Reflect>),
+    /// An argument passed by reference.
+    Ref(&'a dyn Reflect),
+    /// An argument passed by mutable reference.
+    Mut(&'a mut dyn Reflect),
+}
+
+impl<'a> Arg<'a> {
+    /// How this argument is passed.
+    pub fn ownership(&self) -> Ownership {
+        match self {
+            Arg::Owned(_) => Ownership::Owned,
+            Arg::Ref(_) => Ownership::Ref,
+            Arg::Mut(_) => Ownership::Mut,
+        }
+    }
+
+    /// Returns the argument value as a `&dyn Reflect`.
+    pub fn value(&self) -> &dyn Reflect {
+        match self {
+            Arg::Owned(value) => value.as_ref(),
+            Arg::Ref(value) => *value,
+            Arg::Mut(value) => *value,
+        }
+    }
+
+    /// Converts the argument into a value of type `T`.
+    ///
+    /// Values of type `T` passed by value are moved out. Anything else, such as
+    /// a dynamic representation of `T` or a borrowed value, is converted using
+    /// [`FromReflect`].
+    pub fn take_owned<T: FromReflect>(self, info: &ArgInfo) -> Result<T, FunctionError> {
+        check_type(self.value(), info)?;
+        let value = match self {
+            Arg::Owned(value) => match value.take::<T>() {
+                Ok(value) => return Ok(value),
+                Err(value) => T::from_reflect(value.as_ref()),
+            },
+            Arg::Ref(value) => T::from_reflect(value),
+            Arg::Mut(value) => T::from_reflect(value),
+        };
+        value.ok_or_else(|| invalid_type(info, info.type_name()))
+    }
+
+    /// Borrows the argument as a `&T`.
+    ///
+    /// The argument must be passed by reference or mutable reference.
+    pub fn take_ref<T: Reflect>(self, info: &ArgInfo) -> Result<&'a T, FunctionError> {
+        check_type(self.value(), info)?;
+        let value: &'a dyn Reflect = match self {
+            Arg::Ref(value) => value,
+            Arg::Mut(value) => value,
+            Arg::Owned(_) => return Err(invalid_ownership(info, Ownership::Owned)),
+        };
+        value
+            .downcast_ref::<T>()
+            .ok_or_else(|| invalid_type(info, value.type_name()))
+    }
+
+    /// Borrows the argument as a `&mut T`.
+    ///
+    /// The argument must be passed by mutable reference.
+    pub fn take_mut<T: Reflect>(self, info: &ArgInfo) -> Result<&'a mut T, FunctionError> {
+        check_type(self.value(), info)?;
+        match self {
+            // Dynamic types can share the type name of `T` without being a `T`.
+            Arg::Mut(value) if !value.is::<T>() => Err(invalid_type(info, value.type_name())),
+            Arg::Mut(value) => Ok(value.downcast_mut::<T>().unwrap()),
+            arg => Err(invalid_ownership(info, arg.ownership())),
+        }
+    }
+}
+
+fn check_type(value: &dyn Reflect, info: &ArgInfo) -> Result<(), FunctionError> {
+    if value.type_name() == info.type_name() {
+        Ok(())
+    } else {
+        Err(invalid_type(info, value.type_name()))
+    }
+}
+
+fn invalid_type(info: &ArgInfo, received: &str) -> FunctionError {
+    FunctionError::InvalidArgType {
+        index: info.index(),
+        expected: info.type_name().into(),
+        received: received.to_string().into(),
+    }
+}
+
+fn invalid_ownership(info: &ArgInfo, received: Ownership) -> FunctionError {
+    FunctionError::InvalidOwnership {
+        index: info.index(),
+        expected: info.ownership(),
+        received,
+    }
+}
+
+/// An ordered list of arguments passed to a [`DynamicFunction`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::ArgList;
+/// let name = String::from("Ferris");
+/// let mut count = 0_usize;
+///
+/// let args = ArgList::new()
+///     .with_ref(&name)
+///     .with_mut(&mut count)
+///     .with_owned(1.5_f32);
+/// assert_eq!(args.len(), 3);
+/// ```
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Default)]
+pub struct ArgList<'a> {
+    args: VecDeque<Arg<'a>>,
+}
+
+impl<'a> ArgList<'a> {
+    /// Create an empty [`ArgList`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an argument.
+    pub fn push(&mut self, arg: Arg<'a>) {
+        self.args.push_back(arg);
+    }
+
+    /// Appends an argument passed by value.
+    pub fn with_owned<T: Reflect>(self, value: T) -> Self {
+        self.with_boxed(Box::new(value))
+    }
+
+    /// Appends a boxed argument passed by value.
+    pub fn with_boxed(mut self, value: Box<dyn Reflect>) -> Self {
+        self.push(Arg::Owned(value));
+        self
+    }
+
+    /// Appends an argument passed by reference.
+    pub fn with_ref(mut self, value: &'a dyn Reflect) -> Self {
+        self.push(Arg::Ref(value));
+        self
+    }
+
+    /// Appends an argument passed by mutable reference.
+    pub fn with_mut(mut self, value: &'a mut dyn Reflect) -> Self {
+        self.push(Arg::Mut(value));
+        self
+    }
+
+    /// Removes and returns the first argument.
+    pub fn pop_front(&mut self) -> Option<Arg<'a>> {
+        self.args.pop_front()
+    }
+
+    /// The number of arguments in the list.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns `true` if the list contains no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Returns an iterator over the arguments.
+    pub fn iter(&self) -> impl Iterator<Item = &Arg<'a>> {
+        self.args.iter()
+    }
+}
@@ -0,0 +1,31 @@
+use crate::func::Ownership;
+use std::borrow::Cow;
+use thiserror::Error;
+
+/// An error returned when calling a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FunctionError {
+    #[error("expected {expected} arguments but received {received}")]
+    ArgCount { expected: usize, received: usize },
+    #[error("expected argument {index} to be of type `{expected}` but received `{received}`")]
+    InvalidArgType {
+        index: usize,
+        expected: Cow<'static, str>,
+        received: Cow<'static, str>,
+    },
+    #[error("expected argument {index} to be passed {expected} but it was passed {received}")]
+    InvalidOwnership {
+        index: usize,
+        expected: Ownership,
+        received: Ownership,
+    },
+    #[error("no method named `{0}` is registered")]
+    UnknownMethod(Cow<'static, str>),
+}
+
+/// The result of calling a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+pub type FunctionResult = Result<Box<dyn crate::Reflect>, FunctionError>;
@@ -0,0 +1,81 @@
+use crate::func::{ArgList, FunctionError, FunctionInfo, FunctionResult};
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+type BoxedFunction =
+    dyn for<'a> Fn(ArgList<'a>, &FunctionInfo) -> FunctionResult + Send + Sync + 'static;
+
+/// A function or closure that can be called with arguments only known at runtime.
+///
+/// Most functions can be turned into a [`DynamicFunction`] using [`IntoFunction`].
+/// [`DynamicFunction::new`] can be used for anything else, such as functions with a
+/// variable number of arguments.
+///
+/// Arguments are checked against the [`FunctionInfo`] of the function: a call fails with a
+/// [`FunctionError`] if the number of arguments, the type of an argument or the way it is
+/// passed does not match.
+///
+/// [`IntoFunction`]: crate::func::IntoFunction
+#[derive(Clone)]
+pub struct DynamicFunction {
+    info: FunctionInfo,
+    func: Arc<BoxedFunction>,
+}
+
+impl DynamicFunction {
+    /// Create a new [`DynamicFunction`] from a closure operating on an [`ArgList`].
+    ///
+    /// The closure is only called once the number of arguments has been checked against
+    /// `info`, but it is responsible for checking their types.
+    pub fn new<F>(func: F, info: FunctionInfo) -> Self
+    where
+        F: for<'a> Fn(ArgList<'a>, &FunctionInfo) -> FunctionResult + Send + Sync + 'static,
+    {
+        Self {
+            info,
+            func: Arc::new(func),
+        }
+    }
+
+    /// Sets the name of the function.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.info = self.info.with_name(name);
+        self
+    }
+
+    /// The name of the function, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        self.info.name()
+    }
+
+    /// The [`FunctionInfo`] of the function.
+    pub fn info(&self) -> &FunctionInfo {
+        &self.info
+    }
+
+    /// Calls the function with the given arguments.
+    pub fn call(&self, args: ArgList) -> FunctionResult {
+        if args.len() != self.info.arg_count() {
+            return Err(FunctionError::ArgCount {
+                expected: self.info.arg_count(),
+                received: args.len(),
+            });
+        }
+        (self.func)(args, &self.info)
+    }
+}
+
+impl Debug for DynamicFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = self.info.name().unwrap_or("_");
+        write!(f, "DynamicFunction(fn {}(", name)?;
+        for (index, arg) in self.info.args().iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", arg.type_name())?;
+        }
+        write!(f, ") -> {})", self.info.return_type_name())
+    }
+}
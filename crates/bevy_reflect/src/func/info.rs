@@ -0,0 +1,151 @@
+use crate::{TypeInfo, Typed};
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+/// How an argument is passed to a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ownership {
+    /// The argument is passed as `&T`.
+    Ref,
+    /// The argument is passed as `&mut T`.
+    Mut,
+    /// The argument is passed as `T`.
+    Owned,
+}
+
+impl Display for Ownership {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ownership::Ref => f.write_str("by reference"),
+            Ownership::Mut => f.write_str("by mutable reference"),
+            Ownership::Owned => f.write_str("by value"),
+        }
+    }
+}
+
+/// Type information for an argument of a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct ArgInfo {
+    index: usize,
+    name: Option<Cow<'static, str>>,
+    ownership: Ownership,
+    type_info: &'static TypeInfo,
+}
+
+impl ArgInfo {
+    /// Create a new [`ArgInfo`] for an argument of type `T`.
+    ///
+    /// The index is set when the argument is added to a [`FunctionInfo`].
+    pub fn new<T: Typed>(ownership: Ownership) -> Self {
+        Self {
+            index: 0,
+            name: None,
+            ownership,
+            type_info: T::type_info(),
+        }
+    }
+
+    /// Sets the name of the argument.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The position of the argument in the argument list.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The name of the argument, if one was given.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// How the argument is passed.
+    pub fn ownership(&self) -> Ownership {
+        self.ownership
+    }
+
+    /// The [`TypeInfo`] of the argument.
+    pub fn type_info(&self) -> &'static TypeInfo {
+        self.type_info
+    }
+
+    /// The [type name] of the argument.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn type_name(&self) -> &'static str {
+        self.type_info.type_name()
+    }
+
+    /// The [`TypeId`] of the argument.
+    pub fn type_id(&self) -> TypeId {
+        self.type_info.type_id()
+    }
+}
+
+/// Type information for a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct FunctionInfo {
+    name: Option<Cow<'static, str>>,
+    args: Vec<ArgInfo>,
+    return_type_name: &'static str,
+    return_type_id: TypeId,
+}
+
+impl FunctionInfo {
+    /// Create a new [`FunctionInfo`] for a function with the given arguments returning `R`.
+    pub fn new<R: 'static>(args: Vec<ArgInfo>) -> Self {
+        let args = args
+            .into_iter()
+            .enumerate()
+            .map(|(index, arg)| ArgInfo { index, ..arg })
+            .collect();
+        Self {
+            name: None,
+            args,
+            return_type_name: std::any::type_name::<R>(),
+            return_type_id: TypeId::of::<R>(),
+        }
+    }
+
+    /// Sets the name of the function.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The name of the function, if one was given.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The arguments of the function, in order.
+    pub fn args(&self) -> &[ArgInfo] {
+        &self.args
+    }
+
+    /// The number of arguments the function takes.
+    pub fn arg_count(&self) -> usize {
+        self.args.len()
+    }
+
+    /// The [type name] of the value returned by the function.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn return_type_name(&self) -> &'static str {
+        self.return_type_name
+    }
+
+    /// The [`TypeId`] of the value returned by the function.
+    pub fn return_type_id(&self) -> TypeId {
+        self.return_type_id
+    }
+}
@@ -0,0 +1,315 @@
+use crate::func::{Arg, ArgInfo, ArgList, DynamicFunction, FunctionInfo, Ownership};
+use crate::{FromReflect, Reflect, Typed};
+
+/// A trait for converting functions and closures into a [`DynamicFunction`].
+///
+/// This is implemented for functions and closures taking up to 8 arguments by value, which
+/// must implement [`FromReflect`] and [`Typed`], and returning a [`Reflect`] value.
+/// It is also implemented for methods taking `&self` or `&mut self` followed by up to
+/// 7 arguments by value, which are called with the receiver passed by reference.
+///
+/// Closures need explicit argument types, since the `Marker` type parameter is inferred from
+/// them. Functions returning references are not supported.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::Reflect;
+/// # use bevy_reflect::func::{ArgList, IntoFunction};
+/// #[derive(Reflect)]
+/// struct Counter {
+///     count: usize,
+/// }
+///
+/// impl Counter {
+///     fn increment(&mut self, amount: usize) {
+///         self.count += amount;
+///     }
+/// }
+///
+/// let increment = Counter::increment.into_function();
+///
+/// let mut counter = Counter { count: 1 };
+/// increment
+///     .call(ArgList::new().with_mut(&mut counter).with_owned(2_usize))
+///     .unwrap();
+/// assert_eq!(counter.count, 3);
+/// ```
+pub trait IntoFunction<Marker> {
+    /// Converts `self` into a [`DynamicFunction`].
+    fn into_function(self) -> DynamicFunction;
+}
+
+#[doc(hidden)]
+pub struct OwnedArgs;
+
+#[doc(hidden)]
+pub struct RefReceiver;
+
+#[doc(hidden)]
+pub struct MutReceiver;
+
+/// Pops the next argument along with its [`ArgInfo`].
+///
+/// The argument count has already been checked by [`DynamicFunction::call`].
+fn next_arg<'a, 'i>(
+    args: &mut ArgList<'a>,
+    infos: &mut std::slice::Iter<'i, ArgInfo>,
+) -> (Arg<'a>, &'i ArgInfo) {
+    let arg = args.pop_front().expect("argument count should be checked");
+    let info = infos.next().expect("argument count should be checked");
+    (arg, info)
+}
+
+macro_rules! impl_into_function {
+    ($($arg:ident),*) => {
+        impl<F, R, $($arg,)*> IntoFunction<(OwnedArgs, fn($($arg,)*) -> R)> for F
+        where
+            F: Fn($($arg),*) -> R + Send + Sync + 'static,
+            R: Reflect,
+            $($arg: FromReflect + Typed,)*
+        {
+            #[allow(non_snake_case, unused_mut, unused_variables)]
+            fn into_function(self) -> DynamicFunction {
+                let info = FunctionInfo::new::<R>(vec![
+                    $(ArgInfo::new::<$arg>(Ownership::Owned),)*
+                ])
+                .with_name(std::any::type_name::<F>());
+                DynamicFunction::new(
+                    move |mut args, info| {
+                        let mut infos = info.args().iter();
+                        $(
+                            let (arg, arg_info) = next_arg(&mut args, &mut infos);
+                            let $arg = arg.take_owned::<$arg>(arg_info)?;
+                        )*
+                        Ok(Box::new(self($($arg),*)))
+                    },
+                    info,
+                )
+            }
+        }
+    };
+}
+
+macro_rules! impl_into_method {
+    ($($arg:ident),*) => {
+        impl<F, R, Receiver, $($arg,)*> IntoFunction<(RefReceiver, fn(Receiver, $($arg,)*) -> R)> for F
+        where
+            F: for<'r> Fn(&'r Receiver, $($arg),*) -> R + Send + Sync + 'static,
+            Receiver: Reflect + Typed,
+            R: Reflect,
+            $($arg: FromReflect + Typed,)*
+        {
+            #[allow(non_snake_case, unused_mut)]
+            fn into_function(self) -> DynamicFunction {
+                let info = FunctionInfo::new::<R>(vec![
+                    ArgInfo::new::<Receiver>(Ownership::Ref).with_name("self"),
+                    $(ArgInfo::new::<$arg>(Ownership::Owned),)*
+                ])
+                .with_name(std::any::type_name::<F>());
+                DynamicFunction::new(
+                    move |mut args, info| {
+                        let mut infos = info.args().iter();
+                        let (arg, arg_info) = next_arg(&mut args, &mut infos);
+                        let receiver = arg.take_ref::<Receiver>(arg_info)?;
+                        $(
+                            let (arg, arg_info) = next_arg(&mut args, &mut infos);
+                            let $arg = arg.take_owned::<$arg>(arg_info)?;
+                        )*
+                        Ok(Box::new(self(receiver, $($arg),*)))
+                    },
+                    info,
+                )
+            }
+        }
+
+        impl<F, R, Receiver, $($arg,)*> IntoFunction<(MutReceiver, fn(Receiver, $($arg,)*) -> R)> for F
+        where
+            F: for<'r> Fn(&'r mut Receiver, $($arg),*) -> R + Send + Sync + 'static,
+            Receiver: Reflect + Typed,
+            R: Reflect,
+            $($arg: FromReflect + Typed,)*
+        {
+            #[allow(non_snake_case, unused_mut)]
+            fn into_function(self) -> DynamicFunction {
+                let info = FunctionInfo::new::<R>(vec![
+                    ArgInfo::new::<Receiver>(Ownership::Mut).with_name("self"),
+                    $(ArgInfo::new::<$arg>(Ownership::Owned),)*
+                ])
+                .with_name(std::any::type_name::<F>());
+                DynamicFunction::new(
+                    move |mut args, info| {
+                        let mut infos = info.args().iter();
+                        let (arg, arg_info) = next_arg(&mut args, &mut infos);
+                        let receiver = arg.take_mut::<Receiver>(arg_info)?;
+                        $(
+                            let (arg, arg_info) = next_arg(&mut args, &mut infos);
+                            let $arg = arg.take_owned::<$arg>(arg_info)?;
+                        )*
+                        Ok(Box::new(self(receiver, $($arg),*)))
+                    },
+                    info,
+                )
+            }
+        }
+    };
+}
+
+impl_into_function!();
+impl_into_function!(A);
+impl_into_function!(A, B);
+impl_into_function!(A, B, C);
+impl_into_function!(A, B, C, D);
+impl_into_function!(A, B, C, D, E);
+impl_into_function!(A, B, C, D, E, G);
+impl_into_function!(A, B, C, D, E, G, H);
+impl_into_function!(A, B, C, D, E, G, H, I);
+
+impl_into_method!();
+impl_into_method!(A);
+impl_into_method!(A, B);
+impl_into_method!(A, B, C);
+impl_into_method!(A, B, C, D);
+impl_into_method!(A, B, C, D, E);
+impl_into_method!(A, B, C, D, E, G);
+impl_into_method!(A, B, C, D, E, G, H);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::func::FunctionError;
+    use crate::DynamicStruct;
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl Point {
+        fn length_squared(&self) -> i32 {
+            self.x * self.x + self.y * self.y
+        }
+
+        fn translate(&mut self, dx: i32, dy: i32) {
+            self.x += dx;
+            self.y += dy;
+        }
+    }
+
+    #[test]
+    fn should_call_function() {
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        let function = add.into_function();
+        assert_eq!(function.info().arg_count(), 2);
+        assert_eq!(function.info().return_type_name(), "i32");
+
+        let result = function
+            .call(ArgList::new().with_owned(1_i32).with_owned(2_i32))
+            .unwrap();
+        assert_eq!(result.downcast_ref::<i32>(), Some(&3));
+    }
+
+    #[test]
+    fn should_call_closure() {
+        let offset = 10;
+        let function = (move |value: i32| value + offset).into_function();
+
+        let result = function.call(ArgList::new().with_owned(5_i32)).unwrap();
+        assert_eq!(result.downcast_ref::<i32>(), Some(&15));
+
+        let function = (|| String::from("hello")).into_function();
+        let result = function.call(ArgList::new()).unwrap();
+        assert_eq!(result.downcast_ref::<String>().unwrap(), "hello");
+    }
+
+    #[test]
+    fn should_call_methods() {
+        let mut point = Point { x: 3, y: 4 };
+
+        let length_squared = Point::length_squared.into_function();
+        assert_eq!(length_squared.info().args()[0].ownership(), Ownership::Ref);
+        let result = length_squared
+            .call(ArgList::new().with_ref(&point))
+            .unwrap();
+        assert_eq!(result.downcast_ref::<i32>(), Some(&25));
+
+        let translate = Point::translate.into_function();
+        assert_eq!(translate.info().args()[0].ownership(), Ownership::Mut);
+        let result = translate
+            .call(
+                ArgList::new()
+                    .with_mut(&mut point)
+                    .with_owned(1_i32)
+                    .with_owned(-1_i32),
+            )
+            .unwrap();
+        assert!(result.is::<()>());
+        assert_eq!(point, Point { x: 4, y: 3 });
+    }
+
+    #[test]
+    fn should_convert_arguments_with_from_reflect() {
+        let function = (|point: Point| point.x - point.y).into_function();
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.set_name(std::any::type_name::<Point>().to_string());
+        dynamic.insert("x", 7_i32);
+        dynamic.insert("y", 2_i32);
+
+        let result = function.call(ArgList::new().with_ref(&dynamic)).unwrap();
+        assert_eq!(result.downcast_ref::<i32>(), Some(&5));
+    }
+
+    #[test]
+    fn should_check_arguments() {
+        let function = Point::translate.into_function();
+        let mut point = Point { x: 0, y: 0 };
+
+        assert_eq!(
+            function
+                .call(ArgList::new().with_mut(&mut point))
+                .unwrap_err(),
+            FunctionError::ArgCount {
+                expected: 3,
+                received: 1
+            }
+        );
+
+        assert_eq!(
+            function
+                .call(
+                    ArgList::new()
+                        .with_mut(&mut point)
+                        .with_owned(1_i32)
+                        .with_owned(1.0_f32)
+                )
+                .unwrap_err(),
+            FunctionError::InvalidArgType {
+                index: 2,
+                expected: "i32".into(),
+                received: "f32".into(),
+            }
+        );
+
+        assert_eq!(
+            function
+                .call(
+                    ArgList::new()
+                        .with_ref(&Point { x: 0, y: 0 })
+                        .with_owned(1_i32)
+                        .with_owned(1_i32)
+                )
+                .unwrap_err(),
+            FunctionError::InvalidOwnership {
+                index: 0,
+                expected: Ownership::Mut,
+                received: Ownership::Ref,
+            }
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use crate::func::{ArgList, DynamicFunction, FunctionError, FunctionResult};
+use bevy_utils::HashMap;
+use std::borrow::Cow;
+
+/// Type data holding the methods of a type that can be called by name.
+///
+/// This is inserted into the [`TypeRegistration`] of a type deriving `Reflect` with the
+/// `#[reflect(methods(...))]` attribute, which registers the listed inherent methods:
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_reflect::func::{ArgList, ReflectMethods};
+/// #[derive(Reflect)]
+/// #[reflect(methods(area))]
+/// struct Rect {
+///     width: f32,
+///     height: f32,
+/// }
+///
+/// impl Rect {
+///     fn area(&self) -> f32 {
+///         self.width * self.height
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Rect>();
+///
+/// let rect = Rect { width: 2.0, height: 3.0 };
+/// let methods = registry.get_type_data::<ReflectMethods>(std::any::TypeId::of::<Rect>()).unwrap();
+/// let area = methods.call("area", ArgList::new().with_ref(&rect)).unwrap();
+/// assert_eq!(area.downcast_ref::<f32>(), Some(&6.0));
+/// ```
+///
+/// [`TypeRegistration`]: crate::TypeRegistration
+#[derive(Clone, Debug, Default)]
+pub struct ReflectMethods {
+    methods: HashMap<Cow<'static, str>, DynamicFunction>,
+}
+
+impl ReflectMethods {
+    /// Registers a method under the given name, replacing any method with the same name.
+    pub fn insert(&mut self, name: impl Into<Cow<'static, str>>, function: DynamicFunction) {
+        let name = name.into();
+        let function = function.with_name(name.clone());
+        self.methods.insert(name, function);
+    }
+
+    /// Returns the method with the given name.
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction> {
+        self.methods.get(name)
+    }
+
+    /// Calls the method with the given name.
+    ///
+    /// Methods taking `self` expect the receiver as their first argument.
+    pub fn call(&self, name: &str, args: ArgList) -> FunctionResult {
+        self.get(name)
+            .ok_or_else(|| FunctionError::UnknownMethod(name.to_string().into()))?
+            .call(args)
+    }
+
+    /// Returns an iterator over the names of the registered methods.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.methods.keys().map(|name| name.as_ref())
+    }
+
+    /// The number of registered methods.
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    /// Returns `true` if no methods are registered.
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+}
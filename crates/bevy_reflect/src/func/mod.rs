@@ -0,0 +1,38 @@
+//! Reflection-based function calls.
+//!
+//! A [`DynamicFunction`] wraps a Rust function or closure so that it can be called with
+//! arguments only known at runtime, such as values coming from a script or a console command.
+//! Arguments are passed as an [`ArgList`] and checked against the [`FunctionInfo`] of the
+//! function before it is called.
+//!
+//! ```
+//! # use bevy_reflect::func::{ArgList, IntoFunction};
+//! fn add(a: i32, b: i32) -> i32 {
+//!     a + b
+//! }
+//!
+//! let function = add.into_function();
+//! let args = ArgList::new().with_owned(25_i32).with_owned(75_i32);
+//! let result = function.call(args).unwrap();
+//! assert_eq!(result.downcast_ref::<i32>(), Some(&100));
+//! ```
+//!
+//! Inherent methods of a type can be registered in its [`TypeRegistration`] using
+//! `#[reflect(methods(...))]`, after which they can be looked up by name through the
+//! [`ReflectMethods`] type data.
+//!
+//! [`TypeRegistration`]: crate::TypeRegistration
+
+mod args;
+mod error;
+mod function;
+mod info;
+mod into_function;
+mod methods;
+
+pub use args::*;
+pub use error::*;
+pub use function::*;
+pub use info::*;
+pub use into_function::*;
+pub use methods::*;
@@ -3,7 +3,8 @@ use std::any::Any;
 
 use crate::utility::GenericTypeInfoCell;
 use crate::{
-    Array, ArrayIter, FromReflect, List, ListInfo, Reflect, ReflectMut, ReflectRef, TypeInfo, Typed,
+    Array, ArrayIter, FromReflect, GetTypeRegistration, List, ListInfo, Reflect, ReflectMut,
+    ReflectRef, TypeInfo, TypeRegistration, TypeRegistry, Typed,
 };
 
 impl<T: smallvec::Array + Send + Sync + 'static> Array for SmallVec<T>
@@ -53,6 +54,22 @@ where
         });
         SmallVec::push(self, value);
     }
+
+    fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+        let element = element.take::<T::Item>().unwrap_or_else(|element| {
+            <T as smallvec::Array>::Item::from_reflect(&*element).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to insert invalid value of type {}.",
+                    element.type_name()
+                )
+            })
+        });
+        SmallVec::insert(self, index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        Box::new(SmallVec::remove(self, index))
+    }
 }
 
 // SAFE: any and any_mut both return self
@@ -120,6 +137,19 @@ where
     }
 }
 
+impl<T: smallvec::Array + Send + Sync + 'static> GetTypeRegistration for SmallVec<T>
+where
+    T::Item: FromReflect + Clone + GetTypeRegistration,
+{
+    fn get_type_registration() -> TypeRegistration {
+        TypeRegistration::of::<SmallVec<T>>()
+    }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register_dependency::<T::Item>();
+    }
+}
+
 impl<T: smallvec::Array + Send + Sync + 'static> FromReflect for SmallVec<T>
 where
     T::Item: FromReflect + Clone,
@@ -1,8 +1,9 @@
 use crate as bevy_reflect;
 use crate::{
-    map_partial_eq, Array, ArrayInfo, ArrayIter, DynamicMap, FromReflect, FromType,
-    GetTypeRegistration, List, ListInfo, Map, MapInfo, MapIter, Reflect, ReflectDeserialize,
-    ReflectMut, ReflectRef, ReflectSerialize, TypeInfo, TypeRegistration, Typed, ValueInfo,
+    map_partial_eq, take_deserialized, Array, ArrayInfo, ArrayIter, DynamicMap, FromReflect,
+    FromType, GetTypeRegistration, List, ListInfo, Map, MapInfo, MapIter, Reflect,
+    ReflectDeserialize, ReflectMut, ReflectRef, ReflectSerialize, TypeInfo, TypeRegistration,
+    TypeRegistry, Typed, ValueInfo,
 };
 
 use crate::utility::{GenericTypeInfoCell, NonGenericTypeInfoCell};
@@ -103,6 +104,22 @@ impl<T: FromReflect> List for Vec<T> {
         });
         Vec::push(self, value);
     }
+
+    fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+        let element = element.take::<T>().unwrap_or_else(|element| {
+            T::from_reflect(&*element).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to insert invalid value of type {}.",
+                    element.type_name()
+                )
+            })
+        });
+        Vec::insert(self, index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        Box::new(Vec::remove(self, index))
+    }
 }
 
 // SAFE: any and any_mut both return self
@@ -168,12 +185,32 @@ impl<T: FromReflect> Typed for Vec<T> {
     }
 }
 
-impl<T: FromReflect + for<'de> Deserialize<'de>> GetTypeRegistration for Vec<T> {
+impl<T: FromReflect + GetTypeRegistration> GetTypeRegistration for Vec<T> {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Vec<T>>();
-        registration.insert::<ReflectDeserialize>(FromType::<Vec<T>>::from_type());
+        if ReflectDeserialize::of::<T>().is_some() {
+            registration.insert(ReflectDeserialize {
+                func: |deserializer| {
+                    let element = ReflectDeserialize::of::<T>().unwrap();
+                    let values = ReflectDeserialize::deserialize_seq(
+                        deserializer,
+                        std::iter::repeat(element),
+                        None,
+                    )?;
+                    let list = values
+                        .into_iter()
+                        .map(take_deserialized::<T, _>)
+                        .collect::<Result<Vec<T>, _>>()?;
+                    Ok(Box::new(list))
+                },
+            });
+        }
         registration
     }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register_dependency::<T>();
+    }
 }
 
 impl<T: FromReflect> FromReflect for Vec<T> {
@@ -190,7 +227,7 @@ impl<T: FromReflect> FromReflect for Vec<T> {
     }
 }
 
-impl<K: Reflect + Eq + Hash, V: Reflect> Map for HashMap<K, V> {
+impl<K: FromReflect + Eq + Hash, V: FromReflect> Map for HashMap<K, V> {
     fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
         key.downcast_ref::<K>()
             .and_then(|key| HashMap::get(self, key))
@@ -228,10 +265,40 @@ impl<K: Reflect + Eq + Hash, V: Reflect> Map for HashMap<K, V> {
         }
         dynamic_map
     }
+
+    fn insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Option<Box<dyn Reflect>> {
+        let key = key.take::<K>().unwrap_or_else(|key| {
+            K::from_reflect(&*key).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to insert invalid key of type {}.",
+                    key.type_name()
+                )
+            })
+        });
+        let value = value.take::<V>().unwrap_or_else(|value| {
+            V::from_reflect(&*value).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to insert invalid value of type {}.",
+                    value.type_name()
+                )
+            })
+        });
+        HashMap::insert(self, key, value).map(|old_value| Box::new(old_value) as Box<dyn Reflect>)
+    }
+
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        key.downcast_ref::<K>()
+            .and_then(|key| HashMap::remove(self, key))
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
 }
 
 // SAFE: any and any_mut both return self
-unsafe impl<K: Reflect + Eq + Hash, V: Reflect> Reflect for HashMap<K, V> {
+unsafe impl<K: FromReflect + Eq + Hash, V: FromReflect> Reflect for HashMap<K, V> {
     fn type_name(&self) -> &str {
         std::any::type_name::<Self>()
     }
@@ -290,7 +357,7 @@ unsafe impl<K: Reflect + Eq + Hash, V: Reflect> Reflect for HashMap<K, V> {
     }
 }
 
-impl<K: Reflect + Eq + Hash, V: Reflect> Typed for HashMap<K, V> {
+impl<K: FromReflect + Eq + Hash, V: FromReflect> Typed for HashMap<K, V> {
     fn type_info() -> &'static TypeInfo {
         static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
         CELL.get_or_insert::<Self, _>(|| TypeInfo::Map(MapInfo::new::<Self, K, V>()))
@@ -299,14 +366,34 @@ impl<K: Reflect + Eq + Hash, V: Reflect> Typed for HashMap<K, V> {
 
 impl<K, V> GetTypeRegistration for HashMap<K, V>
 where
-    K: Reflect + Clone + Eq + Hash + for<'de> Deserialize<'de>,
-    V: Reflect + Clone + for<'de> Deserialize<'de>,
+    K: FromReflect + GetTypeRegistration + Clone + Eq + Hash,
+    V: FromReflect + GetTypeRegistration + Clone,
 {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Self>();
-        registration.insert::<ReflectDeserialize>(FromType::<Self>::from_type());
+        if ReflectDeserialize::of::<K>().is_some() && ReflectDeserialize::of::<V>().is_some() {
+            registration.insert(ReflectDeserialize {
+                func: |deserializer| {
+                    let entries = ReflectDeserialize::deserialize_map(
+                        deserializer,
+                        ReflectDeserialize::of::<K>().unwrap(),
+                        ReflectDeserialize::of::<V>().unwrap(),
+                    )?;
+                    let mut map = Self::with_capacity(entries.len());
+                    for (key, value) in entries {
+                        map.insert(take_deserialized(key)?, take_deserialized(value)?);
+                    }
+                    Ok(Box::new(map))
+                },
+            });
+        }
         registration
     }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register_dependency::<K>();
+        registry.register_dependency::<V>();
+    }
 }
 
 impl<K: FromReflect + Eq + Hash, V: FromReflect> FromReflect for HashMap<K, V> {
@@ -439,30 +526,33 @@ impl<T: Reflect, const N: usize> Typed for [T; N] {
     }
 }
 
-// TODO:
-// `FromType::from_type` requires `Deserialize<'de>` to be implemented for `T`.
-// Currently serde only supports `Deserialize<'de>` for arrays up to size 32.
-// This can be changed to use const generics once serde utilizes const generics for arrays.
-// Tracking issue: https://github.com/serde-rs/serde/issues/1937
-macro_rules! impl_array_get_type_registration {
-    ($($N:expr)+) => {
-        $(
-            impl<T: Reflect + for<'de> Deserialize<'de>> GetTypeRegistration for [T; $N] {
-                fn get_type_registration() -> TypeRegistration {
-                    let mut registration = TypeRegistration::of::<[T; $N]>();
-                    registration.insert::<ReflectDeserialize>(FromType::<[T; $N]>::from_type());
-                    registration
-                }
-            }
-        )+
-    };
-}
+impl<T: Reflect + GetTypeRegistration, const N: usize> GetTypeRegistration for [T; N] {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<[T; N]>();
+        if ReflectDeserialize::of::<T>().is_some() {
+            registration.insert(ReflectDeserialize {
+                func: |deserializer| {
+                    let element = ReflectDeserialize::of::<T>().unwrap();
+                    let values = ReflectDeserialize::deserialize_seq(
+                        deserializer,
+                        vec![element; N].into_iter(),
+                        Some(N),
+                    )?;
+                    let array = values
+                        .into_iter()
+                        .map(take_deserialized::<T, _>)
+                        .collect::<Result<Vec<T>, _>>()?;
+                    let array: [T; N] = array.try_into().unwrap_or_else(|_| unreachable!());
+                    Ok(Box::new(array))
+                },
+            });
+        }
+        registration
+    }
 
-impl_array_get_type_registration! {
-     0  1  2  3  4  5  6  7  8  9
-    10 11 12 13 14 15 16 17 18 19
-    20 21 22 23 24 25 26 27 28 29
-    30 31 32
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register_dependency::<T>();
+    }
 }
 
 // SAFE: any and any_mut both return self
@@ -1,6 +1,8 @@
 #![doc = include_str!("../README.md")]
 
 mod array;
+mod attributes;
+mod diff;
 mod fields;
 mod list;
 mod map;
@@ -26,6 +28,7 @@ mod impls {
     pub use self::std::*;
 }
 
+pub mod func;
 pub mod serde;
 pub mod std_traits;
 pub mod utility;
@@ -40,6 +43,8 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use attributes::*;
+pub use diff::*;
 pub use fields::*;
 pub use impls::*;
 pub use list::*;
@@ -58,7 +63,64 @@ pub use erased_serde;
 
 #[doc(hidden)]
 pub mod __macro_exports {
-    use crate::Uuid;
+    use crate::{GetTypeRegistration, TypeRegistry, Uuid};
+    use std::marker::PhantomData;
+
+    #[cfg(feature = "auto_register")]
+    pub use inventory;
+
+    /// A field type of a derived type, registered as a dependency of the derived type if it
+    /// implements [`GetTypeRegistration`], and logged at debug level otherwise.
+    ///
+    /// The choice between [`RegisterDependency`] and [`RegisterDependencyFallback`] is made by
+    /// auto-ref method resolution, so it only works for concrete types.
+    pub struct TypeDependency<T>(PhantomData<fn() -> T>);
+
+    impl<T> TypeDependency<T> {
+        #[allow(clippy::new_without_default)]
+        pub fn new() -> Self {
+            TypeDependency(PhantomData)
+        }
+    }
+
+    pub trait RegisterDependency {
+        fn register_dependency(&self, registry: &mut TypeRegistry);
+    }
+
+    impl<T: GetTypeRegistration> RegisterDependency for TypeDependency<T> {
+        fn register_dependency(&self, registry: &mut TypeRegistry) {
+            registry.register_dependency::<T>();
+        }
+    }
+
+    pub trait RegisterDependencyFallback {
+        fn register_dependency(&self, registry: &mut TypeRegistry);
+    }
+
+    impl<T> RegisterDependencyFallback for &TypeDependency<T> {
+        fn register_dependency(&self, _registry: &mut TypeRegistry) {
+            bevy_utils::tracing::debug!(
+                "{} does not implement GetTypeRegistration, so it can't be registered as a field type",
+                std::any::type_name::<T>()
+            );
+        }
+    }
+
+    /// A type submitted for automatic registration by `#[derive(Reflect)]`.
+    pub struct AutomaticRegistration(pub fn(&mut TypeRegistry));
+
+    impl AutomaticRegistration {
+        pub const fn of<T: GetTypeRegistration>() -> Self {
+            AutomaticRegistration(register::<T>)
+        }
+    }
+
+    fn register<T: GetTypeRegistration>(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+
+    #[cfg(feature = "auto_register")]
+    inventory::collect!(AutomaticRegistration);
 
     /// Generates a new UUID from the given UUIDs `a` and `b`,
     /// where the bytes are generated by a bitwise `a ^ b.rotate_right(1)`.
@@ -86,6 +148,24 @@ pub mod __macro_exports {
     }
 }
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "auto_register")]
+macro_rules! __auto_register_type {
+    ($ty:ty) => {
+        $crate::__macro_exports::inventory::submit! {
+            $crate::__macro_exports::AutomaticRegistration::of::<$ty>()
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "auto_register"))]
+macro_rules! __auto_register_type {
+    ($ty:ty) => {};
+}
+
 #[cfg(test)]
 #[allow(clippy::blacklisted_name, clippy::approx_constant)]
 mod tests {
@@ -97,12 +177,15 @@ mod tests {
         ser::{to_string_pretty, PrettyConfig},
         Deserializer,
     };
+    use std::any::TypeId;
     use std::fmt::{Debug, Formatter};
 
     use super::prelude::*;
     use super::*;
     use crate as bevy_reflect;
-    use crate::serde::{ReflectDeserializer, ReflectSerializer};
+    use crate::serde::{
+        ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer,
+    };
 
     #[test]
     fn reflect_struct() {
@@ -485,6 +568,79 @@ mod tests {
         assert!(foo.reflect_partial_eq(&dynamic_struct).unwrap());
     }
 
+    #[test]
+    fn reflect_typed_serialize() {
+        #[derive(Reflect)]
+        struct Foo {
+            a: u32,
+            c: Vec<isize>,
+            d: HashMap<usize, i8>,
+            e: Bar,
+            f: String,
+            g: (i32, Vec<isize>),
+            h: [u32; 2],
+            i: Baz,
+        }
+
+        #[derive(Reflect)]
+        struct Bar {
+            x: u32,
+        }
+
+        #[derive(Reflect)]
+        struct Baz(String);
+
+        let mut hash_map = HashMap::default();
+        hash_map.insert(1, 1);
+        hash_map.insert(2, 2);
+        let foo = Foo {
+            a: 1,
+            c: vec![1, 2],
+            d: hash_map,
+            e: Bar { x: 1 },
+            f: "hi".to_string(),
+            g: (1, vec![1, 2]),
+            h: [2; 2],
+            i: Baz("baz".to_string()),
+        };
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+        registry.register::<Bar>();
+        registry.register::<Baz>();
+        registry.register::<u32>();
+        registry.register::<isize>();
+        registry.register::<usize>();
+        registry.register::<String>();
+        registry.register::<i8>();
+        registry.register::<i32>();
+        registry.register::<Vec<isize>>();
+        registry.register::<HashMap<usize, i8>>();
+        registry.register::<(i32, Vec<isize>)>();
+        registry.register::<[u32; 2]>();
+
+        let serializer = TypedReflectSerializer::new(&foo, &registry);
+        let serialized = to_string_pretty(&serializer, PrettyConfig::default()).unwrap();
+        assert!(!serialized.contains("type"));
+
+        let mut deserializer = Deserializer::from_str(&serialized).unwrap();
+        let registration = registry.get(std::any::TypeId::of::<Foo>()).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        let dynamic_struct = value.take::<DynamicStruct>().unwrap();
+
+        assert_eq!(dynamic_struct.name(), std::any::type_name::<Foo>());
+        assert!(foo.reflect_partial_eq(&dynamic_struct).unwrap());
+
+        // omitted fields are left out of the dynamic struct
+        let mut deserializer = Deserializer::from_str("(a: 3, e: (x: 4))").unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        let dynamic_struct = value.take::<DynamicStruct>().unwrap();
+        assert_eq!(dynamic_struct.field_len(), 2);
+        assert_eq!(*dynamic_struct.get_field::<u32>("a").unwrap(), 3);
+    }
+
     #[test]
     fn reflect_take() {
         #[derive(Reflect, Debug, PartialEq)]
@@ -787,6 +943,255 @@ mod tests {
         let _ = trait_object.as_reflect();
     }
 
+    #[test]
+    fn should_register_field_types() {
+        #[derive(Reflect)]
+        struct Outer {
+            inner: Inner,
+            list: Vec<String>,
+            map: HashMap<u32, f32>,
+            tuple: (u8, usize),
+        }
+
+        #[derive(Reflect)]
+        struct Inner {
+            leaf: Leaf,
+        }
+
+        #[derive(Reflect)]
+        struct Leaf(i64);
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Outer>();
+
+        for type_id in [
+            TypeId::of::<Outer>(),
+            TypeId::of::<Inner>(),
+            TypeId::of::<Leaf>(),
+            TypeId::of::<i64>(),
+            TypeId::of::<Vec<String>>(),
+            TypeId::of::<String>(),
+            TypeId::of::<HashMap<u32, f32>>(),
+            TypeId::of::<u32>(),
+            TypeId::of::<f32>(),
+            TypeId::of::<(u8, usize)>(),
+            TypeId::of::<u8>(),
+            TypeId::of::<usize>(),
+        ] {
+            assert!(registry.contains(type_id));
+        }
+    }
+
+    #[test]
+    fn should_register_generic_field_types() {
+        #[derive(Reflect)]
+        struct Wrapper<T: Reflect> {
+            value: T,
+        }
+
+        #[derive(Reflect)]
+        struct Foo(u32);
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Wrapper<Foo>>();
+
+        assert!(registry.contains(TypeId::of::<Wrapper<Foo>>()));
+        assert!(registry.contains(TypeId::of::<Foo>()));
+        assert!(registry.contains(TypeId::of::<u32>()));
+    }
+
+    #[test]
+    fn should_overwrite_registration_but_not_dependencies() {
+        #[derive(Reflect, Default)]
+        struct Foo {
+            value: usize,
+        }
+
+        #[derive(Reflect)]
+        struct Bar {
+            foo: Foo,
+        }
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Foo>();
+        registry
+            .get_mut(TypeId::of::<Foo>())
+            .unwrap()
+            .insert(<ReflectDefault as FromType<Foo>>::from_type());
+
+        registry.register::<Bar>();
+        assert!(registry
+            .get_type_data::<ReflectDefault>(TypeId::of::<Foo>())
+            .is_some());
+
+        registry.register::<Foo>();
+        assert!(registry
+            .get_type_data::<ReflectDefault>(TypeId::of::<Foo>())
+            .is_none());
+        assert!(registry.get_with_short_name("Foo").is_some());
+    }
+
+    #[cfg(feature = "auto_register")]
+    #[test]
+    fn should_auto_register_derived_types() {
+        #[derive(Reflect)]
+        struct Foo {
+            value: usize,
+        }
+
+        let registry = TypeRegistry::new();
+        assert!(registry.contains(TypeId::of::<Foo>()));
+    }
+
+    #[test]
+    fn should_register_methods() {
+        use crate::func::{ArgList, ReflectMethods};
+
+        #[derive(Reflect)]
+        #[reflect(methods(area, scale))]
+        struct Rect {
+            width: f32,
+            height: f32,
+        }
+
+        impl Rect {
+            fn area(&self) -> f32 {
+                self.width * self.height
+            }
+
+            fn scale(&mut self, factor: f32) {
+                self.width *= factor;
+                self.height *= factor;
+            }
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Rect>();
+
+        let methods = registry
+            .get_type_data::<ReflectMethods>(TypeId::of::<Rect>())
+            .unwrap();
+        assert_eq!(methods.len(), 2);
+        assert_eq!(methods.get("area").unwrap().name(), Some("area"));
+
+        let mut rect = Rect {
+            width: 2.0,
+            height: 3.0,
+        };
+        methods
+            .call(
+                "scale",
+                ArgList::new().with_mut(&mut rect).with_owned(2.0_f32),
+            )
+            .unwrap();
+        let area = methods
+            .call("area", ArgList::new().with_ref(&rect))
+            .unwrap();
+        assert_eq!(area.downcast_ref::<f32>(), Some(&24.0));
+
+        assert!(methods.call("perimeter", ArgList::new()).is_err());
+    }
+
+    #[test]
+    fn should_store_custom_attributes() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Tooltip(String);
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Hidden;
+
+        #[derive(Reflect)]
+        #[reflect(@Tooltip("A struct".to_string()))]
+        struct Foo {
+            #[reflect(@0..10_usize, @Tooltip("A field".to_string()))]
+            a: usize,
+            #[reflect(ignore, @Hidden)]
+            #[allow(dead_code)]
+            b: usize,
+            c: usize,
+        }
+
+        #[derive(Reflect)]
+        #[reflect(@Hidden)]
+        struct Bar(#[reflect(@Hidden)] f32, f32);
+
+        #[derive(Reflect, Clone)]
+        #[reflect_value(@Hidden)]
+        struct Baz;
+
+        let info = match Foo::type_info() {
+            TypeInfo::Struct(info) => info,
+            _ => panic!("expected a struct"),
+        };
+        assert_eq!(
+            info.metadata().custom_attributes().get::<Tooltip>(),
+            Some(&Tooltip("A struct".to_string()))
+        );
+        let a = info.field("a").unwrap().metadata().custom_attributes();
+        assert_eq!(a.len(), 2);
+        assert_eq!(a.get::<std::ops::Range<usize>>(), Some(&(0..10)));
+        assert_eq!(a.get::<Tooltip>(), Some(&Tooltip("A field".to_string())));
+        assert!(info.field("b").is_none());
+        assert!(info
+            .field("c")
+            .unwrap()
+            .metadata()
+            .custom_attributes()
+            .is_empty());
+
+        let info = match Bar::type_info() {
+            TypeInfo::TupleStruct(info) => info,
+            _ => panic!("expected a tuple struct"),
+        };
+        assert!(info.metadata().custom_attributes().contains::<Hidden>());
+        assert!(info
+            .field_at(0)
+            .unwrap()
+            .metadata()
+            .custom_attributes()
+            .contains::<Hidden>());
+        assert!(!info
+            .field_at(1)
+            .unwrap()
+            .metadata()
+            .custom_attributes()
+            .contains::<Hidden>());
+
+        let info = match Baz::type_info() {
+            TypeInfo::Value(info) => info,
+            _ => panic!("expected a value"),
+        };
+        assert!(info.metadata().custom_attributes().contains::<Hidden>());
+    }
+
+    #[cfg(feature = "documentation")]
+    #[test]
+    fn should_capture_docs() {
+        /// The struct docs.
+        ///
+        /// More docs.
+        #[derive(Reflect)]
+        struct Foo {
+            /// The field docs.
+            a: usize,
+            b: usize,
+        }
+
+        let info = match Foo::type_info() {
+            TypeInfo::Struct(info) => info,
+            _ => panic!("expected a struct"),
+        };
+        assert_eq!(
+            info.metadata().docs(),
+            Some("The struct docs.\n\nMore docs.")
+        );
+        assert_eq!(
+            info.field("a").unwrap().metadata().docs(),
+            Some("The field docs.")
+        );
+        assert_eq!(info.field("b").unwrap().metadata().docs(), None);
+    }
+
     #[test]
     fn should_reflect_debug() {
         #[derive(Reflect)]
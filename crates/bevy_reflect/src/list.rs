@@ -15,6 +15,32 @@ pub trait List: Reflect + Array {
     /// Appends an element to the list.
     fn push(&mut self, value: Box<dyn Reflect>);
 
+    /// Inserts an element at position `index` within the list,
+    /// shifting all elements after it towards the back of the list.
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    ///
+    /// The default implementation always panics: lists that can't insert elements can't be
+    /// patched with list diffs.
+    fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+        let _ = (index, element);
+        panic!("{} does not support inserting elements", self.type_name());
+    }
+
+    /// Removes and returns the element at position `index` within the list,
+    /// shifting all elements after it towards the front of the list.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// The default implementation always panics: lists that can't remove elements can't be
+    /// patched with list diffs.
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        let _ = index;
+        panic!("{} does not support removing elements", self.type_name());
+    }
+
     /// Clones the list, producing a [`DynamicList`].
     fn clone_dynamic(&self) -> DynamicList {
         DynamicList {
@@ -151,6 +177,14 @@ impl List for DynamicList {
         DynamicList::push_box(self, value);
     }
 
+    fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+        self.values.insert(index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        self.values.remove(index)
+    }
+
     fn clone_dynamic(&self) -> DynamicList {
         DynamicList {
             name: self.name.clone(),
@@ -44,6 +44,34 @@ pub trait Map: Reflect {
 
     /// Clones the map, producing a [`DynamicMap`].
     fn clone_dynamic(&self) -> DynamicMap;
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map did not have this key present, `None` is returned.
+    /// If the map did have this key present, the value is updated, and the old value is returned.
+    ///
+    /// The default implementation always panics: maps that can't insert entries can't be
+    /// patched with map diffs.
+    fn insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Option<Box<dyn Reflect>> {
+        let _ = (key, value);
+        panic!("{} does not support inserting entries", self.type_name());
+    }
+
+    /// Removes an entry from the map.
+    ///
+    /// If the map did not have this key present, `None` is returned.
+    /// If the map did have this key present, the removed value is returned.
+    ///
+    /// The default implementation always panics: maps that can't remove entries can't be
+    /// patched with map diffs.
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        let _ = key;
+        panic!("{} does not support removing entries", self.type_name());
+    }
 }
 
 /// A container for compile-time map info.
@@ -155,17 +183,40 @@ impl DynamicMap {
     }
 
     /// Inserts a key-value pair of [`Reflect`] values into the map.
-    pub fn insert_boxed(&mut self, key: Box<dyn Reflect>, value: Box<dyn Reflect>) {
+    ///
+    /// Returns the previous value associated with the key, if any.
+    pub fn insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Option<Box<dyn Reflect>> {
         match self.indices.entry(key.reflect_hash().expect(HASH_ERROR)) {
             Entry::Occupied(entry) => {
-                self.values[*entry.get()] = (key, value);
+                let (_, old_value) =
+                    std::mem::replace(&mut self.values[*entry.get()], (key, value));
+                Some(old_value)
             }
             Entry::Vacant(entry) => {
                 entry.insert(self.values.len());
                 self.values.push((key, value));
+                None
             }
         }
     }
+
+    /// Removes the entry with the given key from the map, returning its value.
+    pub fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        let index = self
+            .indices
+            .remove(&key.reflect_hash().expect(HASH_ERROR))?;
+        let (_, value) = self.values.remove(index);
+        for other_index in self.indices.values_mut() {
+            if *other_index > index {
+                *other_index -= 1;
+            }
+        }
+        Some(value)
+    }
 }
 
 impl Map for DynamicMap {
@@ -210,6 +261,18 @@ impl Map for DynamicMap {
             .get(index)
             .map(|(key, value)| (&**key, &**value))
     }
+
+    fn insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Option<Box<dyn Reflect>> {
+        DynamicMap::insert_boxed(self, key, value)
+    }
+
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        DynamicMap::remove(self, key)
+    }
 }
 
 // SAFE: any and any_mut both return self
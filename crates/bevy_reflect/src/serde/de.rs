@@ -1,9 +1,11 @@
 use crate::{
-    serde::type_fields, DynamicArray, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
-    DynamicTupleStruct, Reflect, ReflectDeserialize, TypeRegistry,
+    serde::type_fields, ArrayInfo, DynamicArray, DynamicList, DynamicMap, DynamicStruct,
+    DynamicTuple, DynamicTupleStruct, ListInfo, MapInfo, Reflect, ReflectDeserialize, StructInfo,
+    TupleInfo, TupleStructInfo, TypeInfo, TypeRegistration, TypeRegistry,
 };
 use erased_serde::Deserializer;
 use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use std::any::TypeId;
 
 pub trait DeserializeValue {
     fn deserialize(
@@ -507,3 +509,355 @@ impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
         Ok(tuple)
     }
 }
+
+/// Deserializes a reflected value of a known type, as written by a
+/// [`TypedReflectSerializer`](crate::serde::TypedReflectSerializer).
+///
+/// The [`TypeInfo`] of the registration drives deserialization, so the input does not need to
+/// contain any type names, and formats that are not self-describing, such as `bincode` and
+/// `postcard`, are supported. Structs are produced as [`DynamicStruct`]s and may omit fields
+/// in self-describing formats; applying them to a value leaves the omitted fields untouched.
+pub struct TypedReflectDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> TypedReflectDeserializer<'a> {
+    pub fn new(registration: &'a TypeRegistration, registry: &'a TypeRegistry) -> Self {
+        TypedReflectDeserializer {
+            registration,
+            registry,
+        }
+    }
+
+    /// Create a [`TypedReflectDeserializer`] for values of type `T`.
+    ///
+    /// Returns `None` if `T` is not registered.
+    pub fn of<T: Reflect>(registry: &'a TypeRegistry) -> Option<Self> {
+        let registration = registry.get(TypeId::of::<T>())?;
+        Some(TypedReflectDeserializer::new(registration, registry))
+    }
+
+    fn registration_of<E: de::Error>(
+        registry: &'a TypeRegistry,
+        type_id: TypeId,
+        type_name: &str,
+    ) -> Result<TypedReflectDeserializer<'a>, E> {
+        let registration = registry.get(type_id).ok_or_else(|| {
+            de::Error::custom(format_args!("No registration found for {}", type_name))
+        })?;
+        Ok(TypedReflectDeserializer::new(registration, registry))
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let registry = self.registry;
+        match self.registration.type_info() {
+            TypeInfo::Struct(info) => {
+                let mut dynamic_struct = deserializer.deserialize_struct(
+                    info.type_name(),
+                    info.field_names(),
+                    TypedStructVisitor { info, registry },
+                )?;
+                dynamic_struct.set_name(info.type_name().to_string());
+                Ok(Box::new(dynamic_struct))
+            }
+            TypeInfo::TupleStruct(info) => {
+                let mut tuple_struct = deserializer.deserialize_tuple_struct(
+                    info.type_name(),
+                    info.field_len(),
+                    TypedTupleStructVisitor { info, registry },
+                )?;
+                tuple_struct.set_name(info.type_name().to_string());
+                Ok(Box::new(tuple_struct))
+            }
+            TypeInfo::Tuple(info) => {
+                let mut tuple = deserializer
+                    .deserialize_tuple(info.field_len(), TypedTupleVisitor { info, registry })?;
+                tuple.set_name(info.type_name().to_string());
+                Ok(Box::new(tuple))
+            }
+            TypeInfo::List(info) => {
+                let mut list = deserializer.deserialize_seq(TypedListVisitor { info, registry })?;
+                list.set_name(info.type_name().to_string());
+                Ok(Box::new(list))
+            }
+            TypeInfo::Array(info) => {
+                let mut array = deserializer
+                    .deserialize_tuple(info.capacity(), TypedArrayVisitor { info, registry })?;
+                array.set_name(info.type_name().to_string());
+                Ok(Box::new(array))
+            }
+            TypeInfo::Map(info) => {
+                let mut map = deserializer.deserialize_map(TypedMapVisitor { info, registry })?;
+                map.set_name(info.type_name().to_string());
+                Ok(Box::new(map))
+            }
+            TypeInfo::Value(info) => {
+                let reflect_deserialize = self
+                    .registration
+                    .data::<ReflectDeserialize>()
+                    .ok_or_else(|| {
+                        de::Error::custom(format_args!(
+                            "The TypeRegistration for {} doesn't have DeserializeReflect",
+                            info.type_name()
+                        ))
+                    })?;
+                reflect_deserialize.deserialize(deserializer)
+            }
+            TypeInfo::Dynamic(info) => Err(de::Error::custom(format_args!(
+                "Dynamic type {} cannot be deserialized without type information",
+                info.type_name()
+            ))),
+        }
+    }
+}
+
+struct TypedStructVisitor<'a> {
+    info: &'static StructInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for TypedStructVisitor<'a> {
+    type Value = DynamicStruct;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "struct {}", self.info.type_name())
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut dynamic_struct = DynamicStruct::default();
+        while let Some(Ident(key)) = map.next_key::<Ident>()? {
+            let field = self
+                .info
+                .field(&key)
+                .ok_or_else(|| de::Error::unknown_field(&key, self.info.field_names()))?;
+            let value = map.next_value_seed(TypedReflectDeserializer::registration_of(
+                self.registry,
+                field.type_id(),
+                field.type_name(),
+            )?)?;
+            dynamic_struct.insert_boxed(&key, value);
+        }
+        Ok(dynamic_struct)
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut dynamic_struct = DynamicStruct::default();
+        for (index, field) in self.info.iter().enumerate() {
+            let value = seq
+                .next_element_seed(TypedReflectDeserializer::registration_of(
+                    self.registry,
+                    field.type_id(),
+                    field.type_name(),
+                )?)?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+            dynamic_struct.insert_boxed(field.name(), value);
+        }
+        Ok(dynamic_struct)
+    }
+}
+
+/// A struct field name, which self-describing formats like RON only provide as an identifier.
+struct Ident(String);
+
+impl<'de> de::Deserialize<'de> for Ident {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct IdentVisitor;
+
+        impl<'de> Visitor<'de> for IdentVisitor {
+            type Value = Ident;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("field identifier")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Ident(value.to_string()))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Ident(value))
+            }
+        }
+
+        deserializer.deserialize_identifier(IdentVisitor)
+    }
+}
+
+struct TypedTupleStructVisitor<'a> {
+    info: &'static TupleStructInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for TypedTupleStructVisitor<'a> {
+    type Value = DynamicTupleStruct;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "tuple struct {}", self.info.type_name())
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut tuple_struct = DynamicTupleStruct::default();
+        for index in 0..self.info.field_len() {
+            let field = self.info.field_at(index).unwrap();
+            let value = seq
+                .next_element_seed(TypedReflectDeserializer::registration_of(
+                    self.registry,
+                    field.type_id(),
+                    field.type_name(),
+                )?)?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+            tuple_struct.insert_boxed(value);
+        }
+        Ok(tuple_struct)
+    }
+}
+
+struct TypedTupleVisitor<'a> {
+    info: &'static TupleInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for TypedTupleVisitor<'a> {
+    type Value = DynamicTuple;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "tuple {}", self.info.type_name())
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut tuple = DynamicTuple::default();
+        for index in 0..self.info.field_len() {
+            let field = self.info.field_at(index).unwrap();
+            let value = seq
+                .next_element_seed(TypedReflectDeserializer::registration_of(
+                    self.registry,
+                    field.type_id(),
+                    field.type_name(),
+                )?)?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+            tuple.insert_boxed(value);
+        }
+        Ok(tuple)
+    }
+}
+
+struct TypedListVisitor<'a> {
+    info: &'static ListInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for TypedListVisitor<'a> {
+    type Value = DynamicList;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "list {}", self.info.type_name())
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut list = DynamicList::default();
+        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer::registration_of(
+            self.registry,
+            self.info.item_type_id(),
+            self.info.item_type_name(),
+        )?)? {
+            list.push_box(value);
+        }
+        Ok(list)
+    }
+}
+
+struct TypedArrayVisitor<'a> {
+    info: &'static ArrayInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for TypedArrayVisitor<'a> {
+    type Value = DynamicArray;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "array {}", self.info.type_name())
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(self.info.capacity());
+        for index in 0..self.info.capacity() {
+            let value = seq
+                .next_element_seed(TypedReflectDeserializer::registration_of(
+                    self.registry,
+                    self.info.item_type_id(),
+                    self.info.item_type_name(),
+                )?)?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+            values.push(value);
+        }
+        Ok(DynamicArray::new(values.into_boxed_slice()))
+    }
+}
+
+struct TypedMapVisitor<'a> {
+    info: &'static MapInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for TypedMapVisitor<'a> {
+    type Value = DynamicMap;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "map {}", self.info.type_name())
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut dynamic_map = DynamicMap::default();
+        while let Some(key) = map.next_key_seed(TypedReflectDeserializer::registration_of(
+            self.registry,
+            self.info.key_type_id(),
+            self.info.key_type_name(),
+        )?)? {
+            let value = map.next_value_seed(TypedReflectDeserializer::registration_of(
+                self.registry,
+                self.info.value_type_id(),
+                self.info.value_type_name(),
+            )?)?;
+            dynamic_map.insert_boxed(key, value);
+        }
+        Ok(dynamic_map)
+    }
+}
@@ -0,0 +1,567 @@
+use crate::{
+    serde::{ReflectDeserializer, ReflectSerializer},
+    ListDiffOp, MapDiffOp, ReflectDiff, TypeRegistry,
+};
+use serde::{
+    de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq},
+    Serialize,
+};
+
+mod diff_fields {
+    pub const UNCHANGED: &str = "unchanged";
+    pub const REPLACE: &str = "replace";
+    pub const STRUCT: &str = "struct";
+    pub const TUPLE_STRUCT: &str = "tuple_struct";
+    pub const TUPLE: &str = "tuple";
+    pub const ARRAY: &str = "array";
+    pub const LIST: &str = "list";
+    pub const MAP: &str = "map";
+
+    pub const INSERT: &str = "insert";
+    pub const REMOVE: &str = "remove";
+    pub const MODIFY: &str = "modify";
+    pub const VALUE: &str = "value";
+    pub const DIFF: &str = "diff";
+}
+
+/// Serializes a [`ReflectDiff`], writing replaced and inserted values with a
+/// [`ReflectSerializer`].
+pub struct ReflectDiffSerializer<'a> {
+    pub diff: &'a ReflectDiff,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffSerializer<'a> {
+    pub fn new(diff: &'a ReflectDiff, registry: &'a TypeRegistry) -> Self {
+        ReflectDiffSerializer { diff, registry }
+    }
+}
+
+impl<'a> Serialize for ReflectDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+        match self.diff {
+            ReflectDiff::Unchanged => state.serialize_entry(diff_fields::UNCHANGED, &())?,
+            ReflectDiff::Replace(value) => state.serialize_entry(
+                diff_fields::REPLACE,
+                &ReflectSerializer::new(&**value, self.registry),
+            )?,
+            ReflectDiff::Struct(changes) => state.serialize_entry(
+                diff_fields::STRUCT,
+                &FieldDiffsSerializer {
+                    changes,
+                    registry: self.registry,
+                },
+            )?,
+            ReflectDiff::TupleStruct(changes) => state.serialize_entry(
+                diff_fields::TUPLE_STRUCT,
+                &FieldDiffsSerializer {
+                    changes,
+                    registry: self.registry,
+                },
+            )?,
+            ReflectDiff::Tuple(changes) => state.serialize_entry(
+                diff_fields::TUPLE,
+                &FieldDiffsSerializer {
+                    changes,
+                    registry: self.registry,
+                },
+            )?,
+            ReflectDiff::Array(changes) => state.serialize_entry(
+                diff_fields::ARRAY,
+                &FieldDiffsSerializer {
+                    changes,
+                    registry: self.registry,
+                },
+            )?,
+            ReflectDiff::List(ops) => state.serialize_entry(
+                diff_fields::LIST,
+                &ListOpsSerializer {
+                    ops,
+                    registry: self.registry,
+                },
+            )?,
+            ReflectDiff::Map(ops) => {
+                state.serialize_entry(
+                    diff_fields::MAP,
+                    &MapOpsSerializer {
+                        ops,
+                        registry: self.registry,
+                    },
+                )?;
+            }
+        }
+        state.end()
+    }
+}
+
+struct FieldDiffsSerializer<'a, K> {
+    changes: &'a [(K, ReflectDiff)],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, K: Serialize> Serialize for FieldDiffsSerializer<'a, K> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.changes.len()))?;
+        for (key, diff) in self.changes {
+            state.serialize_entry(key, &ReflectDiffSerializer::new(diff, self.registry))?;
+        }
+        state.end()
+    }
+}
+
+struct ListOpsSerializer<'a> {
+    ops: &'a [ListDiffOp],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ListOpsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.ops.len()))?;
+        for op in self.ops {
+            state.serialize_element(&ListOpSerializer {
+                op,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct ListOpSerializer<'a> {
+    op: &'a ListDiffOp,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ListOpSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let registry = self.registry;
+        match self.op {
+            ListDiffOp::Insert { index, value } => {
+                let mut state = serializer.serialize_map(Some(2))?;
+                state.serialize_entry(diff_fields::INSERT, index)?;
+                state.serialize_entry(
+                    diff_fields::VALUE,
+                    &ReflectSerializer::new(&**value, registry),
+                )?;
+                state.end()
+            }
+            ListDiffOp::Remove { index } => {
+                let mut state = serializer.serialize_map(Some(1))?;
+                state.serialize_entry(diff_fields::REMOVE, index)?;
+                state.end()
+            }
+            ListDiffOp::Modify { index, diff } => {
+                let mut state = serializer.serialize_map(Some(2))?;
+                state.serialize_entry(diff_fields::MODIFY, index)?;
+                state.serialize_entry(
+                    diff_fields::DIFF,
+                    &ReflectDiffSerializer::new(diff, self.registry),
+                )?;
+                state.end()
+            }
+        }
+    }
+}
+
+struct MapOpsSerializer<'a> {
+    ops: &'a [MapDiffOp],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for MapOpsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.ops.len()))?;
+        for op in self.ops {
+            state.serialize_element(&MapOpSerializer {
+                op,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct MapOpSerializer<'a> {
+    op: &'a MapDiffOp,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for MapOpSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let registry = self.registry;
+        match self.op {
+            MapDiffOp::Insert { key, value } => {
+                let mut state = serializer.serialize_map(Some(2))?;
+                state.serialize_entry(
+                    diff_fields::INSERT,
+                    &ReflectSerializer::new(&**key, registry),
+                )?;
+                state.serialize_entry(
+                    diff_fields::VALUE,
+                    &ReflectSerializer::new(&**value, registry),
+                )?;
+                state.end()
+            }
+            MapDiffOp::Remove { key } => {
+                let mut state = serializer.serialize_map(Some(1))?;
+                state.serialize_entry(
+                    diff_fields::REMOVE,
+                    &ReflectSerializer::new(&**key, registry),
+                )?;
+                state.end()
+            }
+            MapDiffOp::Modify { key, diff } => {
+                let mut state = serializer.serialize_map(Some(2))?;
+                state.serialize_entry(
+                    diff_fields::MODIFY,
+                    &ReflectSerializer::new(&**key, registry),
+                )?;
+                state.serialize_entry(
+                    diff_fields::DIFF,
+                    &ReflectDiffSerializer::new(diff, self.registry),
+                )?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// Deserializes a [`ReflectDiff`] written by a [`ReflectDiffSerializer`].
+///
+/// Replaced and inserted values are read with a [`ReflectDeserializer`], so they are usually
+/// dynamic values, which [`apply_diff`](crate::apply_diff) converts as needed.
+pub struct ReflectDiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        ReflectDiffDeserializer { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReflectDiffDeserializer<'a> {
+    type Value = ReflectDiff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ReflectDiffVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct ReflectDiffVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ReflectDiffVisitor<'a> {
+    type Value = ReflectDiff;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("reflect diff")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let kind = map
+            .next_key::<String>()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let registry = self.registry;
+        let diff = match kind.as_str() {
+            diff_fields::UNCHANGED => {
+                map.next_value::<()>()?;
+                ReflectDiff::Unchanged
+            }
+            diff_fields::REPLACE => {
+                ReflectDiff::Replace(map.next_value_seed(ReflectDeserializer::new(registry))?)
+            }
+            diff_fields::STRUCT => {
+                ReflectDiff::Struct(map.next_value_seed(FieldDiffsDeserializer::new(registry))?)
+            }
+            diff_fields::TUPLE_STRUCT => ReflectDiff::TupleStruct(
+                map.next_value_seed(FieldDiffsDeserializer::new(registry))?,
+            ),
+            diff_fields::TUPLE => {
+                ReflectDiff::Tuple(map.next_value_seed(FieldDiffsDeserializer::new(registry))?)
+            }
+            diff_fields::ARRAY => {
+                ReflectDiff::Array(map.next_value_seed(FieldDiffsDeserializer::new(registry))?)
+            }
+            diff_fields::LIST => {
+                ReflectDiff::List(map.next_value_seed(ListOpsDeserializer { registry })?)
+            }
+            diff_fields::MAP => {
+                ReflectDiff::Map(map.next_value_seed(MapOpsDeserializer { registry })?)
+            }
+            kind => {
+                return Err(de::Error::unknown_variant(
+                    kind,
+                    &[
+                        diff_fields::UNCHANGED,
+                        diff_fields::REPLACE,
+                        diff_fields::STRUCT,
+                        diff_fields::TUPLE_STRUCT,
+                        diff_fields::TUPLE,
+                        diff_fields::ARRAY,
+                        diff_fields::LIST,
+                        diff_fields::MAP,
+                    ],
+                ))
+            }
+        };
+        Ok(diff)
+    }
+}
+
+struct FieldDiffsDeserializer<'a, K> {
+    registry: &'a TypeRegistry,
+    marker: std::marker::PhantomData<K>,
+}
+
+impl<'a, K> FieldDiffsDeserializer<'a, K> {
+    fn new(registry: &'a TypeRegistry) -> Self {
+        FieldDiffsDeserializer {
+            registry,
+            marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, 'de, K: de::Deserialize<'de>> DeserializeSeed<'de> for FieldDiffsDeserializer<'a, K> {
+    type Value = Vec<(K, ReflectDiff)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de, K: de::Deserialize<'de>> Visitor<'de> for FieldDiffsDeserializer<'a, K> {
+    type Value = Vec<(K, ReflectDiff)>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map of field diffs")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut changes = Vec::new();
+        while let Some(key) = map.next_key::<K>()? {
+            let diff = map.next_value_seed(ReflectDiffDeserializer::new(self.registry))?;
+            changes.push((key, diff));
+        }
+        Ok(changes)
+    }
+}
+
+struct ListOpsDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ListOpsDeserializer<'a> {
+    type Value = Vec<ListDiffOp>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ListOpsDeserializer<'a> {
+    type Value = Vec<ListDiffOp>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("list of list operations")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut ops = Vec::new();
+        while let Some(op) = seq.next_element_seed(ListOpDeserializer {
+            registry: self.registry,
+        })? {
+            ops.push(op);
+        }
+        Ok(ops)
+    }
+}
+
+struct ListOpDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ListOpDeserializer<'a> {
+    type Value = ListDiffOp;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ListOpDeserializer<'a> {
+    type Value = ListDiffOp;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("list operation")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let kind = map
+            .next_key::<String>()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let index = map.next_value::<usize>()?;
+        match kind.as_str() {
+            diff_fields::INSERT => {
+                expect_key(&mut map, diff_fields::VALUE)?;
+                let value = map.next_value_seed(ReflectDeserializer::new(self.registry))?;
+                Ok(ListDiffOp::Insert { index, value })
+            }
+            diff_fields::REMOVE => Ok(ListDiffOp::Remove { index }),
+            diff_fields::MODIFY => {
+                expect_key(&mut map, diff_fields::DIFF)?;
+                let diff = map.next_value_seed(ReflectDiffDeserializer::new(self.registry))?;
+                Ok(ListDiffOp::Modify { index, diff })
+            }
+            kind => Err(de::Error::unknown_variant(kind, OPERATIONS)),
+        }
+    }
+}
+
+struct MapOpsDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for MapOpsDeserializer<'a> {
+    type Value = Vec<MapDiffOp>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for MapOpsDeserializer<'a> {
+    type Value = Vec<MapDiffOp>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("list of map operations")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut ops = Vec::new();
+        while let Some(op) = seq.next_element_seed(MapOpDeserializer {
+            registry: self.registry,
+        })? {
+            ops.push(op);
+        }
+        Ok(ops)
+    }
+}
+
+struct MapOpDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for MapOpDeserializer<'a> {
+    type Value = MapDiffOp;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for MapOpDeserializer<'a> {
+    type Value = MapDiffOp;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map operation")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let kind = map
+            .next_key::<String>()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let key = map.next_value_seed(ReflectDeserializer::new(self.registry))?;
+        match kind.as_str() {
+            diff_fields::INSERT => {
+                expect_key(&mut map, diff_fields::VALUE)?;
+                let value = map.next_value_seed(ReflectDeserializer::new(self.registry))?;
+                Ok(MapDiffOp::Insert { key, value })
+            }
+            diff_fields::REMOVE => Ok(MapDiffOp::Remove { key }),
+            diff_fields::MODIFY => {
+                expect_key(&mut map, diff_fields::DIFF)?;
+                let diff = map.next_value_seed(ReflectDiffDeserializer::new(self.registry))?;
+                Ok(MapDiffOp::Modify { key, diff })
+            }
+            kind => Err(de::Error::unknown_variant(kind, OPERATIONS)),
+        }
+    }
+}
+
+const OPERATIONS: &[&str] = &[
+    diff_fields::INSERT,
+    diff_fields::REMOVE,
+    diff_fields::MODIFY,
+];
+
+fn expect_key<'de, V: MapAccess<'de>>(map: &mut V, expected: &'static str) -> Result<(), V::Error> {
+    match map.next_key::<String>()? {
+        Some(key) if key == expected => Ok(()),
+        Some(key) => Err(de::Error::custom(format_args!(
+            "expected field `{}`, found `{}`",
+            expected, key
+        ))),
+        None => Err(de::Error::missing_field(expected)),
+    }
+}
@@ -1,7 +1,9 @@
 mod de;
+mod diff;
 mod ser;
 
 pub use de::*;
+pub use diff::*;
 pub use ser::*;
 
 pub(crate) mod type_fields {
@@ -14,3 +16,195 @@ pub(crate) mod type_fields {
     pub const ARRAY: &str = "array";
     pub const VALUE: &str = "value";
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{TypedReflectDeserializer, TypedReflectSerializer};
+    use crate::{self as bevy_reflect, DynamicStruct};
+    use crate::{FromReflect, GetTypeRegistration, Reflect, ReflectDeserialize, TypeRegistry};
+    use bevy_utils::HashMap;
+    use bincode::Options;
+    use serde::de::DeserializeSeed;
+    use std::{any::TypeId, fmt::Debug};
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Everything {
+        value: u32,
+        string: String,
+        option: Option<String>,
+        list: Vec<i32>,
+        array: [u16; 3],
+        tuple: (f32, bool),
+        map: HashMap<u8, String>,
+        tuple_struct: TupleStruct,
+        nested: Nested,
+        unit: Unit,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct TupleStruct(i64, String);
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Nested {
+        list: Vec<TupleStruct>,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Unit;
+
+    fn everything() -> Everything {
+        let mut map = HashMap::default();
+        map.insert(1, String::from("one"));
+        map.insert(2, String::from("two"));
+        Everything {
+            value: 123,
+            string: String::from("Hello world!"),
+            option: Some(String::from("some")),
+            list: vec![-1, 0, 1],
+            array: [1, 2, 3],
+            tuple: (1.5, true),
+            map,
+            tuple_struct: TupleStruct(-123, String::from("tuple")),
+            nested: Nested {
+                list: vec![
+                    TupleStruct(1, String::from("a")),
+                    TupleStruct(2, String::new()),
+                ],
+            },
+            unit: Unit,
+        }
+    }
+
+    fn registry_for<T: GetTypeRegistration>() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<T>();
+        registry
+    }
+
+    fn bincode_round_trip<T>(value: &T) -> T
+    where
+        T: Reflect + FromReflect + GetTypeRegistration,
+    {
+        let registry = registry_for::<T>();
+        let bytes = bincode::options()
+            .serialize(&TypedReflectSerializer::new(value, &registry))
+            .unwrap();
+
+        let mut deserializer = bincode::Deserializer::from_slice(&bytes, bincode::options());
+        let output = TypedReflectDeserializer::of::<T>(&registry)
+            .unwrap()
+            .deserialize(&mut deserializer)
+            .unwrap();
+        T::from_reflect(&*output).unwrap()
+    }
+
+    fn postcard_round_trip<T>(value: &T) -> T
+    where
+        T: Reflect + FromReflect + GetTypeRegistration,
+    {
+        let registry = registry_for::<T>();
+        let bytes = postcard::to_allocvec(&TypedReflectSerializer::new(value, &registry)).unwrap();
+
+        let mut deserializer = postcard::Deserializer::from_bytes(&bytes);
+        let output = TypedReflectDeserializer::of::<T>(&registry)
+            .unwrap()
+            .deserialize(&mut deserializer)
+            .unwrap();
+        T::from_reflect(&*output).unwrap()
+    }
+
+    fn assert_round_trip<T>(value: T)
+    where
+        T: Reflect + FromReflect + GetTypeRegistration + PartialEq + Debug,
+    {
+        assert_eq!(bincode_round_trip(&value), value);
+        assert_eq!(postcard_round_trip(&value), value);
+    }
+
+    #[test]
+    fn should_round_trip_struct() {
+        assert_round_trip(everything());
+        assert_round_trip(Unit);
+    }
+
+    #[test]
+    fn should_round_trip_tuple_struct() {
+        assert_round_trip(TupleStruct(42, String::from("answer")));
+    }
+
+    #[test]
+    fn should_round_trip_tuple() {
+        assert_round_trip((1_u8, String::from("two"), 3.0_f64));
+    }
+
+    #[test]
+    fn should_round_trip_list() {
+        assert_round_trip(vec![String::from("a"), String::new(), String::from("c")]);
+        assert_round_trip(Vec::<u64>::new());
+    }
+
+    #[test]
+    fn should_round_trip_array() {
+        assert_round_trip([0.5_f32, -0.5, 1e10]);
+    }
+
+    #[test]
+    fn should_round_trip_map() {
+        let mut map = HashMap::default();
+        map.insert(String::from("a"), vec![1_usize]);
+        map.insert(String::from("b"), vec![]);
+        assert_round_trip(map);
+    }
+
+    #[test]
+    fn should_round_trip_value() {
+        assert_round_trip(String::from("value"));
+        assert_round_trip(Some(-7_i16));
+        assert_round_trip(None::<i16>);
+    }
+
+    #[test]
+    fn should_deserialize_containers_of_deserializable_values() {
+        type Containers = (Vec<i32>, [u16; 3], (f32, bool), HashMap<u8, String>);
+
+        let registry = registry_for::<(Containers, Vec<TupleStruct>)>();
+        assert!(registry
+            .get_type_data::<ReflectDeserialize>(TypeId::of::<Vec<TupleStruct>>())
+            .is_none());
+
+        let mut map = HashMap::default();
+        map.insert(1, String::from("one"));
+        let value: Containers = (vec![-1, 0, 1], [1, 2, 3], (1.5, true), map);
+        let bytes = bincode::options().serialize(&value).unwrap();
+
+        let mut deserializer = bincode::Deserializer::from_slice(&bytes, bincode::options());
+        let output = registry
+            .get_type_data::<ReflectDeserialize>(TypeId::of::<Containers>())
+            .unwrap()
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(output.take::<Containers>().unwrap(), value);
+    }
+
+    #[test]
+    fn should_be_compact() {
+        let registry = registry_for::<TupleStruct>();
+        let value = TupleStruct(1, String::from("a"));
+        let bytes = bincode::options()
+            .serialize(&TypedReflectSerializer::new(&value, &registry))
+            .unwrap();
+        // One varint byte for the integer, one for the string length and one for the string
+        assert_eq!(bytes.len(), 3);
+    }
+
+    #[test]
+    fn should_not_serialize_unregistered_dynamic_types() {
+        let registry = TypeRegistry::default();
+        let mut value = DynamicStruct::default();
+        value.insert("value", 1_u32);
+
+        assert!(bincode::options()
+            .serialize(&TypedReflectSerializer::new(&value, &registry))
+            .is_err());
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
     serde::type_fields, Array, List, Map, Reflect, ReflectRef, ReflectSerialize, Struct, Tuple,
-    TupleStruct, TypeRegistry,
+    TupleStruct, TypeInfo, TypeRegistry,
 };
 use serde::{
-    ser::{SerializeMap, SerializeSeq},
+    ser::{SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple, SerializeTupleStruct},
     Serialize,
 };
 
@@ -366,3 +366,129 @@ impl<'a> Serialize for ArrayValueSerializer<'a> {
         state.end()
     }
 }
+
+/// Serializes a reflected value without embedding any type names, using the [`TypeInfo`]
+/// registered for each type to drive serialization.
+///
+/// Structs are serialized as structs, tuple structs as tuple structs, and so on, which gives a
+/// much more compact and readable output than [`ReflectSerializer`]. Since the output is untagged,
+/// the type has to be known up front in order to read it back with a
+/// [`TypedReflectDeserializer`](crate::serde::TypedReflectDeserializer).
+///
+/// Every struct and tuple struct type in the value must be registered in the [`TypeRegistry`].
+///
+/// Since nothing in the output depends on the format being self-describing, this also works
+/// with binary formats such as `bincode` and `postcard`, as long as every struct field is
+/// present. Structs with missing fields, such as partial [`DynamicStruct`]s, can only be read
+/// back from self-describing formats like RON or JSON.
+///
+/// [`TypeInfo`]: crate::TypeInfo
+/// [`DynamicStruct`]: crate::DynamicStruct
+pub struct TypedReflectSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> TypedReflectSerializer<'a> {
+    pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        TypedReflectSerializer { value, registry }
+    }
+
+    fn type_info<E: serde::ser::Error>(&self) -> Result<&'static TypeInfo, E> {
+        self.registry
+            .get_with_name(self.value.type_name())
+            .map(|registration| registration.type_info())
+            .ok_or_else(|| {
+                serde::ser::Error::custom(format_args!(
+                    "No registration found for {}",
+                    self.value.type_name()
+                ))
+            })
+    }
+}
+
+impl<'a> Serialize for TypedReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                let info = match self.type_info::<S::Error>()? {
+                    TypeInfo::Struct(info) => info,
+                    _ => {
+                        return Err(serde::ser::Error::custom(format_args!(
+                            "Expected struct info for {}",
+                            value.type_name()
+                        )))
+                    }
+                };
+                if let Some(name) = (0..value.field_len())
+                    .filter_map(|index| value.name_at(index))
+                    .find(|name| info.index_of(name).is_none())
+                {
+                    return Err(serde::ser::Error::custom(format_args!(
+                        "{} has no field named {}",
+                        info.type_name(),
+                        name
+                    )));
+                }
+                // Fields are written in declaration order, so that formats which identify
+                // fields by position can read them back
+                let mut state = serializer.serialize_struct(info.type_name(), value.field_len())?;
+                for name in info.field_names() {
+                    match value.field(name) {
+                        Some(field) => state.serialize_field(
+                            name,
+                            &TypedReflectSerializer::new(field, self.registry),
+                        )?,
+                        None => state.skip_field(name)?,
+                    }
+                }
+                state.end()
+            }
+            ReflectRef::TupleStruct(value) => {
+                let type_name = self.type_info::<S::Error>()?.type_name();
+                let mut state = serializer.serialize_tuple_struct(type_name, value.field_len())?;
+                for field in value.iter_fields() {
+                    state.serialize_field(&TypedReflectSerializer::new(field, self.registry))?;
+                }
+                state.end()
+            }
+            ReflectRef::Tuple(value) => {
+                let mut state = serializer.serialize_tuple(value.field_len())?;
+                for field in value.iter_fields() {
+                    state.serialize_element(&TypedReflectSerializer::new(field, self.registry))?;
+                }
+                state.end()
+            }
+            ReflectRef::List(value) => {
+                let mut state = serializer.serialize_seq(Some(value.len()))?;
+                for item in value.iter() {
+                    state.serialize_element(&TypedReflectSerializer::new(item, self.registry))?;
+                }
+                state.end()
+            }
+            ReflectRef::Array(value) => {
+                let mut state = serializer.serialize_tuple(value.len())?;
+                for item in value.iter() {
+                    state.serialize_element(&TypedReflectSerializer::new(item, self.registry))?;
+                }
+                state.end()
+            }
+            ReflectRef::Map(value) => {
+                let mut state = serializer.serialize_map(Some(value.len()))?;
+                for (key, value) in value.iter() {
+                    state.serialize_entry(
+                        &TypedReflectSerializer::new(key, self.registry),
+                        &TypedReflectSerializer::new(value, self.registry),
+                    )?;
+                }
+                state.end()
+            }
+            ReflectRef::Value(value) => get_serializable::<S::Error>(value, self.registry)?
+                .borrow()
+                .serialize(serializer),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use crate::utility::NonGenericTypeInfoCell;
-use crate::{DynamicInfo, NamedField, Reflect, ReflectMut, ReflectRef, TypeInfo, Typed};
+use crate::{
+    DynamicInfo, InfoMetadata, NamedField, Reflect, ReflectMut, ReflectRef, TypeInfo, Typed,
+};
 use bevy_utils::{Entry, HashMap};
+use once_cell::sync::OnceCell;
 use std::fmt::{Debug, Formatter};
 use std::{
     any::{Any, TypeId},
@@ -72,7 +75,9 @@ pub struct StructInfo {
     type_name: &'static str,
     type_id: TypeId,
     fields: Box<[NamedField]>,
+    field_names: OnceCell<Box<[&'static str]>>,
     field_indices: HashMap<Cow<'static, str>, usize>,
+    metadata: InfoMetadata,
 }
 
 impl StructInfo {
@@ -96,10 +101,23 @@ impl StructInfo {
             type_name: std::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
             fields: fields.to_vec().into_boxed_slice(),
+            field_names: OnceCell::new(),
             field_indices,
+            metadata: InfoMetadata::default(),
         }
     }
 
+    /// Attaches the metadata of the struct, from its container attributes and doc comments.
+    pub fn with_metadata(mut self, metadata: InfoMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// The custom attributes and docs of the struct itself. Fields carry their own metadata.
+    pub fn metadata(&self) -> &InfoMetadata {
+        &self.metadata
+    }
+
     /// Get the field with the given name.
     pub fn field(&self, name: &str) -> Option<&NamedField> {
         self.field_indices
@@ -117,6 +135,15 @@ impl StructInfo {
         self.field_indices.get(name).copied()
     }
 
+    /// The names of the fields of this struct, in the order they are defined.
+    ///
+    /// The names borrow from the fields of a `'static` info, such as the one returned by
+    /// [`Typed::type_info`], and are collected on first use.
+    pub fn field_names(&'static self) -> &'static [&'static str] {
+        self.field_names
+            .get_or_init(|| self.fields.iter().map(|field| &**field.name()).collect())
+    }
+
     /// Iterate over the fields of this struct.
     pub fn iter(&self) -> Iter<'_, NamedField> {
         self.fields.iter()
@@ -1,9 +1,8 @@
 use crate::utility::NonGenericTypeInfoCell;
 use crate::{
-    DynamicInfo, FromReflect, FromType, GetTypeRegistration, Reflect, ReflectDeserialize,
-    ReflectMut, ReflectRef, TypeInfo, TypeRegistration, Typed, UnnamedField,
+    take_deserialized, DynamicInfo, FromReflect, GetTypeRegistration, Reflect, ReflectDeserialize,
+    ReflectMut, ReflectRef, TypeInfo, TypeRegistration, TypeRegistry, Typed, UnnamedField,
 };
-use serde::Deserialize;
 use std::any::{Any, TypeId};
 use std::fmt::{Debug, Formatter};
 use std::slice::Iter;
@@ -525,12 +524,32 @@ macro_rules! impl_reflect_tuple {
             }
         }
 
-        impl<$($name: Reflect + Typed + for<'de> Deserialize<'de>),*> GetTypeRegistration for ($($name,)*) {
+        impl<$($name: Reflect + Typed + GetTypeRegistration),*> GetTypeRegistration for ($($name,)*) {
             fn get_type_registration() -> TypeRegistration {
                 let mut registration = TypeRegistration::of::<($($name,)*)>();
-                registration.insert::<ReflectDeserialize>(FromType::<($($name,)*)>::from_type());
+                if true $(&& ReflectDeserialize::of::<$name>().is_some())* {
+                    registration.insert(ReflectDeserialize {
+                        #[allow(unused_mut, unused_variables)]
+                        func: |deserializer| {
+                            let elements = [$(ReflectDeserialize::of::<$name>().unwrap()),*];
+                            let len = elements.len();
+                            let values = ReflectDeserialize::deserialize_seq(
+                                deserializer,
+                                elements.into_iter(),
+                                Some(len),
+                            )?;
+                            let mut values = values.into_iter();
+                            Ok(Box::new(($(take_deserialized::<$name, _>(values.next().unwrap())?,)*)))
+                        },
+                    });
+                }
                 registration
             }
+
+            #[allow(unused_variables)]
+            fn register_type_dependencies(registry: &mut TypeRegistry) {
+                $(registry.register_dependency::<$name>();)*
+            }
         }
 
         impl<$($name: FromReflect),*> FromReflect for ($($name,)*)
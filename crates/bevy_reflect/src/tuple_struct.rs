@@ -1,5 +1,7 @@
 use crate::utility::NonGenericTypeInfoCell;
-use crate::{DynamicInfo, Reflect, ReflectMut, ReflectRef, TypeInfo, Typed, UnnamedField};
+use crate::{
+    DynamicInfo, InfoMetadata, Reflect, ReflectMut, ReflectRef, TypeInfo, Typed, UnnamedField,
+};
 use std::any::{Any, TypeId};
 use std::fmt::{Debug, Formatter};
 use std::slice::Iter;
@@ -52,6 +54,7 @@ pub struct TupleStructInfo {
     type_name: &'static str,
     type_id: TypeId,
     fields: Box<[UnnamedField]>,
+    metadata: InfoMetadata,
 }
 
 impl TupleStructInfo {
@@ -66,9 +69,21 @@ impl TupleStructInfo {
             type_name: std::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
             fields: fields.to_vec().into_boxed_slice(),
+            metadata: InfoMetadata::default(),
         }
     }
 
+    /// Attaches the metadata of the tuple struct, from its container attributes and doc comments.
+    pub fn with_metadata(mut self, metadata: InfoMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// The custom attributes and docs of the tuple struct itself. Fields carry their own metadata.
+    pub fn metadata(&self) -> &InfoMetadata {
+        &self.metadata
+    }
+
     /// Get the field at the given index.
     pub fn field_at(&self, index: usize) -> Option<&UnnamedField> {
         self.fields.get(index)
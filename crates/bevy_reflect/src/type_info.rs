@@ -1,4 +1,6 @@
-use crate::{ArrayInfo, ListInfo, MapInfo, Reflect, StructInfo, TupleInfo, TupleStructInfo};
+use crate::{
+    ArrayInfo, InfoMetadata, ListInfo, MapInfo, Reflect, StructInfo, TupleInfo, TupleStructInfo,
+};
 use std::any::{Any, TypeId};
 
 /// A static accessor to compile-time type information.
@@ -154,6 +156,7 @@ impl TypeInfo {
 pub struct ValueInfo {
     type_name: &'static str,
     type_id: TypeId,
+    metadata: InfoMetadata,
 }
 
 impl ValueInfo {
@@ -161,9 +164,21 @@ impl ValueInfo {
         Self {
             type_name: std::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
+            metadata: InfoMetadata::default(),
         }
     }
 
+    /// Attaches the metadata of the value type, from its `#[reflect_value]` attributes and doc comments.
+    pub fn with_metadata(mut self, metadata: InfoMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// The custom attributes and docs of the value type.
+    pub fn metadata(&self) -> &InfoMetadata {
+        &self.metadata
+    }
+
     /// The [type name] of the value.
     ///
     /// [type name]: std::any::type_name
@@ -3,8 +3,13 @@ use crate::{Reflect, TypeInfo, Typed};
 use bevy_utils::{HashMap, HashSet};
 use downcast_rs::{impl_downcast, Downcast};
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
 use serde::Deserialize;
-use std::{any::TypeId, fmt::Debug, sync::Arc};
+use std::{
+    any::TypeId,
+    fmt::{self, Debug},
+    sync::Arc,
+};
 
 /// A registry of reflected types.
 pub struct TypeRegistry {
@@ -33,6 +38,12 @@ impl Debug for TypeRegistryArc {
 /// This trait is automatically implemented for types which derive [`Reflect`].
 pub trait GetTypeRegistration {
     fn get_type_registration() -> TypeRegistration;
+
+    /// Registers the types this type depends on, such as the types of its fields or elements,
+    /// with [`TypeRegistry::register_dependency`].
+    ///
+    /// This is called by [`TypeRegistry::register`] after registering the type.
+    fn register_type_dependencies(_registry: &mut TypeRegistry) {}
 }
 
 impl Default for TypeRegistry {
@@ -70,23 +81,64 @@ impl TypeRegistry {
         registry.register::<isize>();
         registry.register::<f32>();
         registry.register::<f64>();
+        #[cfg(feature = "auto_register")]
+        registry.register_derived_types();
         registry
     }
 
-    /// Registers the type `T`.
+    /// Registers the type `T`, along with the types it depends on, such as the types of its
+    /// fields and elements.
+    ///
+    /// If `T` was already registered, its registration is replaced. Dependencies that were
+    /// already registered are left untouched, see [`TypeRegistry::register_dependency`].
     pub fn register<T>(&mut self)
     where
         T: GetTypeRegistration,
     {
         self.add_registration(T::get_type_registration());
+        T::register_type_dependencies(self);
+    }
+
+    /// Registers the type `T` and its dependencies, unless `T` is already registered.
+    ///
+    /// This keeps the type data added to a type when another type depending on it is
+    /// registered later, and stops the recursion through recursive types.
+    pub fn register_dependency<T>(&mut self)
+    where
+        T: GetTypeRegistration,
+    {
+        let registration = T::get_type_registration();
+        if !self.contains(registration.type_id()) {
+            self.add_registration(registration);
+            T::register_type_dependencies(self);
+        }
+    }
+
+    /// Registers every non-generic type deriving [`Reflect`] in the program.
+    ///
+    /// [`TypeRegistry::new`] already calls this, so it is only needed for registries created
+    /// with [`TypeRegistry::empty`].
+    #[cfg(feature = "auto_register")]
+    pub fn register_derived_types(&mut self) {
+        for registration in inventory::iter::<crate::__macro_exports::AutomaticRegistration> {
+            (registration.0)(self);
+        }
+    }
+
+    /// Returns `true` if the type with the given [`TypeId`] has been registered.
+    pub fn contains(&self, type_id: TypeId) -> bool {
+        self.registrations.contains_key(&type_id)
     }
 
     /// Registers the type described by `registration`.
     pub fn add_registration(&mut self, registration: TypeRegistration) {
         let short_name = registration.short_name.to_string();
-        if self.short_name_to_id.contains_key(&short_name)
-            || self.ambiguous_names.contains(&short_name)
-        {
+        // registering the same type again doesn't make its short name ambiguous
+        let other_type_has_name = matches!(
+            self.short_name_to_id.get(&short_name),
+            Some(type_id) if *type_id != registration.type_id()
+        );
+        if other_type_has_name || self.ambiguous_names.contains(&short_name) {
             // name is ambiguous. fall back to long names for all ambiguous types
             self.short_name_to_id.remove(&short_name);
             self.ambiguous_names.insert(short_name);
@@ -444,6 +496,113 @@ impl ReflectDeserialize {
         (self.func)(&mut erased)
             .map_err(<<D as serde::Deserializer<'de>>::Error as serde::de::Error>::custom)
     }
+
+    /// Returns the `ReflectDeserialize` registered by `T` itself, if it has one.
+    pub(crate) fn of<T: GetTypeRegistration>() -> Option<Self> {
+        T::get_type_registration().data::<Self>().cloned()
+    }
+
+    /// Deserializes a sequence, reading each element with the next `ReflectDeserialize` yielded
+    /// by `elements`.
+    ///
+    /// With `len`, the sequence is deserialized as a tuple of exactly that many elements.
+    /// Containers use this when their elements are only known to be deserializable at runtime.
+    pub(crate) fn deserialize_seq(
+        deserializer: &mut dyn erased_serde::Deserializer,
+        elements: impl Iterator<Item = ReflectDeserialize>,
+        len: Option<usize>,
+    ) -> Result<Vec<Box<dyn Reflect>>, erased_serde::Error> {
+        let visitor = SeqVisitor { elements, len };
+        match len {
+            Some(len) => serde::Deserializer::deserialize_tuple(deserializer, len, visitor),
+            None => serde::Deserializer::deserialize_seq(deserializer, visitor),
+        }
+    }
+
+    /// Deserializes a map, reading its keys with `key` and its values with `value`.
+    pub(crate) fn deserialize_map(
+        deserializer: &mut dyn erased_serde::Deserializer,
+        key: ReflectDeserialize,
+        value: ReflectDeserialize,
+    ) -> Result<Vec<DeserializedEntry>, erased_serde::Error> {
+        serde::Deserializer::deserialize_map(deserializer, MapVisitor { key, value })
+    }
+}
+
+/// A key-value pair read by [`ReflectDeserialize::deserialize_map`].
+pub(crate) type DeserializedEntry = (Box<dyn Reflect>, Box<dyn Reflect>);
+
+/// Unboxes a value produced by the [`ReflectDeserialize`] registered for `T`.
+pub(crate) fn take_deserialized<T: Reflect, E: de::Error>(value: Box<dyn Reflect>) -> Result<T, E> {
+    value.take::<T>().map_err(|value| {
+        E::custom(format_args!(
+            "expected a `{}`, found a `{}`",
+            std::any::type_name::<T>(),
+            value.type_name()
+        ))
+    })
+}
+
+impl<'de> DeserializeSeed<'de> for &ReflectDeserialize {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        ReflectDeserialize::deserialize(self, deserializer)
+    }
+}
+
+struct SeqVisitor<I> {
+    elements: I,
+    len: Option<usize>,
+}
+
+impl<'de, I: Iterator<Item = ReflectDeserialize>> Visitor<'de> for SeqVisitor<I> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.len {
+            Some(len) => write!(formatter, "a sequence of {} elements", len),
+            None => formatter.write_str("a sequence"),
+        }
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(mut self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        for element in self.elements.by_ref() {
+            match seq.next_element_seed(&element)? {
+                Some(value) => values.push(value),
+                None => break,
+            }
+        }
+        match self.len {
+            Some(len) if values.len() != len => Err(de::Error::invalid_length(values.len(), &self)),
+            _ => Ok(values),
+        }
+    }
+}
+
+struct MapVisitor {
+    key: ReflectDeserialize,
+    value: ReflectDeserialize,
+}
+
+impl<'de> Visitor<'de> for MapVisitor {
+    type Value = Vec<DeserializedEntry>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(key) = map.next_key_seed(&self.key)? {
+            entries.push((key, map.next_value_seed(&self.value)?));
+        }
+        Ok(entries)
+    }
 }
 
 impl<T: for<'a> Deserialize<'a> + Reflect> FromType<T> for ReflectDeserialize {
@@ -12,15 +12,15 @@ use bevy_ecs::{
     component::Component,
     entity::Entity,
     event::EventReader,
-    query::Added,
+    query::{Added, With},
     reflect::ReflectComponent,
-    system::{Commands, ParamSet, Query, Res},
+    system::{Commands, Local, ParamSet, Query, Res},
 };
 use bevy_math::{Mat4, UVec2, Vec2, Vec3};
 use bevy_reflect::prelude::*;
 use bevy_transform::components::GlobalTransform;
 use bevy_utils::HashSet;
-use bevy_window::{WindowCreated, WindowId, WindowResized, Windows};
+use bevy_window::{PrimaryWindow, Window, WindowClosed, WindowCreated, WindowRef, WindowResized};
 use serde::{Deserialize, Serialize};
 use std::{borrow::Cow, ops::Range};
 use wgpu::Extent3d;
@@ -244,7 +244,7 @@ impl CameraRenderGraph {
 #[derive(Debug, Clone, Reflect, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum RenderTarget {
     /// Window to which the camera's view is rendered.
-    Window(WindowId),
+    Window(WindowRef),
     /// Image to which the camera's view is rendered.
     Image(Handle<Image>),
 }
@@ -262,9 +262,18 @@ impl RenderTarget {
         images: &'a RenderAssets<Image>,
     ) -> Option<&'a TextureView> {
         match self {
-            RenderTarget::Window(window_id) => windows
-                .get(window_id)
-                .and_then(|window| window.swap_chain_texture.as_ref()),
+            RenderTarget::Window(window_ref) => {
+                let window = window_ref.normalize(windows.primary)?;
+                match windows.get(&window) {
+                    Some(window) => window.swap_chain_texture.as_ref(),
+                    // Windows without a surface are rendered to their image
+                    None => windows
+                        .images
+                        .get(&window)
+                        .and_then(|image| images.get(image))
+                        .map(|image| &image.texture_view),
+                }
+            }
             RenderTarget::Image(image_handle) => {
                 images.get(image_handle).map(|image| &image.texture_view)
             }
@@ -273,12 +282,13 @@ impl RenderTarget {
 
     pub fn get_render_target_info(
         &self,
-        windows: &Windows,
+        windows: &Query<&Window>,
+        primary_window: Option<Entity>,
         images: &Assets<Image>,
     ) -> Option<RenderTargetInfo> {
         Some(match self {
-            RenderTarget::Window(window_id) => {
-                let window = windows.get(*window_id)?;
+            RenderTarget::Window(window_ref) => {
+                let window = windows.get(window_ref.normalize(primary_window)?).ok()?;
                 RenderTargetInfo {
                     physical_size: UVec2::new(window.physical_width(), window.physical_height()),
                     scale_factor: window.scale_factor(),
@@ -297,11 +307,14 @@ impl RenderTarget {
     // Check if this render target is contained in the given changed windows or images.
     fn is_changed(
         &self,
-        changed_window_ids: &[WindowId],
+        changed_windows: &[Entity],
+        primary_window: Option<Entity>,
         changed_image_handles: &HashSet<&Handle<Image>>,
     ) -> bool {
         match self {
-            RenderTarget::Window(window_id) => changed_window_ids.contains(window_id),
+            RenderTarget::Window(window_ref) => window_ref
+                .normalize(primary_window)
+                .map_or(false, |window| changed_windows.contains(&window)),
             RenderTarget::Image(image_handle) => changed_image_handles.contains(&image_handle),
         }
     }
@@ -322,36 +335,52 @@ impl Default for DepthCalculation {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn camera_system<T: CameraProjection + Component>(
     mut window_resized_events: EventReader<WindowResized>,
     mut window_created_events: EventReader<WindowCreated>,
+    mut window_closed_events: EventReader<WindowClosed>,
     mut image_asset_events: EventReader<AssetEvent<Image>>,
-    windows: Res<Windows>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut last_primary_window: Local<Option<Entity>>,
+    windows: Query<&Window>,
     images: Res<Assets<Image>>,
     mut queries: ParamSet<(
         Query<(Entity, &mut Camera, &mut T)>,
         Query<Entity, Added<Camera>>,
     )>,
 ) {
-    let mut changed_window_ids = Vec::new();
+    let primary_window = primary_window.get_single().ok();
+    // Cameras targeting the primary window target another one, or none, when it changes
+    let primary_window_changed = *last_primary_window != primary_window;
+    *last_primary_window = primary_window;
+
+    let mut changed_windows = Vec::new();
     // handle resize events. latest events are handled first because we only want to resize each
     // window once
     for event in window_resized_events.iter().rev() {
-        if changed_window_ids.contains(&event.id) {
+        if changed_windows.contains(&event.window) {
             continue;
         }
 
-        changed_window_ids.push(event.id);
+        changed_windows.push(event.window);
     }
 
     // handle resize events. latest events are handled first because we only want to resize each
     // window once
     for event in window_created_events.iter().rev() {
-        if changed_window_ids.contains(&event.id) {
+        if changed_windows.contains(&event.window) {
             continue;
         }
 
-        changed_window_ids.push(event.id);
+        changed_windows.push(event.window);
+    }
+
+    // closed windows leave their cameras without a target
+    for event in window_closed_events.iter() {
+        if !changed_windows.contains(&event.window) {
+            changed_windows.push(event.window);
+        }
     }
 
     let changed_image_handles: HashSet<&Handle<Image>> = image_asset_events
@@ -372,11 +401,15 @@ pub fn camera_system<T: CameraProjection + Component>(
     for (entity, mut camera, mut camera_projection) in queries.p0().iter_mut() {
         if camera
             .target
-            .is_changed(&changed_window_ids, &changed_image_handles)
+            .is_changed(&changed_windows, primary_window, &changed_image_handles)
+            || (primary_window_changed && camera.target == RenderTarget::Window(WindowRef::Primary))
             || added_cameras.contains(&entity)
             || camera_projection.is_changed()
         {
-            camera.computed.target_info = camera.target.get_render_target_info(&windows, &images);
+            camera.computed.target_info =
+                camera
+                    .target
+                    .get_render_target_info(&windows, primary_window, &images);
             if let Some(size) = camera.logical_viewport_size() {
                 camera_projection.update(size.x, size.y);
                 camera.computed.projection_matrix = camera_projection.get_projection_matrix();
@@ -437,3 +470,169 @@ pub fn extract_cameras(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{camera_system, Camera, RenderTarget};
+    use crate::{
+        camera::{OrthographicProjection, PerspectiveProjection},
+        texture::Image,
+        view::{update_window_images, WindowImage},
+    };
+    use bevy_app::{App, CoreStage};
+    use bevy_asset::{AddAsset, AssetPlugin, Assets};
+    use bevy_ecs::{
+        entity::Entity, event::Events, query::With, schedule::ParallelSystemDescriptorCoercion,
+    };
+    use bevy_math::{UVec2, Vec2};
+    use bevy_window::{
+        headless::{HeadlessWindowPlugin, HeadlessWindowSettings},
+        ModifiesWindows, PrimaryWindow, Window, WindowClosed, WindowCreated, WindowDescriptor,
+        WindowPlugin, WindowRef,
+    };
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<Image>()
+            .add_plugin(WindowPlugin {
+                add_primary_window: false,
+                exit_on_all_closed: false,
+                close_when_requested: false,
+            })
+            .add_system(camera_system::<OrthographicProjection>);
+        app
+    }
+
+    /// Opens a window on `entity`, as a windowing backend would.
+    fn open_window(app: &mut App, entity: Entity, width: u32, height: u32) {
+        let mut window = Window::new(&WindowDescriptor::default());
+        window.update_actual_size_from_backend(width, height);
+        app.world.entity_mut(entity).insert(window);
+        app.world
+            .resource_mut::<Events<WindowCreated>>()
+            .send(WindowCreated { window: entity });
+    }
+
+    fn spawn_camera(app: &mut App, target: WindowRef) -> Entity {
+        app.world
+            .spawn()
+            .insert_bundle((
+                Camera {
+                    target: RenderTarget::Window(target),
+                    ..Default::default()
+                },
+                OrthographicProjection::default(),
+            ))
+            .id()
+    }
+
+    fn target_size(app: &App, camera: Entity) -> Option<UVec2> {
+        app.world
+            .get::<Camera>(camera)
+            .unwrap()
+            .physical_target_size()
+    }
+
+    #[test]
+    fn window_ref_resolution() {
+        let mut app = app();
+        let primary = app.world.spawn().insert(PrimaryWindow).id();
+        open_window(&mut app, primary, 800, 600);
+        let other = app.world.spawn().id();
+        open_window(&mut app, other, 400, 300);
+        let primary_camera = spawn_camera(&mut app, WindowRef::Primary);
+        let other_camera = spawn_camera(&mut app, WindowRef::Entity(other));
+
+        app.update();
+        assert_eq!(
+            target_size(&app, primary_camera),
+            Some(UVec2::new(800, 600))
+        );
+        assert_eq!(target_size(&app, other_camera), Some(UVec2::new(400, 300)));
+    }
+
+    #[test]
+    fn primary_window_removal() {
+        let mut app = app();
+        let first = app.world.spawn().insert(PrimaryWindow).id();
+        open_window(&mut app, first, 800, 600);
+        let second = app.world.spawn().id();
+        open_window(&mut app, second, 400, 300);
+        let camera = spawn_camera(&mut app, WindowRef::Primary);
+        app.update();
+        assert_eq!(target_size(&app, camera), Some(UVec2::new(800, 600)));
+
+        app.world.entity_mut(first).remove::<PrimaryWindow>();
+        app.update();
+        assert_eq!(target_size(&app, camera), None);
+
+        app.world.entity_mut(second).insert(PrimaryWindow);
+        app.update();
+        assert_eq!(target_size(&app, camera), Some(UVec2::new(400, 300)));
+    }
+
+    #[test]
+    fn window_spawn_and_despawn() {
+        let mut app = app();
+        let window = app.world.spawn().id();
+        let camera = spawn_camera(&mut app, WindowRef::Entity(window));
+        app.update();
+        assert_eq!(target_size(&app, camera), None);
+
+        open_window(&mut app, window, 800, 600);
+        app.update();
+        assert_eq!(target_size(&app, camera), Some(UVec2::new(800, 600)));
+
+        app.world.despawn(window);
+        app.world
+            .resource_mut::<Events<WindowClosed>>()
+            .send(WindowClosed { window });
+        app.update();
+        assert_eq!(target_size(&app, camera), None);
+    }
+
+    #[test]
+    fn headless_window_target() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<Image>()
+            .insert_resource(HeadlessWindowSettings { scale_factor: 2.0 })
+            .add_plugin(WindowPlugin::default())
+            .add_plugin(HeadlessWindowPlugin)
+            .add_system(camera_system::<PerspectiveProjection>)
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                update_window_images.after(ModifiesWindows),
+            );
+        let camera = app
+            .world
+            .spawn()
+            .insert_bundle((Camera::default(), PerspectiveProjection::default()))
+            .id();
+        app.update();
+        let primary = app
+            .world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(&app.world);
+
+        let projection = app.world.get::<PerspectiveProjection>(camera).unwrap();
+        assert_eq!(projection.aspect_ratio, 1280.0 / 720.0);
+        assert_eq!(target_size(&app, camera), Some(UVec2::new(2560, 1440)));
+        let WindowImage(image) = app.world.get::<WindowImage>(primary).unwrap();
+        let image = app.world.resource::<Assets<Image>>().get(image).unwrap();
+        assert_eq!(image.size(), Vec2::new(2560.0, 1440.0));
+
+        app.world
+            .get_mut::<Window>(primary)
+            .unwrap()
+            .set_resolution(400.0, 200.0);
+        app.update();
+        app.update();
+        let projection = app.world.get::<PerspectiveProjection>(camera).unwrap();
+        assert_eq!(projection.aspect_ratio, 2.0);
+        let WindowImage(image) = app.world.get::<WindowImage>(primary).unwrap();
+        let image = app.world.resource::<Assets<Image>>().get(image).unwrap();
+        assert_eq!(image.size(), Vec2::new(800.0, 400.0));
+    }
+}
@@ -40,6 +40,7 @@ impl Node for CameraDriverNode {
             std::cmp::Ordering::Equal => t1.cmp(t2),
             ord => ord,
         });
+        let windows = world.resource::<ExtractedWindows>();
         let mut camera_windows = HashSet::new();
         let mut previous_priority_target = None;
         let mut ambiguities = HashSet::new();
@@ -52,8 +53,10 @@ impl Node for CameraDriverNode {
             }
             previous_priority_target = Some(new_priority_target);
             if let Ok((_, camera)) = self.cameras.get_manual(world, entity) {
-                if let RenderTarget::Window(id) = camera.target {
-                    camera_windows.insert(id);
+                if let RenderTarget::Window(window_ref) = camera.target {
+                    if let Some(window) = window_ref.normalize(windows.primary) {
+                        camera_windows.insert(window);
+                    }
                 }
                 graph
                     .run_sub_graph(camera.render_graph.clone(), vec![SlotValue::Entity(entity)])?;
@@ -73,8 +76,8 @@ impl Node for CameraDriverNode {
 
         // wgpu (and some backends) require doing work for swap chains if you call `get_current_texture()` and `present()`
         // This ensures that Bevy doesn't crash, even when there are no cameras (and therefore no work submitted).
-        for (id, window) in world.resource::<ExtractedWindows>().iter() {
-            if camera_windows.contains(id) {
+        for (entity, window) in windows.iter() {
+            if camera_windows.contains(entity) {
                 continue;
             }
 
@@ -5,6 +5,7 @@ pub mod color;
 pub mod extract_component;
 pub mod extract_resource;
 pub mod mesh;
+pub mod pipelined_rendering;
 pub mod primitives;
 pub mod render_asset;
 pub mod render_graph;
@@ -40,10 +41,11 @@ use crate::{
     texture::ImagePlugin,
     view::{ViewPlugin, WindowRenderPlugin},
 };
-use bevy_app::{App, AppLabel, Plugin};
-use bevy_asset::{AddAsset, AssetServer};
+use bevy_app::{App, AppLabel, Plugin, PluginDependency};
+use bevy_asset::{AddAsset, AssetPlugin, AssetServer};
 use bevy_ecs::prelude::*;
 use bevy_utils::tracing::debug;
+use bevy_window::{PrimaryWindow, RawWindowHandleWrapper};
 use std::ops::{Deref, DerefMut};
 
 /// Contains the default Bevy rendering backend based on wgpu.
@@ -129,11 +131,16 @@ impl Plugin for RenderPlugin {
         if let Some(backends) = options.backends {
             let instance = wgpu::Instance::new(backends);
             let surface = {
-                let windows = app.world.resource_mut::<bevy_window::Windows>();
-                let raw_handle = windows.get_primary().map(|window| unsafe {
-                    let handle = window.raw_window_handle().get_handle();
-                    instance.create_surface(&handle)
-                });
+                let mut primary_window = app
+                    .world
+                    .query_filtered::<&RawWindowHandleWrapper, With<PrimaryWindow>>();
+                let raw_handle = primary_window
+                    .get_single(&app.world)
+                    .ok()
+                    .map(|wrapper| unsafe {
+                        let handle = wrapper.get_handle();
+                        instance.create_surface(&handle)
+                    });
                 raw_handle
             };
             let request_adapter_options = wgpu::RequestAdapterOptions {
@@ -183,9 +190,10 @@ impl Plugin for RenderPlugin {
                 .insert_resource(asset_server)
                 .init_resource::<RenderGraph>();
 
-            app.add_sub_app(RenderApp, render_app, move |app_world, render_app| {
+            let render_extract = |app_world: &mut World, render_app: &mut App| {
                 #[cfg(feature = "trace")]
-                let _render_span = bevy_utils::tracing::info_span!("renderer subapp").entered();
+                let _render_span =
+                    bevy_utils::tracing::info_span!("renderer subapp extract").entered();
                 {
                     #[cfg(feature = "trace")]
                     let _stage_span =
@@ -214,7 +222,10 @@ impl Plugin for RenderPlugin {
                     // extract
                     extract(app_world, render_app);
                 }
-
+            };
+            let render_update = |render_app: &mut App| {
+                #[cfg(feature = "trace")]
+                let _render_span = bevy_utils::tracing::info_span!("renderer subapp").entered();
                 {
                     #[cfg(feature = "trace")]
                     let _stage_span =
@@ -286,7 +297,8 @@ impl Plugin for RenderPlugin {
 
                     render_app.world.clear_entities();
                 }
-            });
+            };
+            app.add_sub_app_with_update(RenderApp, render_app, render_extract, render_update);
         }
 
         app.add_plugin(WindowRenderPlugin)
@@ -297,6 +309,13 @@ impl Plugin for RenderPlugin {
             // compressed texture formats
             .add_plugin(ImagePlugin);
     }
+
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        vec![
+            PluginDependency::required::<AssetPlugin>(),
+            PluginDependency::required::<bevy_window::WindowPlugin>(),
+        ]
+    }
 }
 
 /// Executes the [`Extract`](RenderStage::Extract) stage of the renderer.
@@ -0,0 +1,29 @@
+use crate::{RenderApp, RenderPlugin};
+use bevy_app::{App, Plugin, PluginDependency};
+
+/// Updates the render app on its own thread, in parallel with the main app.
+///
+/// Each frame, the main world is extracted to the render world before the main schedule runs,
+/// and is then rendered while the main schedule runs. Frames are displayed one update later, in
+/// exchange for app logic and rendering no longer waiting for each other.
+///
+/// The render world is then owned by the render thread, so render systems accessing non-send
+/// resources, such as [`prepare_windows`](crate::view::prepare_windows), run on that thread
+/// instead of the main thread.
+///
+/// See [`App::pipeline_sub_app`].
+#[derive(Default)]
+pub struct PipelinedRenderingPlugin;
+
+impl Plugin for PipelinedRenderingPlugin {
+    fn build(&self, app: &mut App) {
+        // There is no render app when no GPU was found
+        if app.get_sub_app(RenderApp).is_ok() {
+            app.pipeline_sub_app(RenderApp);
+        }
+    }
+
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        vec![PluginDependency::required::<RenderPlugin>()]
+    }
+}
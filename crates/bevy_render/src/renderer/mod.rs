@@ -44,6 +44,9 @@ pub fn render_system(world: &mut World) {
         panic!("Error running render graph: {}", e);
     }
 
+    #[cfg(feature = "bevy_ci_testing")]
+    crate::view::screenshot::save_screenshot(world);
+
     {
         let _span = info_span!("present_frames").entered();
 
@@ -17,6 +17,7 @@ use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::system::{lifetimeless::SRes, SystemParamItem};
 use bevy_math::Vec2;
 use bevy_reflect::TypeUuid;
+use bevy_window::WindowIcon;
 use std::hash::Hash;
 use thiserror::Error;
 use wgpu::{
@@ -354,6 +355,19 @@ impl Image {
             })
     }
 
+    /// Converts the image to a [`WindowIcon`], see [`Window::icon_image`](bevy_window::Window::icon_image).
+    ///
+    /// Returns `None` if the format of the image can't be [converted](Image::convert).
+    pub fn window_icon(&self) -> Option<WindowIcon> {
+        let rgba = self.convert(TextureFormat::Rgba8UnormSrgb)?;
+        let size = rgba.texture_descriptor.size;
+        Some(WindowIcon {
+            rgba: rgba.data,
+            width: size.width,
+            height: size.height,
+        })
+    }
+
     /// Load a bytes buffer in a [`Image`], according to type `image_type`, using the `image`
     /// crate
     pub fn from_buffer(
@@ -804,4 +818,30 @@ mod test {
         let image = Image::default();
         assert_eq!(Vec2::new(1.0, 1.0), image.size());
     }
+
+    #[test]
+    fn image_window_icon() {
+        let size = Extent3d {
+            width: 2,
+            height: 1,
+            depth_or_array_layers: 1,
+        };
+        let image = Image::new_fill(
+            size,
+            TextureDimension::D2,
+            &[255, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+        );
+        let icon = image.window_icon().unwrap();
+        assert_eq!((icon.width, icon.height), (2, 1));
+        assert_eq!(icon.rgba, vec![255, 0, 0, 255, 255, 0, 0, 255]);
+
+        let image = Image::new_fill(
+            size,
+            TextureDimension::D2,
+            &[0; 16],
+            TextureFormat::Rgba32Float,
+        );
+        assert!(image.window_icon().is_none());
+    }
 }
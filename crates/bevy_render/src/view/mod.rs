@@ -1,3 +1,5 @@
+#[cfg(feature = "bevy_ci_testing")]
+pub mod screenshot;
 pub mod visibility;
 pub mod window;
 
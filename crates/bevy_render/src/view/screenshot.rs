@@ -0,0 +1,135 @@
+use crate::{
+    render_asset::RenderAssets,
+    renderer::{RenderDevice, RenderQueue},
+    texture::{BevyDefault, Image, TextureFormatPixelInfo},
+    view::ExtractedWindows,
+    RenderWorld,
+};
+use bevy_app::CiTestingEvent;
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::{info, warn};
+use std::num::NonZeroU32;
+use wgpu::{Extent3d, TextureFormat};
+
+/// The frame on which a [`CiTestingEvent::Screenshot`] was sent, if any, for the render world to
+/// capture the primary window.
+#[derive(Default)]
+pub struct ScreenshotRequest(pub Option<u32>);
+
+/// Extracts the [`CiTestingEvent::Screenshot`]s of the current frame.
+pub(crate) fn extract_screenshot_request(
+    mut render_world: ResMut<RenderWorld>,
+    mut ci_testing_events: EventReader<CiTestingEvent>,
+    mut current_frame: Local<u32>,
+) {
+    if ci_testing_events
+        .iter()
+        .any(|event| *event == CiTestingEvent::Screenshot)
+    {
+        render_world.resource_mut::<ScreenshotRequest>().0 = Some(*current_frame);
+    }
+    *current_frame += 1;
+}
+
+/// Saves the primary window to `screenshot-<frame>.png` if a [`ScreenshotRequest`] is pending.
+///
+/// This reads back the swapchain texture of the window, or its
+/// [`WindowImage`](crate::view::WindowImage) if it has no surface, so it must run once the frame
+/// is rendered and before it is presented.
+pub(crate) fn save_screenshot(world: &mut World) {
+    let frame = match world.resource_mut::<ScreenshotRequest>().0.take() {
+        Some(frame) => frame,
+        None => return,
+    };
+    let windows = world.resource::<ExtractedWindows>();
+    let gpu_images = world.resource::<RenderAssets<Image>>();
+    let primary = match windows.primary {
+        Some(primary) => primary,
+        None => {
+            warn!(
+                "No primary window to take a screenshot of on frame {}",
+                frame
+            );
+            return;
+        }
+    };
+    let rendered = match (windows.get(&primary), windows.images.get(&primary)) {
+        (Some(window), _) => window
+            .swap_chain_texture
+            .as_ref()
+            .and_then(|view| view.surface_texture())
+            .map(|surface_texture| {
+                let size = (window.physical_width, window.physical_height);
+                (&surface_texture.texture, size)
+            }),
+        (None, Some(image)) => gpu_images.get(image).map(|gpu_image| {
+            let size = (gpu_image.size.x as u32, gpu_image.size.y as u32);
+            (&*gpu_image.texture, size)
+        }),
+        (None, None) => None,
+    };
+    let (texture, size) = match rendered {
+        Some((texture, (width, height))) => (
+            texture,
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        ),
+        None => {
+            warn!("The primary window wasn't rendered on frame {}", frame);
+            return;
+        }
+    };
+
+    let render_device = world.resource::<RenderDevice>();
+    let render_queue = world.resource::<RenderQueue>();
+    let pixel_size = TextureFormat::bevy_default().pixel_size();
+    let row_bytes = size.width as usize * pixel_size;
+    let padded_row_bytes = RenderDevice::align_copy_bytes_per_row(row_bytes);
+    let buffer = render_device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("screenshot_buffer"),
+        size: (padded_row_bytes * size.height as usize) as u64,
+        usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    let mut encoder = render_device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("screenshot_encoder"),
+    });
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: NonZeroU32::new(padded_row_bytes as u32),
+                rows_per_image: None,
+            },
+        },
+        size,
+    );
+    render_queue.submit([encoder.finish()]);
+
+    let slice = buffer.slice(..);
+    render_device.map_buffer(&slice, wgpu::MapMode::Read);
+    let mut rgba = Vec::with_capacity(row_bytes * size.height as usize);
+    for row in slice.get_mapped_range().chunks(padded_row_bytes) {
+        rgba.extend_from_slice(&row[..row_bytes]);
+    }
+    buffer.unmap();
+    if TextureFormat::bevy_default() == TextureFormat::Bgra8UnormSrgb {
+        for pixel in rgba.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+        }
+    }
+
+    let path = format!("screenshot-{}.png", frame);
+    match image::RgbaImage::from_raw(size.width, size.height, rgba)
+        .expect("the screenshot buffer has the size of the window")
+        .save(&path)
+    {
+        Ok(()) => info!("Saved screenshot of frame {} to {}", frame, path),
+        Err(err) => warn!("Unable to save screenshot of frame {}: {}", frame, err),
+    }
+}
@@ -1,15 +1,19 @@
 use crate::{
     render_resource::TextureView,
     renderer::{RenderDevice, RenderInstance},
-    texture::BevyDefault,
+    texture::{BevyDefault, Image},
     RenderApp, RenderStage, RenderWorld,
 };
-use bevy_app::{App, Plugin};
+use bevy_app::{App, CoreStage, Plugin};
+use bevy_asset::{AssetEvent, Assets, Handle};
 use bevy_ecs::prelude::*;
 use bevy_utils::{tracing::debug, HashMap, HashSet};
-use bevy_window::{PresentMode, RawWindowHandleWrapper, WindowClosed, WindowId, Windows};
+use bevy_window::{
+    headless::HeadlessWindow, ModifiesWindows, PresentMode, PrimaryWindow, RawWindowHandleWrapper,
+    Window, WindowClosed,
+};
 use std::ops::{Deref, DerefMut};
-use wgpu::TextureFormat;
+use wgpu::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
 
 /// Token to ensure a system runs on the main thread.
 #[derive(Default)]
@@ -24,6 +28,15 @@ pub enum WindowSystem {
 
 impl Plugin for WindowRenderPlugin {
     fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            update_window_images.after(ModifiesWindows),
+        )
+        .add_system_to_stage(
+            CoreStage::PostUpdate,
+            update_window_icons.before(ModifiesWindows),
+        );
+
         if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
                 .init_resource::<ExtractedWindows>()
@@ -34,12 +47,21 @@ impl Plugin for WindowRenderPlugin {
                     RenderStage::Prepare,
                     prepare_windows.label(WindowSystem::Prepare),
                 );
+
+            #[cfg(feature = "bevy_ci_testing")]
+            render_app
+                .init_resource::<super::screenshot::ScreenshotRequest>()
+                .add_system_to_stage(
+                    RenderStage::Extract,
+                    super::screenshot::extract_screenshot_request,
+                );
         }
     }
 }
 
 pub struct ExtractedWindow {
-    pub id: WindowId,
+    /// An entity that contains the components in [`Window`].
+    pub entity: Entity,
     pub handle: RawWindowHandleWrapper,
     pub physical_width: u32,
     pub physical_height: u32,
@@ -50,11 +72,86 @@ pub struct ExtractedWindow {
 
 #[derive(Default)]
 pub struct ExtractedWindows {
-    pub windows: HashMap<WindowId, ExtractedWindow>,
+    /// The entity of the window marked with [`PrimaryWindow`], if any.
+    pub primary: Option<Entity>,
+    pub windows: HashMap<Entity, ExtractedWindow>,
+    /// The images rendered to instead of the windows without a surface, see [`WindowImage`].
+    pub images: HashMap<Entity, Handle<Image>>,
+}
+
+/// The image a [`Window`] without a surface, like a [`HeadlessWindow`], is rendered to.
+///
+/// It is added to the window entity and keeps the physical size of the window, so cameras
+/// targeting the window render offscreen to this image, whose content can be read back or
+/// displayed by another camera.
+#[derive(Component, Debug, Clone)]
+pub struct WindowImage(pub Handle<Image>);
+
+/// Creates and resizes the [`WindowImage`] of each [`HeadlessWindow`].
+pub fn update_window_images(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    windows: Query<(Entity, &Window, Option<&WindowImage>), With<HeadlessWindow>>,
+) {
+    for (entity, window, window_image) in windows.iter() {
+        let size = Extent3d {
+            width: window.physical_width().max(1),
+            height: window.physical_height().max(1),
+            depth_or_array_layers: 1,
+        };
+        let current = window_image.and_then(|WindowImage(handle)| {
+            let image = images.get(handle)?;
+            Some((handle, image.texture_descriptor.size))
+        });
+        match current {
+            Some((_, current_size)) if current_size == size => {}
+            Some((handle, _)) => images.get_mut(handle).unwrap().resize(size),
+            None => {
+                let mut image = Image::default();
+                image.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING
+                    | TextureUsages::COPY_SRC
+                    | TextureUsages::COPY_DST
+                    | TextureUsages::RENDER_ATTACHMENT;
+                image.texture_descriptor.dimension = TextureDimension::D2;
+                image.resize(size);
+                let handle = images.add(image);
+                commands.entity(entity).insert(WindowImage(handle));
+            }
+        }
+    }
+}
+
+/// Reads the pixels of the [icon](Window::icon) of each window from its [`Image`], once loaded
+/// or modified, for the window backend to show.
+pub fn update_window_icons(
+    mut image_events: EventReader<AssetEvent<Image>>,
+    images: Res<Assets<Image>>,
+    mut windows: Query<&mut Window>,
+) {
+    let changed = image_events
+        .iter()
+        .filter_map(|event| match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => Some(handle.id),
+            AssetEvent::Removed { .. } => None,
+        })
+        .collect::<HashSet<_>>();
+    for mut window in windows.iter_mut() {
+        let id = match window.icon() {
+            Some(icon) => icon.id,
+            None => continue,
+        };
+        if window.icon_image_id() == Some(id) && !changed.contains(&id) {
+            continue;
+        }
+        let icon_image = images
+            .get(&Handle::<Image>::weak(id))
+            .and_then(Image::window_icon);
+        window.update_icon_image_from_renderer(id, icon_image);
+    }
 }
 
 impl Deref for ExtractedWindows {
-    type Target = HashMap<WindowId, ExtractedWindow>;
+    type Target = HashMap<Entity, ExtractedWindow>;
 
     fn deref(&self) -> &Self::Target {
         &self.windows
@@ -70,27 +167,43 @@ impl DerefMut for ExtractedWindows {
 fn extract_windows(
     mut render_world: ResMut<RenderWorld>,
     mut closed: EventReader<WindowClosed>,
-    windows: Res<Windows>,
+    windows: Query<(
+        Entity,
+        &Window,
+        &RawWindowHandleWrapper,
+        Option<&PrimaryWindow>,
+    )>,
+    window_images: Query<(Entity, &WindowImage, Option<&PrimaryWindow>)>,
 ) {
     let mut extracted_windows = render_world.get_resource_mut::<ExtractedWindows>().unwrap();
-    for window in windows.iter() {
+    extracted_windows.images.clear();
+    for (entity, window_image, primary) in window_images.iter() {
+        if primary.is_some() {
+            extracted_windows.primary = Some(entity);
+        }
+        extracted_windows
+            .images
+            .insert(entity, window_image.0.clone_weak());
+    }
+    for (entity, window, handle, primary) in windows.iter() {
+        if primary.is_some() {
+            extracted_windows.primary = Some(entity);
+        }
+
         let (new_width, new_height) = (
             window.physical_width().max(1),
             window.physical_height().max(1),
         );
 
-        let mut extracted_window =
-            extracted_windows
-                .entry(window.id())
-                .or_insert(ExtractedWindow {
-                    id: window.id(),
-                    handle: window.raw_window_handle(),
-                    physical_width: new_width,
-                    physical_height: new_height,
-                    present_mode: window.present_mode(),
-                    swap_chain_texture: None,
-                    size_changed: false,
-                });
+        let mut extracted_window = extracted_windows.entry(entity).or_insert(ExtractedWindow {
+            entity,
+            handle: handle.clone(),
+            physical_width: new_width,
+            physical_height: new_height,
+            present_mode: window.present_mode(),
+            swap_chain_texture: None,
+            size_changed: false,
+        });
 
         // NOTE: Drop the swap chain frame here
         extracted_window.swap_chain_texture = None;
@@ -110,15 +223,18 @@ fn extract_windows(
         }
     }
     for closed_window in closed.iter() {
-        extracted_windows.remove(&closed_window.id);
+        extracted_windows.remove(&closed_window.window);
+        if extracted_windows.primary == Some(closed_window.window) {
+            extracted_windows.primary = None;
+        }
     }
 }
 
 #[derive(Default)]
 pub struct WindowSurfaces {
-    surfaces: HashMap<WindowId, wgpu::Surface>,
+    surfaces: HashMap<Entity, wgpu::Surface>,
     /// List of windows that we have already called the initial `configure_surface` for
-    configured_windows: HashSet<WindowId>,
+    configured_windows: HashSet<Entity>,
 }
 
 /// Creates and (re)configures window surfaces, and obtains a swapchain texture for rendering.
@@ -144,7 +260,7 @@ pub struct WindowSurfaces {
 ///   later.
 pub fn prepare_windows(
     // By accessing a NonSend resource, we tell the scheduler to put this system on the main thread,
-    // which is necessary for some OS s
+    // which is necessary for some OS s. With pipelined rendering, this is the render thread.
     _marker: NonSend<NonSendMarker>,
     mut windows: ResMut<ExtractedWindows>,
     mut window_surfaces: ResMut<WindowSurfaces>,
@@ -152,10 +268,17 @@ pub fn prepare_windows(
     render_instance: Res<RenderInstance>,
 ) {
     let window_surfaces = window_surfaces.deref_mut();
+    // Drop the surfaces of closed windows, as their entity may be reused
+    window_surfaces
+        .surfaces
+        .retain(|entity, _| windows.contains_key(entity));
+    window_surfaces
+        .configured_windows
+        .retain(|entity| windows.contains_key(entity));
     for window in windows.windows.values_mut() {
         let surface = window_surfaces
             .surfaces
-            .entry(window.id)
+            .entry(window.entity)
             .or_insert_with(|| unsafe {
                 // NOTE: On some OSes this MUST be called from the main thread.
                 render_instance.create_surface(&window.handle.get_handle())
@@ -165,7 +288,12 @@ pub fn prepare_windows(
             format: TextureFormat::bevy_default(),
             width: window.physical_width,
             height: window.physical_height,
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            // In CI, the swapchain textures are copied from to take screenshots
+            usage: if cfg!(feature = "bevy_ci_testing") {
+                TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC
+            } else {
+                TextureUsages::RENDER_ATTACHMENT
+            },
             present_mode: match window.present_mode {
                 PresentMode::Fifo => wgpu::PresentMode::Fifo,
                 PresentMode::Mailbox => wgpu::PresentMode::Mailbox,
@@ -174,7 +302,7 @@ pub fn prepare_windows(
         };
 
         // Do the initial surface configuration if it hasn't been configured yet
-        if window_surfaces.configured_windows.insert(window.id) || window.size_changed {
+        if window_surfaces.configured_windows.insert(window.entity) || window.size_changed {
             render_device.configure_surface(surface, &swap_chain_descriptor);
         }
 
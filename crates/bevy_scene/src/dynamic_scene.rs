@@ -1,12 +1,15 @@
 use crate::{serde::SceneSerializer, Scene, SceneSpawnError};
 use anyhow::Result;
+use bevy_asset::{Handle, HandleId};
 use bevy_ecs::{
-    entity::EntityMap,
-    reflect::{ReflectComponent, ReflectMapEntities},
+    entity::{Entity, EntityMap},
+    reflect::{ReflectComponent, ReflectMapEntities, ReflectResource},
     world::World,
 };
 use bevy_reflect::{Reflect, TypeRegistryArc, TypeUuid};
+use bevy_utils::HashMap;
 use serde::Serialize;
+use std::any::TypeId;
 
 /// A collection of serializable dynamic entities, each with its own run-time defined set of components.
 /// To spawn a dynamic scene, you can use either:
@@ -18,7 +21,13 @@ use serde::Serialize;
 #[derive(Default, TypeUuid)]
 #[uuid = "749479b1-fb8c-4ff8-a775-623aa76014f5"]
 pub struct DynamicScene {
+    /// Boxed resources that implement the `Reflect` trait and are registered with
+    /// `#[reflect(Resource)]`.
+    pub resources: Vec<Box<dyn Reflect>>,
     pub entities: Vec<DynamicEntity>,
+    /// Other scenes nested in this one. They are spawned along with this scene by the
+    /// [`SceneSpawner`](crate::SceneSpawner).
+    pub instances: Vec<DynamicSceneInstance>,
 }
 
 /// A reflection-powered serializable representation of an entity and its components.
@@ -30,6 +39,40 @@ pub struct DynamicEntity {
     pub components: Vec<Box<dyn Reflect>>,
 }
 
+/// A reference from a [`DynamicScene`] to another scene, spawned as children of one of its
+/// entities.
+pub struct DynamicSceneInstance {
+    /// The entity the root entities of the nested scene are parented to.
+    pub entity: u32,
+    /// The asset path of the nested scene.
+    pub path: String,
+    /// The handle of the nested scene. The [`SceneLoader`](crate::SceneLoader) replaces it with
+    /// a strong handle to `path`.
+    pub handle: Handle<DynamicScene>,
+    /// Components added to the entities of the nested scene, replacing the ones they already
+    /// have. Entity ids refer to the entities of the nested scene.
+    pub overrides: Vec<DynamicEntity>,
+}
+
+impl DynamicSceneInstance {
+    /// Create a reference to the scene at `path`, nested under `entity`.
+    pub fn new(entity: u32, path: impl Into<String>) -> Self {
+        let path = path.into();
+        DynamicSceneInstance {
+            entity,
+            handle: Handle::weak(HandleId::from(&path)),
+            path,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Add components overriding the ones of an entity of the nested scene.
+    pub fn with_override(mut self, entity: DynamicEntity) -> Self {
+        self.overrides.push(entity);
+        self
+    }
+}
+
 impl DynamicScene {
     /// Create a new dynamic scene from a given scene.
     pub fn from_scene(scene: &Scene, type_registry: &TypeRegistryArc) -> Self {
@@ -73,13 +116,26 @@ impl DynamicScene {
             }
         }
 
+        // Add each reflection-powered resource.
+        for registration in type_registry.iter() {
+            if let Some(resource) = registration
+                .data::<ReflectResource>()
+                .and_then(|reflect_resource| reflect_resource.reflect_resource(world))
+            {
+                scene.resources.push(resource.clone_value());
+            }
+        }
+
         scene
     }
 
     /// Write the dynamic entities and their corresponding components to the given world.
     ///
+    /// Nested scene [`instances`](Self::instances) are not written, they are resolved by the
+    /// [`SceneSpawner`](crate::SceneSpawner).
+    ///
     /// This method will return a `SceneSpawnError` if either a type is not registered
-    /// or doesn't reflect the `Component` trait.
+    /// or doesn't reflect the `Component` or `Resource` trait.
     pub fn write_to_world(
         &self,
         world: &mut World,
@@ -88,12 +144,49 @@ impl DynamicScene {
         let registry = world.resource::<TypeRegistryArc>().clone();
         let type_registry = registry.read();
 
-        for scene_entity in &self.entities {
+        for resource in &self.resources {
+            let registration = type_registry
+                .get_with_name(resource.type_name())
+                .ok_or_else(|| SceneSpawnError::UnregisteredType {
+                    type_name: resource.type_name().to_string(),
+                })?;
+            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredResource {
+                    type_name: resource.type_name().to_string(),
+                }
+            })?;
+
+            // Apply the new value if the resource already exists, otherwise insert it.
+            if reflect_resource.reflect_resource(world).is_some() {
+                reflect_resource.apply_resource(world, &**resource);
+            } else {
+                reflect_resource.insert_resource(world, &**resource);
+            }
+        }
+
+        drop(type_registry);
+        Self::write_entities(world, &self.entities, entity_map)
+    }
+
+    /// Write `entities` to the given world, mapping their ids through `entity_map`.
+    pub(crate) fn write_entities(
+        world: &mut World,
+        entities: &[DynamicEntity],
+        entity_map: &mut EntityMap,
+    ) -> Result<(), SceneSpawnError> {
+        let registry = world.resource::<TypeRegistryArc>().clone();
+        let type_registry = registry.read();
+
+        // The entities each component type was written to, so that only the components coming
+        // from the scene get their entity references mapped.
+        let mut written_components: HashMap<TypeId, Vec<Entity>> = HashMap::default();
+
+        for scene_entity in entities {
             // Fetch the entity with the given entity id from the `entity_map`
             // or spawn a new entity with a transiently unique id if there is
             // no corresponding entry.
             let entity = *entity_map
-                .entry(bevy_ecs::entity::Entity::from_raw(scene_entity.entity))
+                .entry(Entity::from_raw(scene_entity.entity))
                 .or_insert_with(|| world.spawn().id());
 
             // Apply/ add each component to the given entity.
@@ -121,13 +214,20 @@ impl DynamicScene {
                 } else {
                     reflect_component.add_component(world, entity, &**component);
                 }
+                written_components
+                    .entry(registration.type_id())
+                    .or_default()
+                    .push(entity);
             }
         }
 
-        for registration in type_registry.iter() {
-            if let Some(map_entities_reflect) = registration.data::<ReflectMapEntities>() {
+        for (type_id, entities) in &written_components {
+            if let Some(map_entities_reflect) = type_registry
+                .get(*type_id)
+                .and_then(|registration| registration.data::<ReflectMapEntities>())
+            {
                 map_entities_reflect
-                    .map_entities(world, entity_map)
+                    .map_specific_entities(world, entity_map, entities)
                     .unwrap();
             }
         }
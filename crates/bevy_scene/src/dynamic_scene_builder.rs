@@ -0,0 +1,493 @@
+use crate::{DynamicEntity, DynamicScene};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    reflect::{ReflectComponent, ReflectResource},
+    system::Resource,
+    world::World,
+};
+use bevy_hierarchy::{Children, Parent};
+use bevy_reflect::{Reflect, ReflectMut, TypeRegistryArc};
+use bevy_utils::HashSet;
+use std::{any::TypeId, collections::BTreeMap};
+
+/// A set of types that are either allowed or denied when extracting a [`DynamicScene`].
+#[derive(Clone, Debug)]
+pub enum SceneFilter {
+    /// Every registered type is allowed.
+    AllowAll,
+    /// Only the listed types are allowed.
+    Allow(HashSet<TypeId>),
+    /// Every registered type except the listed ones is allowed.
+    Deny(HashSet<TypeId>),
+}
+
+impl Default for SceneFilter {
+    fn default() -> Self {
+        SceneFilter::AllowAll
+    }
+}
+
+impl SceneFilter {
+    /// Allow the type with the given [`TypeId`].
+    ///
+    /// A deny list stops denying the type, while [`SceneFilter::AllowAll`] becomes an allow list
+    /// containing only this type.
+    pub fn allow(&mut self, type_id: TypeId) {
+        match self {
+            SceneFilter::Allow(types) => {
+                types.insert(type_id);
+            }
+            SceneFilter::Deny(types) => {
+                types.remove(&type_id);
+            }
+            SceneFilter::AllowAll => *self = SceneFilter::Allow(HashSet::from_iter([type_id])),
+        }
+    }
+
+    /// Deny the type with the given [`TypeId`].
+    pub fn deny(&mut self, type_id: TypeId) {
+        match self {
+            SceneFilter::Allow(types) => {
+                types.remove(&type_id);
+            }
+            SceneFilter::Deny(types) => {
+                types.insert(type_id);
+            }
+            SceneFilter::AllowAll => *self = SceneFilter::Deny(HashSet::from_iter([type_id])),
+        }
+    }
+
+    /// Returns `true` if the type with the given [`TypeId`] passes this filter.
+    pub fn is_allowed(&self, type_id: TypeId) -> bool {
+        match self {
+            SceneFilter::AllowAll => true,
+            SceneFilter::Allow(types) => types.contains(&type_id),
+            SceneFilter::Deny(types) => !types.contains(&type_id),
+        }
+    }
+}
+
+/// A [`DynamicScene`] builder, used to extract only some entities, components and resources
+/// of a [`World`].
+///
+/// Entities are extracted together with all of their descendants by default, see
+/// [`DynamicSceneBuilder::with_descendants`].
+///
+/// # Example
+/// ```
+/// # use bevy_scene::DynamicSceneBuilder;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::TypeRegistryArc;
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(Component)]
+/// # struct Camera;
+/// # let mut world = World::default();
+/// # world.insert_resource(TypeRegistryArc::default());
+/// let mut players = world.query_filtered::<Entity, With<Player>>();
+///
+/// let mut builder = DynamicSceneBuilder::from_world(&world);
+/// builder
+///     .deny::<Camera>()
+///     .extract_entities(players.iter(&world))
+///     .extract_resources();
+/// let dynamic_scene = builder.build();
+/// ```
+pub struct DynamicSceneBuilder<'w> {
+    world: &'w World,
+    type_registry: TypeRegistryArc,
+    entities: BTreeMap<u32, DynamicEntity>,
+    resources: BTreeMap<&'static str, Box<dyn Reflect>>,
+    component_filter: SceneFilter,
+    resource_filter: SceneFilter,
+    include_descendants: bool,
+}
+
+impl<'w> DynamicSceneBuilder<'w> {
+    /// Prepare a builder that will extract entities and their components from the given
+    /// [`World`], using the world's [`TypeRegistryArc`] resource.
+    pub fn from_world(world: &'w World) -> Self {
+        let type_registry = world.resource::<TypeRegistryArc>().clone();
+        Self::from_world_with_type_registry(world, type_registry)
+    }
+
+    /// Prepare a builder that will extract entities and their components from the given
+    /// [`World`], using the given [`TypeRegistryArc`].
+    pub fn from_world_with_type_registry(world: &'w World, type_registry: TypeRegistryArc) -> Self {
+        DynamicSceneBuilder {
+            world,
+            type_registry,
+            entities: BTreeMap::default(),
+            resources: BTreeMap::default(),
+            component_filter: SceneFilter::default(),
+            resource_filter: SceneFilter::default(),
+            include_descendants: true,
+        }
+    }
+
+    /// Set whether extracting an entity also extracts its descendants, found through
+    /// [`Children`]. Defaults to `true`.
+    pub fn with_descendants(&mut self, include_descendants: bool) -> &mut Self {
+        self.include_descendants = include_descendants;
+        self
+    }
+
+    /// Only extract the component `T`, along with any other allowed components.
+    ///
+    /// If some components were denied, this only stops denying `T`.
+    pub fn allow<T: Component>(&mut self) -> &mut Self {
+        self.component_filter.allow(TypeId::of::<T>());
+        self
+    }
+
+    /// Never extract the component `T`.
+    pub fn deny<T: Component>(&mut self) -> &mut Self {
+        self.component_filter.deny(TypeId::of::<T>());
+        self
+    }
+
+    /// Only extract the resource `T`, along with any other allowed resources.
+    ///
+    /// If some resources were denied, this only stops denying `T`.
+    pub fn allow_resource<T: Resource>(&mut self) -> &mut Self {
+        self.resource_filter.allow(TypeId::of::<T>());
+        self
+    }
+
+    /// Never extract the resource `T`.
+    pub fn deny_resource<T: Resource>(&mut self) -> &mut Self {
+        self.resource_filter.deny(TypeId::of::<T>());
+        self
+    }
+
+    /// Replace the filter used for components.
+    pub fn with_component_filter(&mut self, filter: SceneFilter) -> &mut Self {
+        self.component_filter = filter;
+        self
+    }
+
+    /// Replace the filter used for resources.
+    pub fn with_resource_filter(&mut self, filter: SceneFilter) -> &mut Self {
+        self.resource_filter = filter;
+        self
+    }
+
+    /// Extract one entity, and its descendants if enabled.
+    ///
+    /// Re-extracting an entity that was already extracted has no effect.
+    pub fn extract_entity(&mut self, entity: Entity) -> &mut Self {
+        self.extract_entities(std::iter::once(entity))
+    }
+
+    /// Extract entities, and their descendants if enabled.
+    ///
+    /// To extract the entities matching a query, pass the iterator of a [`QueryState`]
+    /// that only fetches [`Entity`].
+    ///
+    /// Re-extracting an entity that was already extracted has no effect.
+    ///
+    /// [`QueryState`]: bevy_ecs::query::QueryState
+    pub fn extract_entities(&mut self, entities: impl Iterator<Item = Entity>) -> &mut Self {
+        let type_registry = self.type_registry.read();
+        let mut stack: Vec<Entity> = entities.collect();
+
+        while let Some(entity) = stack.pop() {
+            if self.entities.contains_key(&entity.id()) {
+                continue;
+            }
+            let entity_ref = match self.world.get_entity(entity) {
+                Some(entity_ref) => entity_ref,
+                None => continue,
+            };
+
+            let mut dynamic_entity = DynamicEntity {
+                entity: entity.id(),
+                components: Vec::new(),
+            };
+            for component_id in entity_ref.archetype().components() {
+                let reflect_component = self
+                    .world
+                    .components()
+                    .get_info(component_id)
+                    .and_then(|info| info.type_id())
+                    .filter(|type_id| self.component_filter.is_allowed(*type_id))
+                    .and_then(|type_id| type_registry.get(type_id))
+                    .and_then(|registration| registration.data::<ReflectComponent>());
+                if let Some(component) = reflect_component.and_then(|reflect_component| {
+                    reflect_component.reflect_component(self.world, entity)
+                }) {
+                    dynamic_entity.components.push(component.clone_value());
+                }
+            }
+            self.entities.insert(entity.id(), dynamic_entity);
+
+            if self.include_descendants {
+                if let Some(children) = entity_ref.get::<Children>() {
+                    stack.extend(children.iter());
+                }
+            }
+        }
+
+        drop(type_registry);
+        self
+    }
+
+    /// Extract every registered resource that passes the resource filter.
+    ///
+    /// Only resources registered with `#[reflect(Resource)]` can be extracted.
+    pub fn extract_resources(&mut self) -> &mut Self {
+        let type_registry = self.type_registry.read();
+        for registration in type_registry.iter() {
+            if !self.resource_filter.is_allowed(registration.type_id()) {
+                continue;
+            }
+            if let Some(resource) = registration
+                .data::<ReflectResource>()
+                .and_then(|reflect_resource| reflect_resource.reflect_resource(self.world))
+            {
+                self.resources
+                    .insert(registration.type_name(), resource.clone_value());
+            }
+        }
+
+        drop(type_registry);
+        self
+    }
+
+    /// Consume the builder, producing a [`DynamicScene`] with the extracted entities, ordered by
+    /// id, and resources.
+    ///
+    /// [`Parent`] and [`Children`] references to entities that were not extracted are removed,
+    /// since they could not be mapped when spawning the scene.
+    pub fn build(mut self) -> DynamicScene {
+        self.remove_external_hierarchy();
+        DynamicScene {
+            resources: self.resources.into_values().collect(),
+            entities: self.entities.into_values().collect(),
+            instances: Vec::new(),
+        }
+    }
+
+    fn remove_external_hierarchy(&mut self) {
+        let extracted: HashSet<u32> = self.entities.keys().copied().collect();
+        for dynamic_entity in self.entities.values_mut() {
+            dynamic_entity
+                .components
+                .retain_mut(|component| retain_extracted_hierarchy(&mut **component, &extracted));
+        }
+    }
+}
+
+/// Removes the entities that are not `extracted` from a reflected [`Parent`] or [`Children`]
+/// component, returning `false` if the component no longer references any entity.
+///
+/// Other components are kept as is.
+fn retain_extracted_hierarchy(component: &mut dyn Reflect, extracted: &HashSet<u32>) -> bool {
+    let is_extracted = |entity: &dyn Reflect| matches!(entity.downcast_ref::<Entity>(), Some(entity) if extracted.contains(&entity.id()));
+    let type_name = component.type_name();
+    if type_name != std::any::type_name::<Parent>()
+        && type_name != std::any::type_name::<Children>()
+    {
+        return true;
+    }
+
+    // both components are tuple structs around their entities
+    let entities = match component.reflect_mut() {
+        ReflectMut::TupleStruct(hierarchy) => match hierarchy.field_mut(0) {
+            Some(entities) => entities,
+            None => return true,
+        },
+        _ => return true,
+    };
+    match entities.reflect_mut() {
+        ReflectMut::List(children) => {
+            for index in (0..children.len()).rev() {
+                if !matches!(children.get(index), Some(child) if is_extracted(child)) {
+                    children.remove(index);
+                }
+            }
+            children.len() > 0
+        }
+        _ => is_extracted(entities),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynamicSceneBuilder;
+    use bevy_ecs::{entity::EntityMap, prelude::*, reflect::ReflectResource};
+    use bevy_hierarchy::{BuildWorldChildren, Children, Parent};
+    use bevy_reflect::{Reflect, TypeRegistryArc};
+    use std::any::type_name;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentA;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentB;
+
+    #[derive(Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceA;
+
+    #[derive(Reflect, Default)]
+    #[reflect(Resource)]
+    struct ResourceB;
+
+    fn world() -> World {
+        let mut world = World::default();
+        let registry = TypeRegistryArc::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<ComponentA>();
+            registry.register::<ComponentB>();
+            registry.register::<ResourceA>();
+            registry.register::<ResourceB>();
+            registry.register::<Parent>();
+            registry.register::<Children>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn extract_selected_entities() {
+        let mut world = world();
+        let a = world.spawn().insert(ComponentA).id();
+        world.spawn().insert(ComponentA);
+        let c = world.spawn().insert_bundle((ComponentA, ComponentB)).id();
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_entities([c, a, c].into_iter());
+        let scene = builder.build();
+
+        assert_eq!(scene.entities.len(), 2);
+        assert_eq!(scene.entities[0].entity, a.id());
+        assert_eq!(scene.entities[1].entity, c.id());
+        assert_eq!(scene.entities[1].components.len(), 2);
+    }
+
+    #[test]
+    fn extract_query() {
+        let mut world = world();
+        let a = world.spawn().insert(ComponentA).id();
+        world.spawn().insert(ComponentB);
+
+        let mut query = world.query_filtered::<Entity, With<ComponentA>>();
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_entities(query.iter(&world));
+        let scene = builder.build();
+
+        assert_eq!(scene.entities.len(), 1);
+        assert_eq!(scene.entities[0].entity, a.id());
+    }
+
+    #[test]
+    fn component_filters() {
+        let mut world = world();
+        let entity = world.spawn().insert_bundle((ComponentA, ComponentB)).id();
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.deny::<ComponentA>().extract_entity(entity);
+        let scene = builder.build();
+        assert_eq!(scene.entities[0].components.len(), 1);
+        assert_eq!(
+            scene.entities[0].components[0].type_name(),
+            type_name::<ComponentB>()
+        );
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.allow::<ComponentA>().extract_entity(entity);
+        let scene = builder.build();
+        assert_eq!(scene.entities[0].components.len(), 1);
+        assert_eq!(
+            scene.entities[0].components[0].type_name(),
+            type_name::<ComponentA>()
+        );
+    }
+
+    #[test]
+    fn extract_descendants() {
+        let mut world = world();
+        let grandchild = world.spawn().insert(ComponentB).id();
+        let child = world
+            .spawn()
+            .insert(ComponentA)
+            .push_children(&[grandchild])
+            .id();
+        let parent = world.spawn().push_children(&[child]).id();
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_entity(parent);
+        let scene = builder.build();
+        let ids: Vec<u32> = scene.entities.iter().map(|entity| entity.entity).collect();
+        assert_eq!(ids.len(), 3);
+        assert!(ids.contains(&child.id()));
+        assert!(ids.contains(&grandchild.id()));
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.with_descendants(false).extract_entity(parent);
+        assert_eq!(builder.build().entities.len(), 1);
+    }
+
+    #[test]
+    fn extract_child_without_parent() {
+        let mut world = world();
+        let grandchild = world.spawn().insert(ComponentB).id();
+        let child = world
+            .spawn()
+            .insert(ComponentA)
+            .push_children(&[grandchild])
+            .id();
+        let sibling = world.spawn().id();
+        world.spawn().push_children(&[child, sibling]);
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_entity(child);
+        let scene = builder.build();
+        assert_eq!(scene.entities.len(), 2);
+
+        let mut dst_world = self::world();
+        let mut entity_map = EntityMap::default();
+        scene
+            .write_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+        let spawned_child = entity_map.get(child).unwrap();
+        let spawned_grandchild = entity_map.get(grandchild).unwrap();
+        assert!(dst_world.get::<Parent>(spawned_child).is_none());
+        assert_eq!(
+            dst_world.get::<Children>(spawned_child).unwrap().as_ref(),
+            &[spawned_grandchild]
+        );
+        assert_eq!(
+            dst_world.get::<Parent>(spawned_grandchild).unwrap().0,
+            spawned_child
+        );
+
+        // without descendants, the children of the extracted entity are left out
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.with_descendants(false).extract_entity(child);
+        let scene = builder.build();
+        assert_eq!(scene.entities[0].components.len(), 1);
+    }
+
+    #[test]
+    fn resource_filters() {
+        let mut world = world();
+        world.insert_resource(ResourceA);
+        world.insert_resource(ResourceB);
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_resources();
+        assert_eq!(builder.build().resources.len(), 2);
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.deny_resource::<ResourceA>().extract_resources();
+        let scene = builder.build();
+        assert_eq!(scene.resources.len(), 1);
+        assert_eq!(scene.resources[0].type_name(), type_name::<ResourceB>());
+    }
+}
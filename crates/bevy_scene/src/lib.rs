@@ -1,5 +1,6 @@
 mod bundle;
 mod dynamic_scene;
+mod dynamic_scene_builder;
 mod scene;
 mod scene_loader;
 mod scene_spawner;
@@ -7,17 +8,20 @@ pub mod serde;
 
 pub use bundle::*;
 pub use dynamic_scene::*;
+pub use dynamic_scene_builder::*;
 pub use scene::*;
 pub use scene_loader::*;
 pub use scene_spawner::*;
 
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{DynamicScene, DynamicSceneBundle, Scene, SceneBundle, SceneSpawner};
+    pub use crate::{
+        DynamicScene, DynamicSceneBuilder, DynamicSceneBundle, Scene, SceneBundle, SceneSpawner,
+    };
 }
 
-use bevy_app::prelude::*;
-use bevy_asset::AddAsset;
+use bevy_app::{prelude::*, PluginDependency};
+use bevy_asset::{AddAsset, AssetPlugin};
 use bevy_ecs::{schedule::ExclusiveSystemDescriptorCoercion, system::IntoExclusiveSystem};
 
 #[derive(Default)]
@@ -29,6 +33,7 @@ impl Plugin for ScenePlugin {
             .add_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
             .init_resource::<SceneSpawner>()
+            .add_event::<SceneInstanceReloaded>()
             .add_system_to_stage(
                 CoreStage::PreUpdate,
                 scene_spawner_system.exclusive_system().at_end(),
@@ -36,4 +41,8 @@ impl Plugin for ScenePlugin {
             // Systems `*_bundle_spawner` must run before `scene_spawner_system`
             .add_system_to_stage(CoreStage::PreUpdate, scene_spawner);
     }
+
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        vec![PluginDependency::required::<AssetPlugin>()]
+    }
 }
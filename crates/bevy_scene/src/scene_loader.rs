@@ -1,6 +1,6 @@
 use crate::serde::SceneDeserializer;
 use anyhow::Result;
-use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
+use bevy_asset::{AssetLoader, AssetPath, LoadContext, LoadedAsset};
 use bevy_ecs::world::{FromWorld, World};
 use bevy_reflect::TypeRegistryArc;
 use bevy_utils::BoxedFuture;
@@ -31,8 +31,14 @@ impl AssetLoader for SceneLoader {
             let scene_deserializer = SceneDeserializer {
                 type_registry: &*self.type_registry.read(),
             };
-            let scene = scene_deserializer.deserialize(&mut deserializer)?;
-            load_context.set_default_asset(LoadedAsset::new(scene));
+            let mut scene = scene_deserializer.deserialize(&mut deserializer)?;
+            let mut dependencies = Vec::new();
+            for instance in &mut scene.instances {
+                let path = AssetPath::from(instance.path.as_str()).to_owned();
+                instance.handle = load_context.get_handle(path.clone());
+                dependencies.push(path);
+            }
+            load_context.set_default_asset(LoadedAsset::new(scene).with_dependencies(dependencies));
             Ok(())
         })
     }
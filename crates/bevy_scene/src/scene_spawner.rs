@@ -1,5 +1,5 @@
 use crate::{DynamicScene, Scene};
-use bevy_asset::{AssetEvent, Assets, Handle};
+use bevy_asset::{AssetEvent, Assets, Handle, HandleId};
 use bevy_ecs::{
     entity::{Entity, EntityMap},
     event::{Events, ManualEventReader},
@@ -7,15 +7,53 @@ use bevy_ecs::{
     system::Command,
     world::{Mut, World},
 };
-use bevy_hierarchy::{AddChild, Parent};
-use bevy_reflect::TypeRegistryArc;
-use bevy_utils::{tracing::error, HashMap};
+use bevy_hierarchy::{
+    despawn_with_children_recursive, AddChild, BuildWorldChildren, Children, Parent,
+};
+use bevy_reflect::{TypeRegistry, TypeRegistryArc};
+use bevy_utils::{HashMap, HashSet};
+use std::any::TypeId;
 use thiserror::Error;
 use uuid::Uuid;
 
-#[derive(Debug)]
+/// The component types written to each entity of a [`DynamicScene`], keyed by scene entity.
+type SceneComponents = HashMap<Entity, HashSet<TypeId>>;
+
+#[derive(Debug, Default)]
 struct InstanceInfo {
     entity_map: EntityMap,
+    /// The components the scene wrote to each of its entities when it was last applied, used to
+    /// find what a modified scene removed.
+    scene_components: SceneComponents,
+    nested: NestedInstances,
+}
+
+/// The entities spawned for the scenes nested in an instance, at any depth.
+#[derive(Debug, Default)]
+struct NestedInstances {
+    /// One entity map per nested scene instance.
+    entity_maps: Vec<EntityMap>,
+    /// The nested scenes, so that the instance can be reloaded when one of them is modified.
+    scenes: HashSet<HandleId>,
+}
+
+impl InstanceInfo {
+    fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entity_map.values().chain(
+            self.nested
+                .entity_maps
+                .iter()
+                .flat_map(|entity_map| entity_map.values()),
+        )
+    }
+}
+
+/// Event sent once a spawned instance of a [`DynamicScene`] has been updated after its asset was
+/// modified.
+#[derive(Debug, Clone)]
+pub struct SceneInstanceReloaded {
+    pub handle: Handle<DynamicScene>,
+    pub instance_id: InstanceId,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -38,18 +76,23 @@ pub struct SceneSpawner {
     scenes_to_despawn: Vec<Handle<DynamicScene>>,
     instances_to_despawn: Vec<InstanceId>,
     scenes_with_parent: Vec<(InstanceId, Entity)>,
+    scenes_to_update: Vec<Handle<DynamicScene>>,
 }
 
 #[derive(Error, Debug)]
 pub enum SceneSpawnError {
     #[error("scene contains the unregistered component `{type_name}`. consider adding `#[reflect(Component)]` to your type")]
     UnregisteredComponent { type_name: String },
+    #[error("scene contains the unregistered resource `{type_name}`. consider adding `#[reflect(Resource)]` to your type")]
+    UnregisteredResource { type_name: String },
     #[error("scene contains the unregistered type `{type_name}`. consider registering the type using `app.register_type::<T>()`")]
     UnregisteredType { type_name: String },
     #[error("scene does not exist")]
     NonExistentScene { handle: Handle<DynamicScene> },
     #[error("scene does not exist")]
     NonExistentRealScene { handle: Handle<Scene> },
+    #[error("scene is nested in itself")]
+    RecursiveScene { handle: Handle<DynamicScene> },
 }
 
 impl SceneSpawner {
@@ -107,7 +150,7 @@ impl SceneSpawner {
 
     pub fn despawn_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
         if let Some(instance) = self.spawned_instances.remove(instance_id) {
-            for entity in instance.entity_map.values() {
+            for entity in instance.entities() {
                 let _ = world.despawn(entity);
             }
         }
@@ -118,11 +161,10 @@ impl SceneSpawner {
         world: &mut World,
         scene_handle: &Handle<DynamicScene>,
     ) -> Result<(), SceneSpawnError> {
-        let mut entity_map = EntityMap::default();
-        Self::spawn_dynamic_internal(world, scene_handle, &mut entity_map)?;
+        let mut instance_info = InstanceInfo::default();
+        Self::spawn_dynamic_internal(world, scene_handle, &mut instance_info)?;
         let instance_id = InstanceId::new();
-        self.spawned_instances
-            .insert(instance_id, InstanceInfo { entity_map });
+        self.spawned_instances.insert(instance_id, instance_info);
         let spawned = self
             .spawned_dynamic_scenes
             .entry(scene_handle.clone())
@@ -134,19 +176,240 @@ impl SceneSpawner {
     fn spawn_dynamic_internal(
         world: &mut World,
         scene_handle: &Handle<DynamicScene>,
-        entity_map: &mut EntityMap,
+        instance_info: &mut InstanceInfo,
     ) -> Result<(), SceneSpawnError> {
         world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
-            let scene =
-                scenes
-                    .get(scene_handle)
-                    .ok_or_else(|| SceneSpawnError::NonExistentScene {
-                        handle: scene_handle.clone_weak(),
-                    })?;
-            scene.write_to_world(world, entity_map)
+            // Nothing is written unless all the nested scenes can be spawned too
+            Self::check_nested_scenes(&scenes, scene_handle, &mut Vec::new())?;
+            let scene = Self::get_scene(&scenes, scene_handle)?;
+            scene.write_to_world(world, &mut instance_info.entity_map)?;
+            let type_registry = world.resource::<TypeRegistryArc>().clone();
+            instance_info.scene_components = Self::scene_components(scene, &type_registry.read());
+            Self::spawn_nested_instances(
+                world,
+                &scenes,
+                scene,
+                &mut instance_info.entity_map,
+                &mut instance_info.nested,
+            )
         })
     }
 
+    fn get_scene<'a>(
+        scenes: &'a Assets<DynamicScene>,
+        scene_handle: &Handle<DynamicScene>,
+    ) -> Result<&'a DynamicScene, SceneSpawnError> {
+        scenes
+            .get(scene_handle)
+            .ok_or_else(|| SceneSpawnError::NonExistentScene {
+                handle: scene_handle.clone_weak(),
+            })
+    }
+
+    /// Checks that a scene and the scenes nested in it are loaded, and that none of them is
+    /// nested in itself.
+    fn check_nested_scenes(
+        scenes: &Assets<DynamicScene>,
+        scene_handle: &Handle<DynamicScene>,
+        ancestors: &mut Vec<HandleId>,
+    ) -> Result<(), SceneSpawnError> {
+        if ancestors.contains(&scene_handle.id) {
+            return Err(SceneSpawnError::RecursiveScene {
+                handle: scene_handle.clone_weak(),
+            });
+        }
+        let scene = Self::get_scene(scenes, scene_handle)?;
+        ancestors.push(scene_handle.id);
+        for instance in &scene.instances {
+            Self::check_nested_scenes(scenes, &instance.handle, ancestors)?;
+        }
+        ancestors.pop();
+        Ok(())
+    }
+
+    /// Spawns the scenes nested in `scene`, whose own entities were written using `entity_map`.
+    ///
+    /// Each nested scene gets its own entity map, which its overrides are mapped through, and
+    /// its root entities are added as children of the entity it is nested under.
+    fn spawn_nested_instances(
+        world: &mut World,
+        scenes: &Assets<DynamicScene>,
+        scene: &DynamicScene,
+        entity_map: &mut EntityMap,
+        nested: &mut NestedInstances,
+    ) -> Result<(), SceneSpawnError> {
+        for instance in &scene.instances {
+            let nested_scene = Self::get_scene(scenes, &instance.handle)?;
+            let mut nested_entity_map = EntityMap::default();
+            nested_scene.write_to_world(world, &mut nested_entity_map)?;
+            Self::spawn_nested_instances(
+                world,
+                scenes,
+                nested_scene,
+                &mut nested_entity_map,
+                nested,
+            )?;
+            DynamicScene::write_entities(world, &instance.overrides, &mut nested_entity_map)?;
+
+            let parent = *entity_map
+                .entry(Entity::from_raw(instance.entity))
+                .or_insert_with(|| world.spawn().id());
+            let roots: Vec<Entity> = nested_entity_map
+                .values()
+                .filter(|entity| !world.entity(*entity).contains::<Parent>())
+                .collect();
+            world.entity_mut(parent).push_children(&roots);
+
+            nested.entity_maps.push(nested_entity_map);
+            nested.scenes.insert(instance.handle.id);
+        }
+        Ok(())
+    }
+
+    fn scene_components(scene: &DynamicScene, type_registry: &TypeRegistry) -> SceneComponents {
+        scene
+            .entities
+            .iter()
+            .map(|scene_entity| {
+                let types = scene_entity
+                    .components
+                    .iter()
+                    .filter_map(|component| type_registry.get_with_name(component.type_name()))
+                    .map(|registration| registration.type_id())
+                    .collect();
+                (Entity::from_raw(scene_entity.entity), types)
+            })
+            .collect()
+    }
+
+    /// Re-applies a modified scene to one of its instances.
+    ///
+    /// Entities and components that are no longer part of the scene are removed, while
+    /// components and children added at runtime are kept. Nested scenes are spawned again.
+    fn reload_instance(
+        world: &mut World,
+        scenes: &Assets<DynamicScene>,
+        scene: &DynamicScene,
+        instance_info: &mut InstanceInfo,
+    ) -> Result<(), SceneSpawnError> {
+        // Nested instances are spawned again from scratch. Like despawned instances, only their
+        // own entities are removed, so children added to them at runtime are kept as roots.
+        let mut nested_entities = HashSet::default();
+        for entity_map in instance_info.nested.entity_maps.drain(..) {
+            nested_entities.extend(entity_map.values());
+        }
+        instance_info.nested.scenes.clear();
+        for &entity in &nested_entities {
+            if world.get_entity(entity).is_none() {
+                continue;
+            }
+            if let Some(children) = world.get::<Children>(entity) {
+                let runtime_children: Vec<Entity> = children
+                    .iter()
+                    .filter(|child| !nested_entities.contains(child))
+                    .copied()
+                    .collect();
+                world.entity_mut(entity).remove_children(&runtime_children);
+            }
+            if let Some(parent) = world.get::<Parent>(entity).map(|parent| parent.0) {
+                if !nested_entities.contains(&parent) && world.get_entity(parent).is_some() {
+                    world.entity_mut(parent).remove_children(&[entity]);
+                }
+            }
+            let _ = world.despawn(entity);
+        }
+
+        let type_registry = world.resource::<TypeRegistryArc>().clone();
+        let type_registry = type_registry.read();
+        let scene_components = Self::scene_components(scene, &type_registry);
+
+        let mut removed_entities = Vec::new();
+        for (scene_entity, old_types) in &instance_info.scene_components {
+            let entity = match instance_info.entity_map.get(*scene_entity) {
+                Ok(entity) if world.get_entity(entity).is_some() => entity,
+                _ => continue,
+            };
+            match scene_components.get(scene_entity) {
+                Some(new_types) => {
+                    for type_id in old_types.difference(new_types) {
+                        if let Some(reflect_component) = type_registry
+                            .get(*type_id)
+                            .and_then(|registration| registration.data::<ReflectComponent>())
+                        {
+                            reflect_component.remove_component(world, entity);
+                        }
+                    }
+                }
+                None => removed_entities.push((*scene_entity, entity)),
+            }
+        }
+        drop(type_registry);
+
+        let retained_entities: HashSet<Entity> = scene_components
+            .keys()
+            .filter_map(|scene_entity| instance_info.entity_map.get(*scene_entity).ok())
+            .collect();
+        for (scene_entity, entity) in removed_entities {
+            instance_info.entity_map.remove(scene_entity);
+            if world.get_entity(entity).is_none() {
+                continue;
+            }
+            // Children that are still part of the scene must survive their old parent
+            if let Some(children) = world.get::<Children>(entity) {
+                let retained_children: Vec<Entity> = children
+                    .iter()
+                    .filter(|child| retained_entities.contains(child))
+                    .copied()
+                    .collect();
+                world.entity_mut(entity).remove_children(&retained_children);
+            }
+            despawn_with_children_recursive(world, entity);
+        }
+
+        // `Children` written by the scene replace the existing ones, so set aside the children
+        // that were added at runtime and push them back once the scene has been written.
+        let mut runtime_children = Vec::new();
+        for (scene_entity, types) in &scene_components {
+            if !types.contains(&TypeId::of::<Children>()) {
+                continue;
+            }
+            let entity = match instance_info.entity_map.get(*scene_entity) {
+                Ok(entity) => entity,
+                Err(_) => continue,
+            };
+            if let Some(children) = world.get::<Children>(entity) {
+                let children: Vec<Entity> = children
+                    .iter()
+                    .filter(|child| !retained_entities.contains(child))
+                    .copied()
+                    .collect();
+                world.entity_mut(entity).remove::<Children>();
+                runtime_children.push((entity, children));
+            }
+        }
+
+        scene.write_to_world(world, &mut instance_info.entity_map)?;
+
+        for (parent, children) in runtime_children {
+            let children: Vec<Entity> = children
+                .into_iter()
+                .filter(|child| world.get_entity(*child).is_some())
+                .collect();
+            if !children.is_empty() {
+                world.entity_mut(parent).push_children(&children);
+            }
+        }
+        instance_info.scene_components = scene_components;
+
+        Self::spawn_nested_instances(
+            world,
+            scenes,
+            scene,
+            &mut instance_info.entity_map,
+            &mut instance_info.nested,
+        )
+    }
+
     pub fn spawn_sync(
         &mut self,
         world: &mut World,
@@ -161,9 +424,7 @@ impl SceneSpawner {
         scene_handle: Handle<Scene>,
         instance_id: InstanceId,
     ) -> Result<InstanceId, SceneSpawnError> {
-        let mut instance_info = InstanceInfo {
-            entity_map: EntityMap::default(),
-        };
+        let mut instance_info = InstanceInfo::default();
         let type_registry = world.resource::<TypeRegistryArc>().clone();
         let type_registry = type_registry.read();
         world.resource_scope(|world, scenes: Mut<Assets<Scene>>| {
@@ -231,21 +492,64 @@ impl SceneSpawner {
         scene_handles: &[Handle<DynamicScene>],
     ) -> Result<(), SceneSpawnError> {
         for scene_handle in scene_handles {
-            if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(scene_handle) {
-                for instance_id in spawned_instances.iter() {
-                    if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
-                        Self::spawn_dynamic_internal(
-                            world,
-                            scene_handle,
-                            &mut instance_info.entity_map,
-                        )?;
+            // Instances of the modified scene, and of the scenes it is nested in
+            let spawned_instances = &self.spawned_instances;
+            let instances: Vec<(Handle<DynamicScene>, InstanceId)> = self
+                .spawned_dynamic_scenes
+                .iter()
+                .flat_map(|(handle, instance_ids)| {
+                    instance_ids.iter().filter_map(move |instance_id| {
+                        let instance_info = spawned_instances.get(instance_id)?;
+                        (handle == scene_handle
+                            || instance_info.nested.scenes.contains(&scene_handle.id))
+                        .then(|| (handle.clone_weak(), *instance_id))
+                    })
+                })
+                .collect();
+
+            for (handle, instance_id) in instances {
+                let instance_info = match self.spawned_instances.get_mut(&instance_id) {
+                    Some(instance_info) => instance_info,
+                    None => continue,
+                };
+                let result = world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+                    Self::check_nested_scenes(&scenes, &handle, &mut Vec::new())?;
+                    let scene = Self::get_scene(&scenes, &handle)?;
+                    Self::reload_instance(world, &scenes, scene, instance_info)
+                });
+                match result {
+                    Ok(()) => {
+                        if let Some(mut events) =
+                            world.get_resource_mut::<Events<SceneInstanceReloaded>>()
+                        {
+                            events.send(SceneInstanceReloaded {
+                                handle,
+                                instance_id,
+                            });
+                        }
+                    }
+                    // A newly nested scene is not loaded yet, try again later
+                    Err(SceneSpawnError::NonExistentScene { .. }) => {
+                        if !self.scenes_to_update.contains(&handle) {
+                            self.scenes_to_update.push(handle);
+                        }
                     }
+                    Err(err) => return Err(err),
                 }
             }
         }
         Ok(())
     }
 
+    /// Whether instances of the scene, or of scenes it is nested in, have been spawned.
+    fn is_scene_spawned(&self, scene_handle: &Handle<DynamicScene>) -> bool {
+        self.spawned_dynamic_scenes.contains_key(scene_handle)
+            || self
+                .spawned_instances
+                .values()
+                .any(|instance| instance.nested.scenes.contains(&scene_handle.id))
+    }
+
     pub fn despawn_queued_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
         let scenes_to_despawn = std::mem::take(&mut self.scenes_to_despawn);
 
@@ -267,12 +571,11 @@ impl SceneSpawner {
         let scenes_to_spawn = std::mem::take(&mut self.dynamic_scenes_to_spawn);
 
         for (scene_handle, instance_id) in scenes_to_spawn {
-            let mut entity_map = EntityMap::default();
+            let mut instance_info = InstanceInfo::default();
 
-            match Self::spawn_dynamic_internal(world, &scene_handle, &mut entity_map) {
-                Ok(_) => {
-                    self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+            match Self::spawn_dynamic_internal(world, &scene_handle, &mut instance_info) {
+                Ok(()) => {
+                    self.spawned_instances.insert(instance_id, instance_info);
                     let spawned = self
                         .spawned_dynamic_scenes
                         .entry(scene_handle.clone())
@@ -344,7 +647,7 @@ impl SceneSpawner {
     ) -> Option<impl Iterator<Item = Entity> + '_> {
         self.spawned_instances
             .get(&instance_id)
-            .map(|instance| instance.entity_map.values())
+            .map(|instance| instance.entities())
     }
 }
 
@@ -352,18 +655,21 @@ pub fn scene_spawner_system(world: &mut World) {
     world.resource_scope(|world, mut scene_spawner: Mut<SceneSpawner>| {
         let scene_asset_events = world.resource::<Events<AssetEvent<DynamicScene>>>();
 
-        let mut updated_spawned_scenes = Vec::new();
         let scene_spawner = &mut *scene_spawner;
-        for event in scene_spawner
+        let modified_scenes: Vec<Handle<DynamicScene>> = scene_spawner
             .scene_asset_event_reader
             .iter(scene_asset_events)
-        {
-            if let AssetEvent::Modified { handle } = event {
-                if scene_spawner.spawned_dynamic_scenes.contains_key(handle) {
-                    updated_spawned_scenes.push(handle.clone_weak());
-                }
-            }
-        }
+            .filter_map(|event| match event {
+                AssetEvent::Modified { handle } => Some(handle.clone_weak()),
+                _ => None,
+            })
+            .collect();
+        let mut updated_spawned_scenes = std::mem::take(&mut scene_spawner.scenes_to_update);
+        updated_spawned_scenes.extend(
+            modified_scenes
+                .into_iter()
+                .filter(|handle| scene_spawner.is_scene_spawned(handle)),
+        );
 
         scene_spawner.despawn_queued_scenes(world).unwrap();
         scene_spawner.despawn_queued_instances(world);
@@ -376,3 +682,323 @@ pub fn scene_spawner_system(world: &mut World) {
         scene_spawner.set_scene_instance_parent_sync(world);
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{InstanceInfo, SceneSpawnError, SceneSpawner};
+    use crate::{serde::SceneDeserializer, DynamicEntity, DynamicScene, DynamicSceneInstance};
+    use bevy_app::App;
+    use bevy_asset::{AddAsset, AssetPlugin, AssetServer, Assets, FileAssetIo, Handle, HandleId};
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::{BuildWorldChildren, Children, Parent};
+    use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypeRegistryArc};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentA;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct ComponentB;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Value(u32);
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Stats {
+        speed: u32,
+        armor: u32,
+    }
+
+    #[derive(Component)]
+    struct RuntimeComponent;
+
+    fn registry() -> TypeRegistryArc {
+        let registry = TypeRegistryArc::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<ComponentA>();
+            registry.register::<ComponentB>();
+            registry.register::<Value>();
+            registry.register::<Stats>();
+            registry.register::<Parent>();
+            registry.register::<Children>();
+        }
+        registry
+    }
+
+    fn world() -> World {
+        let mut app = App::new();
+        app.insert_resource(AssetServer::new(FileAssetIo::new("", false)))
+            .insert_resource(registry())
+            .add_plugin(AssetPlugin)
+            .add_asset::<DynamicScene>();
+        std::mem::take(&mut app.world)
+    }
+
+    fn set_scene(world: &mut World, path: &str, scene: DynamicScene) -> Handle<DynamicScene> {
+        let handle = Handle::weak(HandleId::from(path));
+        world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(handle.clone_weak(), scene);
+        handle
+    }
+
+    fn set_scene_from_world(world: &mut World, path: &str, source: &World) -> Handle<DynamicScene> {
+        let scene = DynamicScene::from_world(source, &registry());
+        set_scene(world, path, scene)
+    }
+
+    /// Sets the scene at `path` to `source` after a round trip through the scene format, which
+    /// omits fields with default values.
+    fn set_scene_from_ron(world: &mut World, path: &str, source: &World) -> Handle<DynamicScene> {
+        let registry = registry();
+        let ron = DynamicScene::from_world(source, &registry)
+            .serialize_ron(&registry)
+            .unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+        set_scene(world, path, scene)
+    }
+
+    fn spawn(world: &mut World, handle: &Handle<DynamicScene>) -> InstanceInfo {
+        let mut instance_info = InstanceInfo::default();
+        SceneSpawner::spawn_dynamic_internal(world, handle, &mut instance_info).unwrap();
+        instance_info
+    }
+
+    fn reload(world: &mut World, handle: &Handle<DynamicScene>, instance_info: &mut InstanceInfo) {
+        world
+            .resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+                let scene = scenes.get(handle).unwrap();
+                SceneSpawner::reload_instance(world, &scenes, scene, instance_info)
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn reload_removes_deleted_entities_and_components() {
+        let mut source = World::new();
+        let a = source.spawn().insert_bundle((ComponentA, ComponentB)).id();
+        let b = source.spawn().insert(ComponentA).id();
+
+        let mut world = world();
+        let handle = set_scene_from_world(&mut world, "scene.scn.ron", &source);
+        let mut instance_info = spawn(&mut world, &handle);
+        let spawned_a = instance_info.entity_map.get(a).unwrap();
+        let spawned_b = instance_info.entity_map.get(b).unwrap();
+        world.entity_mut(spawned_a).insert(RuntimeComponent);
+
+        source.entity_mut(a).remove::<ComponentB>();
+        source.despawn(b);
+        set_scene_from_world(&mut world, "scene.scn.ron", &source);
+        reload(&mut world, &handle, &mut instance_info);
+
+        assert!(world.get_entity(spawned_b).is_none());
+        assert!(instance_info.entity_map.get(b).is_err());
+        let spawned_a = world.entity(spawned_a);
+        assert!(spawned_a.contains::<ComponentA>());
+        assert!(!spawned_a.contains::<ComponentB>());
+        assert!(spawned_a.contains::<RuntimeComponent>());
+    }
+
+    #[test]
+    fn reload_resets_fields_to_default() {
+        let mut source = World::new();
+        let entity = source.spawn().insert(Stats { speed: 5, armor: 1 }).id();
+
+        let mut world = world();
+        let handle = set_scene_from_ron(&mut world, "scene.scn.ron", &source);
+        let mut instance_info = spawn(&mut world, &handle);
+        let spawned = instance_info.entity_map.get(entity).unwrap();
+        assert_eq!(
+            world.get::<Stats>(spawned),
+            Some(&Stats { speed: 5, armor: 1 })
+        );
+
+        source.get_mut::<Stats>(entity).unwrap().speed = 0;
+        set_scene_from_ron(&mut world, "scene.scn.ron", &source);
+        reload(&mut world, &handle, &mut instance_info);
+
+        assert_eq!(
+            world.get::<Stats>(spawned),
+            Some(&Stats { speed: 0, armor: 1 })
+        );
+    }
+
+    #[test]
+    fn reload_keeps_runtime_children() {
+        let mut source = World::new();
+        let root = source.spawn().insert(ComponentA).id();
+        let child = source.spawn().insert(ComponentB).id();
+        source.entity_mut(root).push_children(&[child]);
+
+        let mut world = world();
+        let handle = set_scene_from_world(&mut world, "scene.scn.ron", &source);
+        let mut instance_info = spawn(&mut world, &handle);
+        let spawned_root = instance_info.entity_map.get(root).unwrap();
+        let spawned_child = instance_info.entity_map.get(child).unwrap();
+        let runtime_child = world.spawn().id();
+        world
+            .entity_mut(spawned_root)
+            .push_children(&[runtime_child]);
+
+        let new_child = source.spawn().insert(ComponentA).id();
+        source.entity_mut(root).push_children(&[new_child]);
+        set_scene_from_world(&mut world, "scene.scn.ron", &source);
+        reload(&mut world, &handle, &mut instance_info);
+
+        let spawned_new_child = instance_info.entity_map.get(new_child).unwrap();
+        let children = world.get::<Children>(spawned_root).unwrap();
+        assert_eq!(
+            &**children,
+            &[spawned_child, spawned_new_child, runtime_child]
+        );
+        assert_eq!(world.get::<Parent>(runtime_child).unwrap().0, spawned_root);
+        assert_eq!(
+            world.get::<Parent>(spawned_new_child).unwrap().0,
+            spawned_root
+        );
+    }
+
+    #[test]
+    fn spawn_nested_scenes_with_overrides() {
+        let mut door = World::new();
+        let door_root = door.spawn().insert(Value(1)).id();
+        let door_handle = door.spawn().insert(ComponentB).id();
+        door.entity_mut(door_root).push_children(&[door_handle]);
+
+        let mut world = world();
+        set_scene_from_world(&mut world, "door.scn.ron", &door);
+        let mut level = DynamicScene::default();
+        level.entities.push(DynamicEntity {
+            entity: 0,
+            components: vec![Box::new(ComponentA)],
+        });
+        level
+            .instances
+            .push(DynamicSceneInstance::new(0, "door.scn.ron"));
+        level
+            .instances
+            .push(
+                DynamicSceneInstance::new(0, "door.scn.ron").with_override(DynamicEntity {
+                    entity: door_root.id(),
+                    components: vec![Box::new(Value(2))],
+                }),
+            );
+        let level_handle = set_scene(&mut world, "level.scn.ron", level);
+
+        let instance_info = spawn(&mut world, &level_handle);
+        assert_eq!(instance_info.nested.entity_maps.len(), 2);
+        assert!(instance_info
+            .nested
+            .scenes
+            .contains(&HandleId::from("door.scn.ron")));
+        assert_eq!(instance_info.entities().count(), 5);
+
+        let level_root = instance_info.entity_map.get(Entity::from_raw(0)).unwrap();
+        let doors = &instance_info.nested.entity_maps;
+        let first_root = doors[0].get(door_root).unwrap();
+        let second_root = doors[1].get(door_root).unwrap();
+        assert_eq!(
+            &**world.get::<Children>(level_root).unwrap(),
+            &[first_root, second_root]
+        );
+        assert_eq!(world.get::<Value>(first_root), Some(&Value(1)));
+        assert_eq!(world.get::<Value>(second_root), Some(&Value(2)));
+
+        // Entity references are mapped within each nested instance
+        for door in doors {
+            let root = door.get(door_root).unwrap();
+            let handle = door.get(door_handle).unwrap();
+            assert_eq!(world.get::<Parent>(root).unwrap().0, level_root);
+            assert_eq!(world.get::<Parent>(handle).unwrap().0, root);
+            assert_eq!(&**world.get::<Children>(root).unwrap(), &[handle]);
+        }
+    }
+
+    #[test]
+    fn reload_keeps_runtime_children_of_nested_instances() {
+        let mut door = World::new();
+        let door_root = door.spawn().insert(Value(1)).id();
+
+        let mut world = world();
+        set_scene_from_world(&mut world, "door.scn.ron", &door);
+        let mut level = DynamicScene::default();
+        level.entities.push(DynamicEntity {
+            entity: 0,
+            components: vec![Box::new(ComponentA)],
+        });
+        level
+            .instances
+            .push(DynamicSceneInstance::new(0, "door.scn.ron"));
+        let level_handle = set_scene(&mut world, "level.scn.ron", level);
+
+        let mut instance_info = spawn(&mut world, &level_handle);
+        let level_root = instance_info.entity_map.get(Entity::from_raw(0)).unwrap();
+        let old_door = instance_info.nested.entity_maps[0].get(door_root).unwrap();
+        let runtime_child = world.spawn().id();
+        world.entity_mut(old_door).push_children(&[runtime_child]);
+
+        reload(&mut world, &level_handle, &mut instance_info);
+
+        let new_door = instance_info.nested.entity_maps[0].get(door_root).unwrap();
+        assert!(world.get_entity(old_door).is_none());
+        assert!(world.get_entity(runtime_child).is_some());
+        assert!(world.get::<Parent>(runtime_child).is_none());
+        assert_eq!(&**world.get::<Children>(level_root).unwrap(), &[new_door]);
+    }
+
+    #[test]
+    fn reject_recursive_nested_scenes() {
+        let mut world = world();
+        let mut scene_a = DynamicScene::default();
+        scene_a
+            .instances
+            .push(DynamicSceneInstance::new(0, "b.scn.ron"));
+        let mut scene_b = DynamicScene::default();
+        scene_b
+            .instances
+            .push(DynamicSceneInstance::new(0, "a.scn.ron"));
+        let handle = set_scene(&mut world, "a.scn.ron", scene_a);
+        set_scene(&mut world, "b.scn.ron", scene_b);
+
+        let entities = world.entities().len();
+        let result =
+            SceneSpawner::spawn_dynamic_internal(&mut world, &handle, &mut InstanceInfo::default());
+        assert!(matches!(
+            result,
+            Err(SceneSpawnError::RecursiveScene { .. })
+        ));
+        assert_eq!(world.entities().len(), entities);
+    }
+
+    #[test]
+    fn wait_for_nested_scenes_to_load() {
+        let mut world = world();
+        let mut scene = DynamicScene::default();
+        scene.entities.push(DynamicEntity {
+            entity: 0,
+            components: vec![Box::new(ComponentA)],
+        });
+        scene
+            .instances
+            .push(DynamicSceneInstance::new(0, "missing.scn.ron"));
+        let handle = set_scene(&mut world, "scene.scn.ron", scene);
+
+        let result =
+            SceneSpawner::spawn_dynamic_internal(&mut world, &handle, &mut InstanceInfo::default());
+        assert!(matches!(
+            result,
+            Err(SceneSpawnError::NonExistentScene { .. })
+        ));
+        assert_eq!(world.query::<&ComponentA>().iter(&world).count(), 0);
+    }
+}
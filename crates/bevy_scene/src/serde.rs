@@ -1,15 +1,24 @@
-use crate::{DynamicEntity, DynamicScene};
+use crate::{DynamicEntity, DynamicScene, DynamicSceneInstance};
 use anyhow::Result;
 use bevy_reflect::{
-    serde::{ReflectDeserializer, ReflectSerializer},
-    Reflect, TypeRegistry, TypeRegistryArc,
+    serde::{ReflectDeserializer, TypedReflectDeserializer, TypedReflectSerializer},
+    std_traits::ReflectDefault,
+    DynamicStruct, Reflect, ReflectRef, TypeRegistration, TypeRegistry, TypeRegistryArc,
 };
 use serde::{
     de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
-    ser::{SerializeSeq, SerializeStruct},
+    ser::{SerializeMap, SerializeSeq, SerializeStruct},
     Deserialize, Serialize,
 };
 
+/// The version of the scene format written by [`SceneSerializer`].
+pub const SCENE_FORMAT_VERSION: u32 = 2;
+
+/// Serializes a [`DynamicScene`] into the current scene format.
+///
+/// Resources and components are written as maps keyed by their type name, which is shortened
+/// whenever the short name is unambiguous in the registry. Struct fields that are equal to the
+/// value of the type's [`ReflectDefault`] are omitted.
 pub struct SceneSerializer<'a> {
     pub scene: &'a DynamicScene,
     pub registry: &'a TypeRegistryArc,
@@ -26,10 +35,52 @@ impl<'a> Serialize for SceneSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_seq(Some(self.scene.entities.len()))?;
-        for entity in &self.scene.entities {
-            state.serialize_element(&EntitySerializer {
-                entity,
+        let registry = self.registry.read();
+        let mut state = serializer.serialize_struct(SCENE_STRUCT, 4)?;
+        state.serialize_field(SCENE_FIELD_VERSION, &SCENE_FORMAT_VERSION)?;
+        state.serialize_field(
+            SCENE_FIELD_RESOURCES,
+            &ReflectMapSerializer {
+                values: &self.scene.resources,
+                registry: &registry,
+            },
+        )?;
+        state.serialize_field(
+            SCENE_FIELD_ENTITIES,
+            &EntitiesSerializer {
+                entities: &self.scene.entities,
+                registry: &registry,
+            },
+        )?;
+        if self.scene.instances.is_empty() {
+            state.skip_field(SCENE_FIELD_INSTANCES)?;
+        } else {
+            state.serialize_field(
+                SCENE_FIELD_INSTANCES,
+                &InstancesSerializer {
+                    instances: &self.scene.instances,
+                    registry: &registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+pub struct InstancesSerializer<'a> {
+    pub instances: &'a [DynamicSceneInstance],
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for InstancesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.instances.len()))?;
+        for instance in self.instances {
+            state.serialize_element(&InstanceSerializer {
+                instance,
                 registry: self.registry,
             })?;
         }
@@ -37,9 +88,57 @@ impl<'a> Serialize for SceneSerializer<'a> {
     }
 }
 
+pub struct InstanceSerializer<'a> {
+    pub instance: &'a DynamicSceneInstance,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for InstanceSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct(INSTANCE_STRUCT, 3)?;
+        state.serialize_field(INSTANCE_FIELD_ENTITY, &self.instance.entity)?;
+        state.serialize_field(INSTANCE_FIELD_SCENE, &self.instance.path)?;
+        state.serialize_field(
+            INSTANCE_FIELD_OVERRIDES,
+            &EntitiesSerializer {
+                entities: &self.instance.overrides,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+pub struct EntitiesSerializer<'a> {
+    pub entities: &'a [DynamicEntity],
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for EntitiesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.entities.len()))?;
+        for entity in self.entities {
+            state.serialize_entry(
+                &entity.entity,
+                &EntitySerializer {
+                    entity,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
 pub struct EntitySerializer<'a> {
     pub entity: &'a DynamicEntity,
-    pub registry: &'a TypeRegistryArc,
+    pub registry: &'a TypeRegistry,
 }
 
 impl<'a> Serialize for EntitySerializer<'a> {
@@ -47,12 +146,11 @@ impl<'a> Serialize for EntitySerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct(ENTITY_STRUCT, 2)?;
-        state.serialize_field(ENTITY_FIELD_ENTITY, &self.entity.entity)?;
+        let mut state = serializer.serialize_struct(ENTITY_STRUCT, 1)?;
         state.serialize_field(
             ENTITY_FIELD_COMPONENTS,
-            &ComponentsSerializer {
-                components: &self.entity.components,
+            &ReflectMapSerializer {
+                values: &self.entity.components,
                 registry: self.registry,
             },
         )?;
@@ -60,27 +158,92 @@ impl<'a> Serialize for EntitySerializer<'a> {
     }
 }
 
-pub struct ComponentsSerializer<'a> {
-    pub components: &'a [Box<dyn Reflect>],
-    pub registry: &'a TypeRegistryArc,
+/// Serializes reflected values as a map from their type name to their untagged value.
+pub struct ReflectMapSerializer<'a> {
+    pub values: &'a [Box<dyn Reflect>],
+    pub registry: &'a TypeRegistry,
 }
 
-impl<'a> Serialize for ComponentsSerializer<'a> {
+impl<'a> Serialize for ReflectMapSerializer<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_seq(Some(self.components.len()))?;
-        for component in self.components.iter() {
-            state.serialize_element(&ReflectSerializer::new(
-                &**component,
-                &*self.registry.read(),
-            ))?;
+        let mut state = serializer.serialize_map(Some(self.values.len()))?;
+        for value in self.values {
+            let registration = self
+                .registry
+                .get_with_name(value.type_name())
+                .ok_or_else(|| {
+                    serde::ser::Error::custom(format_args!(
+                        "No registration found for {}",
+                        value.type_name()
+                    ))
+                })?;
+            let key = match self.registry.get_with_short_name(registration.short_name()) {
+                Some(short) if short.type_id() == registration.type_id() => {
+                    registration.short_name()
+                }
+                _ => registration.type_name(),
+            };
+            match strip_default_fields(&**value, registration) {
+                Some(stripped) => state
+                    .serialize_entry(key, &TypedReflectSerializer::new(&stripped, self.registry))?,
+                None => state
+                    .serialize_entry(key, &TypedReflectSerializer::new(&**value, self.registry))?,
+            }
         }
         state.end()
     }
 }
 
+/// Returns a copy of a struct `value` without the fields that are equal to the corresponding
+/// field of the type's default value, or `None` if the type is not a struct or has no
+/// [`ReflectDefault`].
+fn strip_default_fields(
+    value: &dyn Reflect,
+    registration: &TypeRegistration,
+) -> Option<DynamicStruct> {
+    let default = registration.data::<ReflectDefault>()?.default();
+    let (value, default) = match (value.reflect_ref(), default.reflect_ref()) {
+        (ReflectRef::Struct(value), ReflectRef::Struct(default)) => (value, default),
+        _ => return None,
+    };
+
+    let mut stripped = DynamicStruct::default();
+    stripped.set_name(value.type_name().to_string());
+    for (index, field) in value.iter_fields().enumerate() {
+        let name = value.name_at(index).unwrap();
+        let is_default = default
+            .field(name)
+            .and_then(|default_field| field.reflect_partial_eq(default_field))
+            .unwrap_or(false);
+        if !is_default {
+            stripped.insert_boxed(name, field.clone_value());
+        }
+    }
+    Some(stripped)
+}
+
+/// Completes a struct `value` whose default fields were omitted by [`strip_default_fields`].
+///
+/// The missing fields are taken from the type's [`ReflectDefault`], so that applying the value
+/// to an existing component or resource also resets the fields that changed back to their
+/// default.
+fn fill_default_fields(
+    value: Box<dyn Reflect>,
+    registration: &TypeRegistration,
+) -> Box<dyn Reflect> {
+    match registration.data::<ReflectDefault>() {
+        Some(reflect_default) if matches!(value.reflect_ref(), ReflectRef::Struct(_)) => {
+            let mut full = reflect_default.default();
+            full.apply(&*value);
+            full
+        }
+        _ => value,
+    }
+}
+
 pub struct SceneDeserializer<'a> {
     pub type_registry: &'a TypeRegistry,
 }
@@ -91,15 +254,379 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(SceneVisitor {
+            type_registry: self.type_registry,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum SceneField {
+    Version,
+    Resources,
+    Entities,
+    Instances,
+}
+
+pub const SCENE_STRUCT: &str = "Scene";
+pub const SCENE_FIELD_VERSION: &str = "version";
+pub const SCENE_FIELD_RESOURCES: &str = "resources";
+pub const SCENE_FIELD_ENTITIES: &str = "entities";
+pub const SCENE_FIELD_INSTANCES: &str = "instances";
+
+struct SceneVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
+    type Value = DynamicScene;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("scene")
+    }
+
+    // Scenes written before the format was versioned are a plain list of entities.
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
     {
         Ok(DynamicScene {
-            entities: deserializer.deserialize_seq(SceneEntitySeqVisitor {
+            resources: Vec::new(),
+            entities: SceneEntitySeqVisitor {
                 type_registry: self.type_registry,
-            })?,
+            }
+            .visit_seq(seq)?,
+            instances: Vec::new(),
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut version = None;
+        let mut resources = None;
+        let mut entities = None;
+        let mut instances = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                SceneField::Version => {
+                    if version.is_some() {
+                        return Err(Error::duplicate_field(SCENE_FIELD_VERSION));
+                    }
+                    let value = map.next_value::<u32>()?;
+                    if value != SCENE_FORMAT_VERSION {
+                        return Err(Error::custom(format_args!(
+                            "unsupported scene format version {}, expected {}",
+                            value, SCENE_FORMAT_VERSION
+                        )));
+                    }
+                    version = Some(value);
+                }
+                SceneField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_FIELD_RESOURCES));
+                    }
+                    resources = Some(map.next_value_seed(ReflectMapDeserializer {
+                        registry: self.type_registry,
+                    })?);
+                }
+                SceneField::Entities => {
+                    if entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_FIELD_ENTITIES));
+                    }
+                    entities = Some(map.next_value_seed(EntityMapDeserializer {
+                        registry: self.type_registry,
+                    })?);
+                }
+                SceneField::Instances => {
+                    if instances.is_some() {
+                        return Err(Error::duplicate_field(SCENE_FIELD_INSTANCES));
+                    }
+                    instances = Some(map.next_value_seed(InstancesDeserializer {
+                        registry: self.type_registry,
+                    })?);
+                }
+            }
+        }
+
+        version.ok_or_else(|| Error::missing_field(SCENE_FIELD_VERSION))?;
+        Ok(DynamicScene {
+            resources: resources.unwrap_or_default(),
+            entities: entities.unwrap_or_default(),
+            instances: instances.unwrap_or_default(),
+        })
+    }
+}
+
+pub struct InstancesDeserializer<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for InstancesDeserializer<'a> {
+    type Value = Vec<DynamicSceneInstance>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for InstancesDeserializer<'a> {
+    type Value = Vec<DynamicSceneInstance>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("list of scene instances")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut instances = Vec::new();
+        while let Some(instance) = seq.next_element_seed(InstanceDeserializer {
+            registry: self.registry,
+        })? {
+            instances.push(instance);
+        }
+
+        Ok(instances)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum InstanceField {
+    Entity,
+    Scene,
+    Overrides,
+}
+
+pub const INSTANCE_STRUCT: &str = "Instance";
+pub const INSTANCE_FIELD_ENTITY: &str = "entity";
+pub const INSTANCE_FIELD_SCENE: &str = "scene";
+pub const INSTANCE_FIELD_OVERRIDES: &str = "overrides";
+
+pub struct InstanceDeserializer<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for InstanceDeserializer<'a> {
+    type Value = DynamicSceneInstance;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            INSTANCE_STRUCT,
+            &[
+                INSTANCE_FIELD_ENTITY,
+                INSTANCE_FIELD_SCENE,
+                INSTANCE_FIELD_OVERRIDES,
+            ],
+            self,
+        )
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for InstanceDeserializer<'a> {
+    type Value = DynamicSceneInstance;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("scene instance")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entity = None;
+        let mut path = None;
+        let mut overrides = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                InstanceField::Entity => {
+                    if entity.is_some() {
+                        return Err(Error::duplicate_field(INSTANCE_FIELD_ENTITY));
+                    }
+                    entity = Some(map.next_value::<u32>()?);
+                }
+                InstanceField::Scene => {
+                    if path.is_some() {
+                        return Err(Error::duplicate_field(INSTANCE_FIELD_SCENE));
+                    }
+                    path = Some(map.next_value::<String>()?);
+                }
+                InstanceField::Overrides => {
+                    if overrides.is_some() {
+                        return Err(Error::duplicate_field(INSTANCE_FIELD_OVERRIDES));
+                    }
+                    overrides = Some(map.next_value_seed(EntityMapDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+            }
+        }
+
+        let entity = entity.ok_or_else(|| Error::missing_field(INSTANCE_FIELD_ENTITY))?;
+        let path = path.ok_or_else(|| Error::missing_field(INSTANCE_FIELD_SCENE))?;
+        let mut instance = DynamicSceneInstance::new(entity, path);
+        instance.overrides = overrides.unwrap_or_default();
+        Ok(instance)
+    }
+}
+
+pub struct EntityMapDeserializer<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityMapDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(EntityMapVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct EntityMapVisitor<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for EntityMapVisitor<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map of entities")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entities = Vec::new();
+        while let Some(entity) = map.next_key::<u32>()? {
+            let components = map.next_value_seed(EntityComponentsDeserializer {
+                registry: self.registry,
+            })?;
+            entities.push(DynamicEntity { entity, components });
+        }
+
+        Ok(entities)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum EntityComponentsField {
+    Components,
+}
+
+struct EntityComponentsDeserializer<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityComponentsDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(ENTITY_STRUCT, &[ENTITY_FIELD_COMPONENTS], self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for EntityComponentsDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("entity")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut components = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                EntityComponentsField::Components => {
+                    if components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
+                    }
+                    components = Some(map.next_value_seed(ReflectMapDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+            }
+        }
+
+        Ok(components.unwrap_or_default())
+    }
+}
+
+/// Deserializes a map from type names to untagged values, as written by
+/// [`ReflectMapSerializer`].
+pub struct ReflectMapDeserializer<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReflectMapDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ReflectMapVisitor {
+            registry: self.registry,
         })
     }
 }
 
+struct ReflectMapVisitor<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ReflectMapVisitor<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map of type names to values")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut values = Vec::new();
+        while let Some(type_name) = map.next_key::<String>()? {
+            let registration = self
+                .registry
+                .get_with_short_name(&type_name)
+                .or_else(|| self.registry.get_with_name(&type_name))
+                .ok_or_else(|| {
+                    Error::custom(format_args!("No registration found for {}", type_name))
+                })?;
+            let value =
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            values.push(fill_default_fields(value, registration));
+        }
+
+        Ok(values)
+    }
+}
+
 struct SceneEntitySeqVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
 }
@@ -249,3 +776,193 @@ impl<'a, 'de> Visitor<'de> for ComponentSeqVisitor<'a> {
         Ok(dynamic_properties)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::SceneDeserializer;
+    use crate::{DynamicEntity, DynamicScene, DynamicSceneInstance};
+    use bevy_asset::HandleId;
+    use bevy_ecs::{entity::EntityMap, prelude::*, reflect::ReflectResource};
+    use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect, TypeRegistryArc};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Health {
+        current: u32,
+        max: u32,
+    }
+
+    #[derive(Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Inventory {
+        items: Vec<Item>,
+    }
+
+    #[derive(Reflect, FromReflect, Default, Debug, PartialEq)]
+    struct Item {
+        name: String,
+        count: u32,
+    }
+
+    fn registry() -> TypeRegistryArc {
+        let registry = TypeRegistryArc::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<Score>();
+            registry.register::<Inventory>();
+        }
+        registry
+    }
+
+    fn deserialize(input: &str, registry: &TypeRegistryArc) -> DynamicScene {
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap()
+    }
+
+    #[test]
+    fn round_trip_scene() {
+        let registry = registry();
+        let mut world = World::new();
+        world.insert_resource(registry.clone());
+        world.insert_resource(Score(7));
+        world.spawn().insert(Health { current: 3, max: 0 });
+
+        let scene = DynamicScene::from_world(&world, &registry);
+        let output = scene.serialize_ron(&registry).unwrap();
+        assert!(output.contains("version: 2"));
+        assert!(output.contains("\"Health\""));
+        assert!(output.contains("current: 3"));
+        assert!(!output.contains("max"));
+
+        let scene = deserialize(&output, &registry);
+        let mut dst_world = World::new();
+        dst_world.insert_resource(registry.clone());
+        scene
+            .write_to_world(&mut dst_world, &mut EntityMap::default())
+            .unwrap();
+
+        assert_eq!(dst_world.resource::<Score>(), &Score(7));
+        let health = dst_world.query::<&Health>().single(&dst_world);
+        assert_eq!(health, &Health { current: 3, max: 0 });
+    }
+
+    #[test]
+    fn round_trip_component_with_list_of_structs() {
+        let registry = registry();
+        let mut world = World::new();
+        world.insert_resource(registry.clone());
+        let inventory = Inventory {
+            items: vec![
+                Item {
+                    name: String::from("key"),
+                    count: 1,
+                },
+                Item {
+                    name: String::from("coin"),
+                    count: 12,
+                },
+            ],
+        };
+        world.spawn().insert(inventory);
+
+        let scene = DynamicScene::from_world(&world, &registry);
+        let output = scene.serialize_ron(&registry).unwrap();
+        let scene = deserialize(&output, &registry);
+        let mut dst_world = World::new();
+        dst_world.insert_resource(registry.clone());
+        scene
+            .write_to_world(&mut dst_world, &mut EntityMap::default())
+            .unwrap();
+
+        let inventory = dst_world.query::<&Inventory>().single(&dst_world);
+        assert_eq!(inventory.items.len(), 2);
+        assert_eq!(inventory.items[1].name, "coin");
+        assert_eq!(inventory.items[1].count, 12);
+    }
+
+    #[test]
+    fn load_legacy_scene() {
+        let registry = registry();
+        let input = r#"[
+  (
+    entity: 4,
+    components: [
+      {
+        "type": "bevy_scene::serde::tests::Health",
+        "struct": {
+          "current": {
+            "type": "u32",
+            "value": 5,
+          },
+          "max": {
+            "type": "u32",
+            "value": 10,
+          },
+        },
+      },
+    ],
+  ),
+]"#;
+
+        let scene = deserialize(input, &registry);
+        assert!(scene.resources.is_empty());
+        assert_eq!(scene.entities.len(), 1);
+        assert_eq!(scene.entities[0].entity, 4);
+        assert_eq!(scene.entities[0].components.len(), 1);
+    }
+
+    #[test]
+    fn round_trip_scene_instances() {
+        let registry = registry();
+        let mut scene = DynamicScene::default();
+        scene.entities.push(DynamicEntity {
+            entity: 0,
+            components: Vec::new(),
+        });
+        scene
+            .instances
+            .push(
+                DynamicSceneInstance::new(0, "door.scn.ron").with_override(DynamicEntity {
+                    entity: 1,
+                    components: vec![Box::new(Health { current: 1, max: 2 })],
+                }),
+            );
+
+        let output = scene.serialize_ron(&registry).unwrap();
+        assert!(output.contains("\"door.scn.ron\""));
+
+        let scene = deserialize(&output, &registry);
+        assert_eq!(scene.instances.len(), 1);
+        let instance = &scene.instances[0];
+        assert_eq!(instance.entity, 0);
+        assert_eq!(instance.path, "door.scn.ron");
+        assert_eq!(instance.handle.id, HandleId::from("door.scn.ron"));
+        assert_eq!(instance.overrides.len(), 1);
+        assert_eq!(instance.overrides[0].entity, 1);
+        assert!(instance.overrides[0].components[0]
+            .reflect_partial_eq(&Health { current: 1, max: 2 })
+            .unwrap());
+    }
+
+    #[test]
+    fn reject_unknown_version() {
+        let registry = registry();
+        let mut deserializer =
+            ron::de::Deserializer::from_str("(version: 3, entities: {})").unwrap();
+        let result = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer);
+        assert!(result.is_err());
+    }
+}
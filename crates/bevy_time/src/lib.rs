@@ -17,6 +17,7 @@ pub mod prelude {
 
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
+use bevy_utils::{Duration, Instant};
 
 /// Adds time functionality to Apps.
 #[derive(Default)]
@@ -30,6 +31,7 @@ pub struct TimeSystem;
 impl Plugin for TimePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Time>()
+            .init_resource::<TimeUpdateStrategy>()
             .init_resource::<FixedTimesteps>()
             .register_type::<Timer>()
             // time system is added as an "exclusive system" to ensure it runs before other systems
@@ -41,6 +43,68 @@ impl Plugin for TimePlugin {
     }
 }
 
-fn time_system(mut time: ResMut<Time>) {
-    time.update();
+/// Configures how the [`Time`] resource is updated every frame.
+///
+/// The manual strategies make time deterministic, which is mostly useful in tests:
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_time::{Time, TimePlugin, TimeUpdateStrategy};
+/// # use bevy_utils::Duration;
+/// let mut app = App::new();
+/// app.add_plugin(TimePlugin)
+///     .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+/// app.update_frames(3);
+/// assert_eq!(app.world.resource::<Time>().delta(), Duration::from_millis(100));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUpdateStrategy {
+    /// Time advances with the wall clock.
+    Automatic,
+    /// Time is set to the given [`Instant`] on every update.
+    ManualInstant(Instant),
+    /// Time advances by the given [`Duration`] on every update.
+    ManualDuration(Duration),
+}
+
+impl Default for TimeUpdateStrategy {
+    fn default() -> Self {
+        TimeUpdateStrategy::Automatic
+    }
+}
+
+fn time_system(mut time: ResMut<Time>, update_strategy: Res<TimeUpdateStrategy>) {
+    match *update_strategy {
+        TimeUpdateStrategy::Automatic => time.update(),
+        TimeUpdateStrategy::ManualInstant(instant) => time.update_with_instant(instant),
+        TimeUpdateStrategy::ManualDuration(duration) => {
+            let last_update = time.last_update().unwrap_or_else(|| time.startup());
+            time.update_with_instant(last_update + duration);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Time, TimePlugin, TimeUpdateStrategy};
+    use bevy_app::App;
+    use bevy_utils::Duration;
+
+    #[test]
+    fn manual_duration_update() {
+        let mut app = App::new();
+        app.add_plugin(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                250,
+            )));
+
+        app.update();
+        let time = app.world.resource::<Time>();
+        assert_eq!(time.time_since_startup(), Duration::from_millis(250));
+
+        app.update_frames(3);
+        let time = app.world.resource::<Time>();
+        assert_eq!(time.delta(), Duration::from_millis(250));
+        assert_eq!(time.time_since_startup(), Duration::from_secs(1));
+    }
 }
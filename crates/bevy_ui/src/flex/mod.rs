@@ -12,13 +12,13 @@ use bevy_log::warn;
 use bevy_math::Vec2;
 use bevy_transform::components::Transform;
 use bevy_utils::HashMap;
-use bevy_window::{Window, WindowId, WindowScaleFactorChanged, Windows};
+use bevy_window::{PrimaryWindow, Window, WindowClosed, WindowScaleFactorChanged};
 use std::fmt;
 use taffy::{number::Number, Taffy};
 
 pub struct FlexSurface {
     entity_to_taffy: HashMap<Entity, taffy::node::Node>,
-    window_nodes: HashMap<WindowId, taffy::node::Node>,
+    window_nodes: HashMap<Entity, taffy::node::Node>,
     taffy: Taffy,
 }
 
@@ -31,7 +31,7 @@ unsafe impl Sync for FlexSurface {}
 fn _assert_send_sync_flex_surface_impl_safe() {
     fn _assert_send_sync<T: Send + Sync>() {}
     _assert_send_sync::<HashMap<Entity, taffy::node::Node>>();
-    _assert_send_sync::<HashMap<WindowId, taffy::node::Node>>();
+    _assert_send_sync::<HashMap<Entity, taffy::node::Node>>();
     // FIXME https://github.com/DioxusLabs/taffy/issues/146
     // _assert_send_sync::<Taffy>();
 }
@@ -129,9 +129,9 @@ without UI components as a child of an entity with UI components, results may be
             .unwrap();
     }
 
-    pub fn update_window(&mut self, window: &Window) {
+    pub fn update_window(&mut self, window_entity: Entity, window: &Window) {
         let taffy = &mut self.taffy;
-        let node = self.window_nodes.entry(window.id()).or_insert_with(|| {
+        let node = self.window_nodes.entry(window_entity).or_insert_with(|| {
             taffy
                 .new_node(taffy::style::Style::default(), &Vec::new())
                 .unwrap()
@@ -151,12 +151,19 @@ without UI components as a child of an entity with UI components, results may be
             .unwrap();
     }
 
+    /// Removes the root node of a closed window.
+    pub fn remove_window(&mut self, window_entity: Entity) {
+        if let Some(node) = self.window_nodes.remove(&window_entity) {
+            self.taffy.remove(node);
+        }
+    }
+
     pub fn set_window_children(
         &mut self,
-        window_id: WindowId,
+        window_entity: Entity,
         children: impl Iterator<Item = Entity>,
     ) {
-        let taffy_node = self.window_nodes.get(&window_id).unwrap();
+        let taffy_node = self.window_nodes.get(&window_entity).unwrap();
         let child_nodes = children
             .map(|e| *self.entity_to_taffy.get(&e).unwrap())
             .collect::<Vec<taffy::node::Node>>();
@@ -194,8 +201,10 @@ pub enum FlexError {
 
 #[allow(clippy::too_many_arguments)]
 pub fn flex_node_system(
-    windows: Res<Windows>,
+    primary_window: Query<(Entity, &Window), With<PrimaryWindow>>,
+    windows: Query<(Entity, &Window)>,
     mut scale_factor_events: EventReader<WindowScaleFactorChanged>,
+    mut window_closed_events: EventReader<WindowClosed>,
     mut flex_surface: ResMut<FlexSurface>,
     root_node_query: Query<Entity, (With<Node>, Without<Parent>)>,
     node_query: Query<(Entity, &Style, Option<&CalculatedSize>), (With<Node>, Changed<Style>)>,
@@ -208,12 +217,17 @@ pub fn flex_node_system(
     mut node_transform_query: Query<(Entity, &mut Node, &mut Transform, Option<&Parent>)>,
 ) {
     // update window root nodes
-    for window in windows.iter() {
-        flex_surface.update_window(window);
+    for event in window_closed_events.iter() {
+        flex_surface.remove_window(event.window);
+    }
+    for (entity, window) in windows.iter() {
+        flex_surface.update_window(entity, window);
     }
 
     // assume one window for time being...
-    let logical_to_physical_factor = windows.scale_factor(WindowId::primary());
+    let primary_window = primary_window.get_single().ok();
+    let logical_to_physical_factor =
+        primary_window.map_or(1.0, |(_, window)| window.scale_factor());
 
     if scale_factor_events.iter().next_back().is_some() {
         update_changed(
@@ -248,8 +262,8 @@ pub fn flex_node_system(
     // TODO: handle removed nodes
 
     // update window children (for now assuming all Nodes live in the primary window)
-    if let Some(primary_window) = windows.get_primary() {
-        flex_surface.set_window_children(primary_window.id(), root_node_query.iter());
+    if let Some((primary_window, _)) = primary_window {
+        flex_surface.set_window_children(primary_window, root_node_query.iter());
     }
 
     // update children
@@ -290,3 +304,67 @@ pub fn flex_node_system(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{flex_node_system, FlexSurface};
+    use crate::{Node, Size, Style, Val};
+    use bevy_app::App;
+    use bevy_ecs::{entity::Entity, query::With};
+    use bevy_math::Vec2;
+    use bevy_transform::components::Transform;
+    use bevy_window::{
+        headless::{HeadlessWindowPlugin, HeadlessWindowSettings},
+        PrimaryWindow, Window, WindowPlugin,
+    };
+
+    #[test]
+    fn headless_window_layout() {
+        let mut app = App::new();
+        app.insert_resource(HeadlessWindowSettings { scale_factor: 2.0 })
+            .add_plugin(WindowPlugin {
+                exit_on_all_closed: false,
+                ..Default::default()
+            })
+            .add_plugin(HeadlessWindowPlugin)
+            .init_resource::<FlexSurface>()
+            .add_system(flex_node_system);
+        let node = app
+            .world
+            .spawn()
+            .insert_bundle((
+                Node::default(),
+                Style {
+                    size: Size::new(Val::Percent(50.0), Val::Percent(25.0)),
+                    ..Default::default()
+                },
+                Transform::default(),
+            ))
+            .id();
+        app.update();
+        let primary = app
+            .world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(&app.world);
+        assert_eq!(
+            app.world.get::<Node>(node).unwrap().size,
+            Vec2::new(640.0, 180.0)
+        );
+
+        app.world
+            .get_mut::<Window>(primary)
+            .unwrap()
+            .set_resolution(400.0, 200.0);
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world.get::<Node>(node).unwrap().size,
+            Vec2::new(200.0, 50.0)
+        );
+
+        app.world.despawn(primary);
+        app.update();
+        app.update();
+        assert!(app.world.resource::<FlexSurface>().window_nodes.is_empty());
+    }
+}
@@ -2,6 +2,7 @@ use crate::{CalculatedClip, Node};
 use bevy_ecs::{
     entity::Entity,
     prelude::Component,
+    query::With,
     reflect::ReflectComponent,
     system::{Local, Query, Res},
 };
@@ -10,7 +11,7 @@ use bevy_math::Vec2;
 use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
 use bevy_transform::components::GlobalTransform;
 use bevy_utils::FloatOrd;
-use bevy_window::Windows;
+use bevy_window::{PrimaryWindow, Window};
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 
@@ -59,7 +60,7 @@ pub struct State {
 /// The system that sets Interaction for all UI elements based on the mouse cursor activity
 pub fn ui_focus_system(
     mut state: Local<State>,
-    windows: Res<Windows>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
     mouse_button_input: Res<Input<MouseButton>>,
     touches_input: Res<Touches>,
     mut node_query: Query<(
@@ -95,8 +96,9 @@ pub fn ui_focus_system(
     let mouse_clicked =
         mouse_button_input.just_pressed(MouseButton::Left) || touches_input.any_just_pressed();
 
-    let cursor_position = windows
-        .get_primary()
+    let cursor_position = primary_window
+        .get_single()
+        .ok()
         .and_then(|window| window.cursor_position())
         .or_else(|| touches_input.first_pressed_position());
 
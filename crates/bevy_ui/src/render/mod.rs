@@ -28,7 +28,7 @@ use bevy_text::{DefaultTextPipeline, Text};
 use bevy_transform::components::GlobalTransform;
 use bevy_utils::FloatOrd;
 use bevy_utils::HashMap;
-use bevy_window::{WindowId, Windows};
+use bevy_window::{PrimaryWindow, Window};
 use bytemuck::{Pod, Zeroable};
 use std::ops::Range;
 
@@ -274,7 +274,7 @@ pub fn extract_text_uinodes(
     mut render_world: ResMut<RenderWorld>,
     texture_atlases: Res<Assets<TextureAtlas>>,
     text_pipeline: Res<DefaultTextPipeline>,
-    windows: Res<Windows>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
     uinode_query: Query<(
         Entity,
         &Node,
@@ -286,7 +286,9 @@ pub fn extract_text_uinodes(
 ) {
     let mut extracted_uinodes = render_world.resource_mut::<ExtractedUiNodes>();
 
-    let scale_factor = windows.scale_factor(WindowId::primary()) as f32;
+    let scale_factor = primary_window
+        .get_single()
+        .map_or(1.0, |window| window.scale_factor()) as f32;
 
     for (entity, uinode, transform, text, visibility, clip) in uinode_query.iter() {
         if !visibility.is_visible {
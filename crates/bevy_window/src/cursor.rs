@@ -3,6 +3,9 @@
 /// Examples of all of these cursors can be found [here](https://www.w3schools.com/cssref/playit.asp?filename=playcss_cursor).
 /// This `enum` is simply a copy of a similar `enum` found in [`winit`](https://docs.rs/winit/latest/winit/window/enum.CursorIcon.html).
 /// `winit`, in turn, mostly copied cursor types avilable in the browser.
+///
+/// Cursors can't be set from custom images yet, as the version of `winit` used by `bevy_winit`
+/// doesn't support them.
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 pub enum CursorIcon {
     /// The platform-dependent default cursor.
@@ -79,3 +82,29 @@ pub enum CursorIcon {
     /// Indicates that the row can be resized vertically.
     RowResize,
 }
+
+/// Defines if and how the cursor is grabbed by a window.
+///
+/// ## Platform-specific
+///
+/// - **`Windows`** and **`X11`** don't support [`CursorGrabMode::Locked`], `bevy_winit` emulates
+///   it by moving the cursor back to where it was locked.
+/// - **`Wayland`** doesn't support [`CursorGrabMode::Locked`] nor moving the cursor, so the cursor
+///   is confined instead.
+/// - **`macOS`** doesn't support [`CursorGrabMode::Confined`], so the cursor is locked instead.
+/// - **`iOS/Android`** don't have cursors.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum CursorGrabMode {
+    /// The cursor can freely leave the window.
+    None,
+    /// The cursor is confined to the window area.
+    Confined,
+    /// The cursor is locked inside the window area to a certain position.
+    Locked,
+}
+
+impl Default for CursorGrabMode {
+    fn default() -> Self {
+        CursorGrabMode::None
+    }
+}
@@ -0,0 +1,410 @@
+//! A windowing backend with virtual windows, to run windowed apps headless, for example in CI.
+use crate::{
+    CursorMoved, ModifiesWindows, Window, WindowBackend, WindowBackendScaleFactorChanged,
+    WindowClosed, WindowCreated, WindowMoved, WindowPlugin, WindowResized,
+    WindowScaleFactorChanged,
+};
+use bevy_app::{App, CoreStage, Plugin, PluginDependency};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::EventWriter,
+    query::{Added, Changed, With},
+    schedule::ParallelSystemDescriptorCoercion,
+    system::{Commands, Local, Query, Res},
+};
+use bevy_utils::HashSet;
+
+/// The settings of the [`HeadlessWindowPlugin`].
+#[derive(Debug, Clone)]
+pub struct HeadlessWindowSettings {
+    /// The scale factor reported for every virtual window, as an OS would for its monitor.
+    ///
+    /// Changing it applies the new scale factor to the open windows too.
+    pub scale_factor: f64,
+}
+
+impl Default for HeadlessWindowSettings {
+    fn default() -> Self {
+        HeadlessWindowSettings { scale_factor: 1.0 }
+    }
+}
+
+/// Marks a [`Window`] backed by a virtual window of the [`HeadlessWindowPlugin`].
+#[derive(Component, Debug, Clone)]
+pub struct HeadlessWindow {
+    /// The scale factor of the window when its changes were last applied.
+    scale_factor: f64,
+}
+
+/// Returns the physical size of a virtual window, which always has its requested size.
+fn physical_size(window: &Window) -> (u32, u32) {
+    let scale_factor = window.scale_factor();
+    (
+        (window.requested_width() as f64 * scale_factor).round() as u32,
+        (window.requested_height() as f64 * scale_factor).round() as u32,
+    )
+}
+
+/// Opens a virtual window for each new [`Window`].
+///
+/// The window is focused, has the requested size and the scale factor of the
+/// [`HeadlessWindowSettings`], and is placed at its requested position, if any.
+pub fn create_headless_windows(
+    mut commands: Commands,
+    settings: Res<HeadlessWindowSettings>,
+    mut created_windows: Query<(Entity, &mut Window), Added<Window>>,
+    mut window_created_events: EventWriter<WindowCreated>,
+    mut window_resized_events: EventWriter<WindowResized>,
+) {
+    for (entity, mut window) in created_windows.iter_mut() {
+        window.update_scale_factor_from_backend(settings.scale_factor);
+        let (physical_width, physical_height) = physical_size(&window);
+        window.update_actual_size_from_backend(physical_width, physical_height);
+        window.update_focused_status_from_backend(true);
+        let position = window.take_position_request().or_else(|| {
+            window
+                .initial_position()
+                .map(|position| position.as_ivec2())
+        });
+        if let Some(position) = position {
+            window.update_actual_position_from_backend(position);
+        }
+
+        commands.entity(entity).insert(HeadlessWindow {
+            scale_factor: window.scale_factor(),
+        });
+        window_resized_events.send(WindowResized {
+            window: entity,
+            width: window.width(),
+            height: window.height(),
+        });
+        window_created_events.send(WindowCreated { window: entity });
+    }
+}
+
+/// Applies a change of the [`HeadlessWindowSettings`] scale factor to the open virtual windows,
+/// as if they were moved to a monitor with that scale factor.
+pub fn apply_headless_window_settings(
+    settings: Res<HeadlessWindowSettings>,
+    mut windows: Query<(Entity, &mut Window), With<HeadlessWindow>>,
+    mut window_backend_scale_factor_events: EventWriter<WindowBackendScaleFactorChanged>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (entity, mut window) in windows.iter_mut() {
+        if window.backend_scale_factor() != settings.scale_factor {
+            window.update_scale_factor_from_backend(settings.scale_factor);
+            window_backend_scale_factor_events.send(WindowBackendScaleFactorChanged {
+                window: entity,
+                scale_factor: settings.scale_factor,
+            });
+        }
+    }
+}
+
+/// Applies the changes of [`Window`]s to their virtual windows.
+///
+/// Resizes and moves happen immediately, and a cursor position set with
+/// [`Window::set_cursor_position`] is reported with a [`CursorMoved`] event, so cursor input can
+/// be simulated. Maximize and minimize requests are ignored.
+pub fn update_headless_windows(
+    mut changed_windows: Query<(Entity, &mut Window, &mut HeadlessWindow), Changed<Window>>,
+    mut window_resized_events: EventWriter<WindowResized>,
+    mut window_scale_factor_events: EventWriter<WindowScaleFactorChanged>,
+    mut window_moved_events: EventWriter<WindowMoved>,
+    mut cursor_moved_events: EventWriter<CursorMoved>,
+) {
+    for (entity, mut window, mut headless) in changed_windows.iter_mut() {
+        if window.scale_factor() != headless.scale_factor {
+            headless.scale_factor = window.scale_factor();
+            window_scale_factor_events.send(WindowScaleFactorChanged {
+                window: entity,
+                scale_factor: window.scale_factor(),
+            });
+        }
+
+        let (physical_width, physical_height) = physical_size(&window);
+        if (physical_width, physical_height) != (window.physical_width(), window.physical_height())
+        {
+            window.update_actual_size_from_backend(physical_width, physical_height);
+            window_resized_events.send(WindowResized {
+                window: entity,
+                width: window.width(),
+                height: window.height(),
+            });
+        }
+
+        if window.has_pending_requests() {
+            window.take_maximize_request();
+            window.take_minimize_request();
+            if let Some(position) = window.take_position_request() {
+                if window.position() != Some(position) {
+                    window.update_actual_position_from_backend(position);
+                    window_moved_events.send(WindowMoved {
+                        window: entity,
+                        position,
+                    });
+                }
+            }
+            if let Some(position) = window.take_cursor_position_request() {
+                let physical_position = position.as_dvec2() * window.scale_factor();
+                window.update_cursor_physical_position_from_backend(Some(physical_position));
+                cursor_moved_events.send(CursorMoved {
+                    window: entity,
+                    position,
+                });
+            }
+        }
+    }
+}
+
+/// Closes the virtual windows of despawned [`Window`]s.
+///
+/// The open virtual windows are checked against the live [`Window`] entities, so windows
+/// despawned since the last run are closed whenever that happened.
+pub fn close_headless_windows(
+    mut open_windows: Local<HashSet<Entity>>,
+    windows: Query<Entity, With<HeadlessWindow>>,
+    mut window_closed_events: EventWriter<WindowClosed>,
+) {
+    open_windows.retain(|window| {
+        let open = windows.contains(*window);
+        if !open {
+            window_closed_events.send(WindowClosed { window: *window });
+        }
+        open
+    });
+    open_windows.extend(windows.iter());
+}
+
+/// A windowing backend which opens virtual windows instead of OS windows.
+///
+/// The [`Window`]s behave as if the OS always granted their requested settings, and send the
+/// same events as other backends, so window dependent logic like UI layout, cursor picking and
+/// camera projections can run in tests or on machines without a display. This replaces a
+/// backend like `bevy_winit`, and needs a runner such as the `ScheduleRunnerPlugin`.
+///
+/// Virtual windows have no surface to render to: `bevy_render` renders the cameras targeting
+/// them offscreen, to an `Image` of the window's physical size added to the window entity.
+///
+/// # Panics
+///
+/// Panics if another [`WindowBackend`] has already been added.
+#[derive(Default)]
+pub struct HeadlessWindowPlugin;
+
+impl Plugin for HeadlessWindowPlugin {
+    fn build(&self, app: &mut App) {
+        WindowBackend::insert(app, std::any::type_name::<Self>());
+        app.init_resource::<HeadlessWindowSettings>()
+            .add_system_to_stage(CoreStage::PreUpdate, create_headless_windows)
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                apply_headless_window_settings.before(ModifiesWindows),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                update_headless_windows.label(ModifiesWindows),
+            )
+            // Run last, so windows despawned at any point of the frame are closed
+            .add_system_to_stage(CoreStage::Last, close_headless_windows);
+    }
+
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        vec![PluginDependency::required::<WindowPlugin>()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeadlessWindowPlugin, HeadlessWindowSettings};
+    use crate::{
+        CursorMoved, PrimaryWindow, Window, WindowBackend, WindowBackendScaleFactorChanged,
+        WindowClosed, WindowCreated, WindowMoved, WindowPlugin, WindowResized,
+        WindowScaleFactorChanged,
+    };
+    use bevy_app::App;
+    use bevy_ecs::{entity::Entity, event::Events, query::With};
+    use bevy_input::InputPlugin;
+    use bevy_math::{IVec2, Vec2};
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.insert_resource(HeadlessWindowSettings { scale_factor: 2.0 })
+            .add_plugin(InputPlugin)
+            .add_plugin(WindowPlugin::default())
+            .add_plugin(HeadlessWindowPlugin);
+        app
+    }
+
+    fn primary_window(app: &mut App) -> Entity {
+        app.world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(&app.world)
+    }
+
+    #[test]
+    fn open_and_resize_windows() {
+        let mut app = app();
+        app.update();
+        let primary = primary_window(&mut app);
+        let window = app.world.get::<Window>(primary).unwrap();
+        assert_eq!(window.scale_factor(), 2.0);
+        assert_eq!(window.physical_width(), 2560);
+        assert_eq!(window.physical_height(), 1440);
+        assert_eq!(window.width(), 1280.0);
+        assert!(window.is_focused());
+        let created = app.world.resource::<Events<WindowCreated>>();
+        assert_eq!(
+            created.get_reader().iter(created).next().unwrap().window,
+            primary
+        );
+
+        app.world
+            .get_mut::<Window>(primary)
+            .unwrap()
+            .set_resolution(400.0, 300.0);
+        app.update();
+        let window = app.world.get::<Window>(primary).unwrap();
+        assert_eq!(window.physical_width(), 800);
+        assert_eq!(window.height(), 300.0);
+        let resized = app.world.resource::<Events<WindowResized>>();
+        let mut resized_reader = resized.get_reader();
+        let last_resize = resized_reader.iter(resized).last().unwrap();
+        assert_eq!((last_resize.width, last_resize.height), (400.0, 300.0));
+
+        app.world.despawn(primary);
+        app.update();
+        let closed = app.world.resource::<Events<WindowClosed>>();
+        assert_eq!(
+            closed.get_reader().iter(closed).next().unwrap().window,
+            primary
+        );
+    }
+
+    #[test]
+    fn simulate_cursor() {
+        let mut app = app();
+        app.update();
+        let primary = primary_window(&mut app);
+
+        app.world
+            .get_mut::<Window>(primary)
+            .unwrap()
+            .set_cursor_position(Vec2::new(10.0, 20.0));
+        app.update();
+        let window = app.world.get::<Window>(primary).unwrap();
+        assert_eq!(window.cursor_position(), Some(Vec2::new(10.0, 20.0)));
+        let moved = app.world.resource::<Events<CursorMoved>>();
+        let mut moved_reader = moved.get_reader();
+        let event = moved_reader.iter(moved).next().unwrap();
+        assert_eq!(event.window, primary);
+        assert_eq!(event.position, Vec2::new(10.0, 20.0));
+    }
+
+    #[test]
+    fn scale_factor_override() {
+        let mut app = app();
+        app.update();
+        let primary = primary_window(&mut app);
+
+        app.world
+            .get_mut::<Window>(primary)
+            .unwrap()
+            .set_scale_factor_override(Some(1.0));
+        app.update();
+        let window = app.world.get::<Window>(primary).unwrap();
+        assert_eq!(window.scale_factor(), 1.0);
+        assert_eq!(window.backend_scale_factor(), 2.0);
+        assert_eq!(window.physical_width(), 1280);
+        assert_eq!(window.width(), 1280.0);
+        let changed = app.world.resource::<Events<WindowScaleFactorChanged>>();
+        let mut changed_reader = changed.get_reader();
+        let event = changed_reader.iter(changed).last().unwrap();
+        assert_eq!((event.window, event.scale_factor), (primary, 1.0));
+    }
+
+    #[test]
+    fn settings_apply_to_open_windows() {
+        let mut app = app();
+        app.update();
+        let primary = primary_window(&mut app);
+
+        app.world
+            .resource_mut::<HeadlessWindowSettings>()
+            .scale_factor = 1.5;
+        app.update();
+        let window = app.world.get::<Window>(primary).unwrap();
+        assert_eq!(window.scale_factor(), 1.5);
+        assert_eq!(window.physical_width(), 1920);
+        let changed = app
+            .world
+            .resource::<Events<WindowBackendScaleFactorChanged>>();
+        let mut changed_reader = changed.get_reader();
+        let event = changed_reader.iter(changed).next().unwrap();
+        assert_eq!((event.window, event.scale_factor), (primary, 1.5));
+        let resized = app.world.resource::<Events<WindowResized>>();
+        let mut resized_reader = resized.get_reader();
+        let last_resize = resized_reader.iter(resized).last().unwrap();
+        assert_eq!((last_resize.width, last_resize.height), (1280.0, 720.0));
+    }
+
+    #[test]
+    fn move_windows() {
+        let mut app = app();
+        app.update();
+        let primary = primary_window(&mut app);
+
+        let moves = |app: &App| {
+            let moved = app.world.resource::<Events<WindowMoved>>();
+            let mut moved_reader = moved.get_reader();
+            moved_reader
+                .iter(moved)
+                .map(|event| (event.window, event.position))
+                .collect::<Vec<_>>()
+        };
+
+        let position = IVec2::new(10, 20);
+        app.world
+            .get_mut::<Window>(primary)
+            .unwrap()
+            .set_position(position);
+        app.update();
+        assert_eq!(
+            app.world.get::<Window>(primary).unwrap().position(),
+            Some(position)
+        );
+        assert_eq!(moves(&app), vec![(primary, position)]);
+
+        // Moved by the user, then requested at the same position again
+        app.world
+            .get_mut::<Window>(primary)
+            .unwrap()
+            .update_actual_position_from_backend(IVec2::ZERO);
+        app.update();
+        app.update();
+        app.world
+            .get_mut::<Window>(primary)
+            .unwrap()
+            .set_position(position);
+        app.update();
+        assert_eq!(
+            app.world.get::<Window>(primary).unwrap().position(),
+            Some(position)
+        );
+        assert_eq!(moves(&app), vec![(primary, position)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the windows are already opened by bevy_winit::WinitPlugin")]
+    fn reject_other_backends() {
+        let mut app = App::new();
+        app.insert_resource(WindowBackend {
+            name: "bevy_winit::WinitPlugin",
+        })
+        .add_plugin(WindowPlugin::default())
+        .add_plugin(HeadlessWindowPlugin);
+    }
+}
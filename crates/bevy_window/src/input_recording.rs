@@ -0,0 +1,646 @@
+use crate::{ReceivedCharacter, WindowPlugin};
+use bevy_app::{App, CoreStage, Plugin, PluginDependency};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventReader, Events},
+    prelude::{ExclusiveSystemDescriptorCoercion, IntoExclusiveSystem},
+    schedule::ParallelSystemDescriptorCoercion,
+    system::{Res, ResMut},
+    world::World,
+};
+use bevy_input::{
+    gamepad::GamepadEventRaw,
+    keyboard::KeyboardInput,
+    mouse::{MouseButtonInput, MouseMotion, MouseWheel},
+    touch::TouchInput,
+    InputBackendSystem, InputPlugin, InputSystem,
+};
+use bevy_reflect::{Reflect, ReflectRef};
+use bevy_time::{Time, TimePlugin, TimeSystem, TimeUpdateStrategy};
+use bevy_utils::{AHasher, Duration, FixedState};
+use std::hash::{BuildHasher, Hasher};
+
+/// An input event captured by the [`InputRecordingPlugin`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum RecordedInput {
+    Keyboard(KeyboardInput),
+    MouseButton(MouseButtonInput),
+    MouseMotion(MouseMotion),
+    MouseWheel(MouseWheel),
+    Touch(TouchInput),
+    Gamepad(GamepadEventRaw),
+    Character(ReceivedCharacter),
+}
+
+impl RecordedInput {
+    /// Sends the event.
+    fn send(self, world: &mut World) {
+        fn send<T: Event>(world: &mut World, event: T) {
+            world.resource_mut::<Events<T>>().send(event);
+        }
+        match self {
+            RecordedInput::Keyboard(event) => send(world, event),
+            RecordedInput::MouseButton(event) => send(world, event),
+            RecordedInput::MouseMotion(event) => send(world, event),
+            RecordedInput::MouseWheel(event) => send(world, event),
+            RecordedInput::Touch(event) => send(world, event),
+            RecordedInput::Gamepad(event) => send(world, event),
+            RecordedInput::Character(event) => send(world, event),
+        }
+    }
+}
+
+/// The inputs of a frame of an [`InputRecording`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedFrame {
+    /// The number of the frame, starting at `0` for the first update.
+    pub frame: u32,
+    /// The [`Time`] delta of the frame.
+    pub delta: Duration,
+    /// The input events processed during the frame, grouped by type.
+    pub inputs: Vec<RecordedInput>,
+    /// The [`InputChecksum`] of the world at the end of the frame, if any component was selected.
+    pub checksum: Option<u64>,
+}
+
+/// The inputs of an app, frame by frame.
+///
+/// This resource is filled by the [`InputRecordingPlugin`], and is replayed by the
+/// [`InputReplayPlugin`].
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputRecording {
+    pub frames: Vec<RecordedFrame>,
+}
+
+/// An error that occurs when saving or loading an [`InputRecording`].
+#[cfg(feature = "serialize")]
+#[derive(thiserror::Error, Debug)]
+pub enum InputRecordingError {
+    #[error("failed to access the recording file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid recording: {0}")]
+    Ron(#[from] ron::Error),
+}
+
+#[cfg(feature = "serialize")]
+impl InputRecording {
+    /// Saves the recording to a RON file.
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<(), InputRecordingError> {
+        let ron = ron::ser::to_string_pretty(self, Default::default())?;
+        std::fs::write(path, ron)?;
+        Ok(())
+    }
+
+    /// Loads a recording from a RON file.
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, InputRecordingError> {
+        let ron = std::fs::read_to_string(path)?;
+        Ok(ron::from_str(&ron)?)
+    }
+}
+
+/// Computes a checksum of selected components, to detect when a replay diverges from its
+/// recording.
+///
+/// Components are hashed in the order of their entities with the [`FixedState`] hasher, through
+/// their reflected structure and the bit patterns of their primitive fields, so the checksum is
+/// the same from one run to the next. Other value types are hashed with
+/// [`Reflect::reflect_hash`], or only by type name if they don't support hashing.
+#[derive(Clone, Default)]
+pub struct InputChecksum {
+    hashers: Vec<fn(&mut World, &mut AHasher)>,
+}
+
+impl InputChecksum {
+    /// Includes the components of type `T` in the checksum.
+    pub fn with_component<T: Component + Reflect>(mut self) -> Self {
+        self.hashers.push(|world, hasher| {
+            let mut components: Vec<_> = world.query::<(Entity, &T)>().iter(world).collect();
+            components.sort_by_key(|(entity, _)| *entity);
+            hasher.write_usize(components.len());
+            for (entity, component) in components {
+                hasher.write_u32(entity.id());
+                hasher.write_u32(entity.generation());
+                hash_reflect(component, hasher);
+            }
+        });
+        self
+    }
+
+    /// Returns the checksum of the selected components, or `None` if none was selected.
+    pub fn compute(&self, world: &mut World) -> Option<u64> {
+        if self.hashers.is_empty() {
+            return None;
+        }
+        let mut hasher = FixedState.build_hasher();
+        for hash in &self.hashers {
+            hash(world, &mut hasher);
+        }
+        Some(hasher.finish())
+    }
+}
+
+/// Hashes the structure of `value` and the bit patterns of its primitive values.
+fn hash_reflect(value: &dyn Reflect, hasher: &mut AHasher) {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            hasher.write_usize(value.field_len());
+            for (index, field) in value.iter_fields().enumerate() {
+                hasher.write(value.name_at(index).unwrap_or_default().as_bytes());
+                hash_reflect(field, hasher);
+            }
+        }
+        ReflectRef::TupleStruct(value) => {
+            hasher.write_usize(value.field_len());
+            value
+                .iter_fields()
+                .for_each(|field| hash_reflect(field, hasher));
+        }
+        ReflectRef::Tuple(value) => {
+            hasher.write_usize(value.field_len());
+            value
+                .iter_fields()
+                .for_each(|field| hash_reflect(field, hasher));
+        }
+        ReflectRef::List(value) => {
+            hasher.write_usize(value.len());
+            value.iter().for_each(|item| hash_reflect(item, hasher));
+        }
+        ReflectRef::Array(value) => {
+            hasher.write_usize(value.len());
+            value.iter().for_each(|item| hash_reflect(item, hasher));
+        }
+        ReflectRef::Map(value) => {
+            // Entries are combined commutatively, as the iteration order of maps isn't stable
+            let entries = value.iter().fold(0u64, |entries, (key, value)| {
+                let mut entry_hasher = FixedState.build_hasher();
+                hash_reflect(key, &mut entry_hasher);
+                hash_reflect(value, &mut entry_hasher);
+                entries.wrapping_add(entry_hasher.finish())
+            });
+            hasher.write_usize(value.len());
+            hasher.write_u64(entries);
+        }
+        ReflectRef::Value(value) => hash_value(value, hasher),
+    }
+}
+
+/// Hashes the bit pattern of a primitive value, or the [`Reflect::reflect_hash`] of other values.
+fn hash_value(value: &dyn Reflect, hasher: &mut AHasher) {
+    macro_rules! hash_primitives {
+        ($($ty:ty => $write:ident),* $(,)?) => {
+            $(if let Some(value) = value.downcast_ref::<$ty>() {
+                hasher.$write(*value as _);
+                return;
+            })*
+        };
+    }
+
+    hash_primitives!(
+        bool => write_u8,
+        char => write_u32,
+        u8 => write_u8,
+        u16 => write_u16,
+        u32 => write_u32,
+        u64 => write_u64,
+        u128 => write_u128,
+        usize => write_u64,
+        i8 => write_i8,
+        i16 => write_i16,
+        i32 => write_i32,
+        i64 => write_i64,
+        i128 => write_i128,
+        isize => write_i64,
+    );
+    if let Some(value) = value.downcast_ref::<f32>() {
+        hasher.write_u32(value.to_bits());
+    } else if let Some(value) = value.downcast_ref::<f64>() {
+        hasher.write_u64(value.to_bits());
+    } else if let Some(value) = value.downcast_ref::<String>() {
+        hasher.write(value.as_bytes());
+    } else if let Some(hash) = value.reflect_hash() {
+        hasher.write_u64(hash);
+    } else {
+        hasher.write(value.type_name().as_bytes());
+    }
+}
+
+/// Records the input events of each frame into the [`InputRecording`] resource.
+///
+/// Inputs are recorded in [`CoreStage::PreUpdate`], after the systems labeled
+/// [`InputBackendSystem`] sent their events, and before the [`InputSystem`]s process them. The
+/// [`Time`] delta of each frame is recorded too, as well as the checksum of the components
+/// selected with `checksum`, so that a replay can be verified.
+#[derive(Clone, Default)]
+pub struct InputRecordingPlugin {
+    pub checksum: InputChecksum,
+}
+
+impl Plugin for InputRecordingPlugin {
+    fn build(&self, app: &mut App) {
+        // The `WindowPlugin` adds this event too, but it isn't needed for headless apps
+        app.add_event::<ReceivedCharacter>()
+            .init_resource::<InputRecording>()
+            .insert_resource(self.checksum.clone())
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                record_inputs.after(InputBackendSystem).before(InputSystem),
+            )
+            .add_system_to_stage(CoreStage::Last, record_checksum.exclusive_system().at_end());
+    }
+
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        vec![
+            PluginDependency::required::<TimePlugin>(),
+            PluginDependency::required::<InputPlugin>(),
+            PluginDependency::optional::<WindowPlugin>(),
+        ]
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn record_inputs(
+    mut recording: ResMut<InputRecording>,
+    time: Res<Time>,
+    mut keyboard: EventReader<KeyboardInput>,
+    mut mouse_buttons: EventReader<MouseButtonInput>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut touches: EventReader<TouchInput>,
+    mut gamepads: EventReader<GamepadEventRaw>,
+    mut characters: EventReader<ReceivedCharacter>,
+) {
+    let inputs = keyboard
+        .iter()
+        .cloned()
+        .map(RecordedInput::Keyboard)
+        .chain(
+            mouse_buttons
+                .iter()
+                .cloned()
+                .map(RecordedInput::MouseButton),
+        )
+        .chain(mouse_motion.iter().cloned().map(RecordedInput::MouseMotion))
+        .chain(mouse_wheel.iter().cloned().map(RecordedInput::MouseWheel))
+        .chain(touches.iter().cloned().map(RecordedInput::Touch))
+        .chain(gamepads.iter().cloned().map(RecordedInput::Gamepad))
+        .chain(characters.iter().cloned().map(RecordedInput::Character))
+        .collect();
+
+    let frame = recording.frames.len() as u32;
+    recording.frames.push(RecordedFrame {
+        frame,
+        delta: time.delta(),
+        inputs,
+        checksum: None,
+    });
+}
+
+fn record_checksum(world: &mut World) {
+    let checksum = world.resource::<InputChecksum>().clone().compute(world);
+    if let Some(frame) = world.resource_mut::<InputRecording>().frames.last_mut() {
+        frame.checksum = checksum;
+    }
+}
+
+/// The state of the replay of an [`InputRecording`] by the [`InputReplayPlugin`].
+pub struct InputReplay {
+    recording: InputRecording,
+    next_frame: usize,
+    verify_checksums: bool,
+}
+
+impl InputReplay {
+    /// Returns `true` once all the recorded frames have been replayed.
+    pub fn is_finished(&self) -> bool {
+        self.next_frame >= self.recording.frames.len()
+    }
+
+    /// Returns the number of frames that have been replayed.
+    pub fn replayed_frames(&self) -> usize {
+        self.next_frame.min(self.recording.frames.len())
+    }
+
+    /// Returns the recorded frame being replayed, if any.
+    fn current_frame(&self) -> Option<&RecordedFrame> {
+        self.next_frame
+            .checked_sub(1)
+            .and_then(|frame| self.recording.frames.get(frame))
+    }
+}
+
+/// Replays an [`InputRecording`], sending its input events instead of the windowing and gamepad
+/// backends, and advancing [`Time`] by the recorded deltas.
+///
+/// The inputs of each frame are sent at the start of [`CoreStage::PreUpdate`], where they were
+/// recorded, so they are processed by the [`InputSystem`]s of the same frame.
+///
+/// This is meant for headless apps, without the `WinitPlugin` and the `GilrsPlugin`. Once all the
+/// frames have been replayed, time goes back to advancing automatically.
+///
+/// When `verify_checksums` is set, the [`InputChecksum`] of each frame is compared to the
+/// recorded one, and the app panics as soon as they differ.
+#[derive(Clone)]
+pub struct InputReplayPlugin {
+    pub recording: InputRecording,
+    pub checksum: InputChecksum,
+    pub verify_checksums: bool,
+}
+
+impl InputReplayPlugin {
+    /// Replays `recording` without verifying it.
+    pub fn new(recording: InputRecording) -> Self {
+        Self {
+            recording,
+            checksum: Default::default(),
+            verify_checksums: false,
+        }
+    }
+
+    /// Replays `recording`, panicking as soon as the checksum of a frame differs from the recorded
+    /// one.
+    pub fn verified(recording: InputRecording, checksum: InputChecksum) -> Self {
+        Self {
+            recording,
+            checksum,
+            verify_checksums: true,
+        }
+    }
+}
+
+impl Plugin for InputReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ReceivedCharacter>()
+            .insert_resource(InputReplay {
+                recording: self.recording.clone(),
+                next_frame: 0,
+                verify_checksums: self.verify_checksums,
+            })
+            .insert_resource(self.checksum.clone())
+            .add_system_to_stage(
+                CoreStage::First,
+                replay_time.exclusive_system().at_start().before(TimeSystem),
+            )
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                replay_inputs.exclusive_system().at_start(),
+            )
+            .add_system_to_stage(CoreStage::Last, verify_checksum.exclusive_system().at_end());
+    }
+
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        vec![
+            PluginDependency::required::<TimePlugin>(),
+            PluginDependency::required::<InputPlugin>(),
+            PluginDependency::optional::<WindowPlugin>(),
+        ]
+    }
+}
+
+fn replay_time(world: &mut World) {
+    let mut replay = world.resource_mut::<InputReplay>();
+    let frame = replay.next_frame;
+    replay.next_frame += 1;
+    let frames = replay.recording.frames.len();
+    match replay
+        .recording
+        .frames
+        .get(frame)
+        .map(|recorded| recorded.delta)
+    {
+        Some(delta) => world.insert_resource(TimeUpdateStrategy::ManualDuration(delta)),
+        None if frame == frames => world.insert_resource(TimeUpdateStrategy::Automatic),
+        None => {}
+    }
+}
+
+fn replay_inputs(world: &mut World) {
+    let inputs = match world.resource::<InputReplay>().current_frame() {
+        Some(recorded) => recorded.inputs.clone(),
+        None => return,
+    };
+    for input in inputs {
+        input.send(world);
+    }
+}
+
+fn verify_checksum(world: &mut World) {
+    let replay = world.resource::<InputReplay>();
+    if !replay.verify_checksums {
+        return;
+    }
+    let (frame, expected) = match replay.current_frame() {
+        Some(recorded) => (recorded.frame, recorded.checksum),
+        None => return,
+    };
+    let checksum = world.resource::<InputChecksum>().clone().compute(world);
+    if let (Some(expected), Some(actual)) = (expected, checksum) {
+        if expected != actual {
+            panic!(
+                "The replay diverged from the recording on frame {}: checksum {:x} instead of {:x}",
+                frame, actual, expected
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        InputChecksum, InputRecording, InputRecordingPlugin, InputReplayPlugin, RecordedInput,
+    };
+    use bevy_app::{App, CoreStage};
+    use bevy_ecs::{
+        component::Component,
+        event::EventWriter,
+        schedule::ParallelSystemDescriptorCoercion,
+        system::{Query, Res, ResMut},
+    };
+    use bevy_input::{
+        gamepad::{
+            Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType,
+            GamepadEventRaw, GamepadEventType, GamepadInfo,
+        },
+        keyboard::KeyCode,
+        simulation::{SimulateInput, SimulatedInput},
+        Axis, Input, InputBackendSystem, InputPlugin, InputSystem,
+    };
+    use bevy_reflect::Reflect;
+    use bevy_time::{Time, TimePlugin, TimeUpdateStrategy};
+    use bevy_utils::Duration;
+
+    #[derive(Component, Reflect, Default)]
+    struct Position(f32);
+
+    fn movement(
+        time: Res<Time>,
+        keys: Res<Input<KeyCode>>,
+        gamepad_axes: Res<Axis<GamepadAxis>>,
+        mut query: Query<&mut Position>,
+    ) {
+        let stick = gamepad_axes
+            .get(GamepadAxis::new(
+                Gamepad::new(0),
+                GamepadAxisType::LeftStickX,
+            ))
+            .unwrap_or_default();
+        for mut position in query.iter_mut() {
+            if keys.pressed(KeyCode::Right) {
+                position.0 += 10.0 * time.delta_seconds();
+            }
+            position.0 += stick * time.delta_seconds();
+        }
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugin(TimePlugin)
+            .add_plugin(InputPlugin)
+            .add_system(movement);
+        app.world.spawn().insert(Position::default());
+        app
+    }
+
+    /// Gamepad events to send from [`gamepad_backend`].
+    #[derive(Default)]
+    struct GamepadBackendEvents(Vec<GamepadEventRaw>);
+
+    /// Sends gamepad events in `PreUpdate`, like the `GilrsPlugin`.
+    fn gamepad_backend(
+        mut pending: ResMut<GamepadBackendEvents>,
+        mut events: EventWriter<GamepadEventRaw>,
+    ) {
+        events.send_batch(pending.0.drain(..));
+    }
+
+    fn checksum() -> InputChecksum {
+        InputChecksum::default().with_component::<Position>()
+    }
+
+    fn record() -> (InputRecording, f32) {
+        let mut app = app();
+        app.add_plugin(InputRecordingPlugin {
+            checksum: checksum(),
+        });
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            10,
+        )));
+        app.update();
+        app.simulate_input(SimulatedInput::KeyPress(KeyCode::Right));
+        app.update();
+        app.update();
+        app.simulate_input(SimulatedInput::KeyRelease(KeyCode::Right));
+        app.update();
+
+        let position = app.world.query::<&Position>().single(&app.world).0;
+        (app.world.resource::<InputRecording>().clone(), position)
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let (recording, position) = record();
+        assert_eq!(recording.frames.len(), 4);
+        assert_eq!(recording.frames[1].inputs.len(), 1);
+        assert!(position > 0.0);
+
+        let mut app = app();
+        app.add_plugin(InputReplayPlugin::verified(recording, checksum()));
+        app.update_frames(4);
+        let replayed = app.world.query::<&Position>().single(&app.world).0;
+        assert_eq!(replayed, position);
+    }
+
+    #[test]
+    fn record_and_replay_gamepad() {
+        let gamepad = Gamepad::new(0);
+        let mut recorder = app();
+        recorder
+            .init_resource::<GamepadBackendEvents>()
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                gamepad_backend
+                    .label(InputBackendSystem)
+                    .before(InputSystem),
+            )
+            .add_plugin(InputRecordingPlugin {
+                checksum: checksum(),
+            })
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                10,
+            )));
+        recorder.world.resource_mut::<GamepadBackendEvents>().0 = vec![
+            GamepadEventRaw::new(
+                gamepad,
+                GamepadEventType::Connected(GamepadInfo {
+                    name: "Recorded".to_string(),
+                    ..Default::default()
+                }),
+            ),
+            GamepadEventRaw::new(
+                gamepad,
+                GamepadEventType::ButtonChanged(GamepadButtonType::South, 1.0),
+            ),
+            GamepadEventRaw::new(
+                gamepad,
+                GamepadEventType::AxisChanged(GamepadAxisType::LeftStickX, 1.0),
+            ),
+        ];
+        recorder.update_frames(3);
+        let position = recorder
+            .world
+            .query::<&Position>()
+            .single(&recorder.world)
+            .0;
+        let recording = recorder.world.resource::<InputRecording>().clone();
+        assert!(position > 0.0);
+        assert_eq!(recording.frames[0].inputs.len(), 3);
+        assert!(recording.frames[0]
+            .inputs
+            .iter()
+            .all(|input| matches!(input, RecordedInput::Gamepad(_))));
+
+        let mut app = app();
+        app.add_plugin(InputReplayPlugin::verified(recording, checksum()));
+        app.update();
+        assert!(app
+            .world
+            .resource::<Input<GamepadButton>>()
+            .just_pressed(GamepadButton::new(gamepad, GamepadButtonType::South)));
+        app.update_frames(2);
+        let replayed = app.world.query::<&Position>().single(&app.world).0;
+        assert_eq!(replayed, position);
+    }
+
+    #[test]
+    #[should_panic(expected = "diverged from the recording on frame 1")]
+    fn detect_divergence() {
+        let (recording, _) = record();
+
+        let mut app = app();
+        app.add_plugin(InputReplayPlugin::verified(recording, checksum()));
+        app.update();
+        // Something that wasn't recorded
+        for mut position in app.world.query::<&mut Position>().iter_mut(&mut app.world) {
+            position.0 = 100.0;
+        }
+        app.update();
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn save_and_load() {
+        let (recording, _) = record();
+        let path = std::env::temp_dir().join("bevy_window_input_recording.ron");
+        recording.save(&path).unwrap();
+        let loaded = InputRecording::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.frames.len(), recording.frames.len());
+        assert_eq!(
+            loaded.frames.last().unwrap().checksum,
+            recording.frames.last().unwrap().checksum
+        );
+    }
+}
@@ -1,32 +1,33 @@
 #[warn(missing_docs)]
 mod cursor;
 mod event;
+pub mod headless;
+pub mod input_recording;
 mod raw_window_handle;
 mod system;
+pub mod touch_emulation;
 mod window;
-mod windows;
 
 pub use crate::raw_window_handle::*;
 pub use cursor::*;
 pub use event::*;
 pub use system::*;
 pub use window::*;
-pub use windows::*;
 
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        CursorEntered, CursorIcon, CursorLeft, CursorMoved, FileDragAndDrop, ReceivedCharacter,
-        Window, WindowDescriptor, WindowMoved, Windows,
+        CursorEntered, CursorGrabMode, CursorIcon, CursorLeft, CursorMoved, FileDragAndDrop,
+        PrimaryWindow, ReceivedCharacter, Window, WindowDescriptor, WindowMoved, WindowRef,
     };
 }
 
 use bevy_app::prelude::*;
-use bevy_ecs::{event::Events, schedule::SystemLabel};
+use bevy_ecs::schedule::SystemLabel;
 
 /// A [`Plugin`] that defines an interface for windowing support in Bevy.
 pub struct WindowPlugin {
-    /// Whether to create a window when added.
+    /// Whether to spawn a [`PrimaryWindow`] when added, from the [`WindowDescriptor`] resource.
     ///
     /// Note that if there are no windows, by default the App will exit,
     /// due to [`exit_on_all_closed`].
@@ -62,7 +63,6 @@ impl Default for WindowPlugin {
 impl Plugin for WindowPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<WindowResized>()
-            .add_event::<CreateWindow>()
             .add_event::<WindowCreated>()
             .add_event::<WindowClosed>()
             .add_event::<WindowCloseRequested>()
@@ -75,8 +75,7 @@ impl Plugin for WindowPlugin {
             .add_event::<WindowScaleFactorChanged>()
             .add_event::<WindowBackendScaleFactorChanged>()
             .add_event::<FileDragAndDrop>()
-            .add_event::<WindowMoved>()
-            .init_resource::<Windows>();
+            .add_event::<WindowMoved>();
 
         if self.add_primary_window {
             let window_descriptor = app
@@ -84,11 +83,9 @@ impl Plugin for WindowPlugin {
                 .get_resource::<WindowDescriptor>()
                 .map(|descriptor| (*descriptor).clone())
                 .unwrap_or_default();
-            let mut create_window_event = app.world.resource_mut::<Events<CreateWindow>>();
-            create_window_event.send(CreateWindow {
-                id: WindowId::primary(),
-                descriptor: window_descriptor,
-            });
+            app.world
+                .spawn()
+                .insert_bundle((Window::new(&window_descriptor), PrimaryWindow));
         }
 
         if self.exit_on_all_closed {
@@ -102,3 +99,31 @@ impl Plugin for WindowPlugin {
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
 pub struct ModifiesWindows;
+
+/// The windowing backend opening the [`Window`]s of an [`App`], like `bevy_winit` or the
+/// [`HeadlessWindowPlugin`](headless::HeadlessWindowPlugin).
+///
+/// Each backend plugin inserts it with [`WindowBackend::insert`], so that two backends can't
+/// open the same windows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowBackend {
+    /// The name of the backend plugin.
+    pub name: &'static str,
+}
+
+impl WindowBackend {
+    /// Makes the plugin `name` the windowing backend of `app`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another windowing backend has already been added.
+    pub fn insert(app: &mut App, name: &'static str) {
+        if let Some(backend) = app.world.get_resource::<WindowBackend>() {
+            panic!(
+                "{} can't be added, the windows are already opened by {}",
+                name, backend.name
+            );
+        }
+        app.insert_resource(WindowBackend { name });
+    }
+}
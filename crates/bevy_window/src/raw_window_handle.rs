@@ -1,3 +1,4 @@
+use bevy_ecs::component::Component;
 use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
 
 /// A wrapper over [`RawWindowHandle`] that allows us to safely pass it across threads.
@@ -5,11 +6,15 @@ use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
 /// Depending on the platform, the underlying pointer-containing handle cannot be used on all threads,
 /// and so we cannot simply make it (or any type that has a safe operation to get a [`RawWindowHandle`])
 /// thread-safe.
-#[derive(Debug, Clone)]
+///
+/// Windowing backends insert it as a component on [`Window`](crate::Window) entities once the
+/// window is created.
+#[derive(Component, Debug, Clone)]
 pub struct RawWindowHandleWrapper(RawWindowHandle);
 
 impl RawWindowHandleWrapper {
-    pub(crate) fn new(handle: RawWindowHandle) -> Self {
+    /// Wraps the handle of a window created by a windowing backend.
+    pub fn new(handle: RawWindowHandle) -> Self {
         Self(handle)
     }
 
@@ -1,4 +1,4 @@
-use crate::{Window, WindowCloseRequested, WindowFocused, WindowId, Windows};
+use crate::{Window, WindowCloseRequested, WindowFocused};
 
 use bevy_app::AppExit;
 use bevy_ecs::prelude::*;
@@ -11,8 +11,8 @@ use bevy_input::{keyboard::KeyCode, Input};
 /// Ensure that you read the caveats documented on that field if doing so.
 ///
 /// [`WindowPlugin`]: crate::WindowPlugin
-pub fn exit_on_all_closed(mut app_exit_events: EventWriter<AppExit>, windows: Res<Windows>) {
-    if windows.iter().count() == 0 {
+pub fn exit_on_all_closed(mut app_exit_events: EventWriter<AppExit>, windows: Query<&Window>) {
+    if windows.is_empty() {
         app_exit_events.send(AppExit);
     }
 }
@@ -25,11 +25,14 @@ pub fn exit_on_all_closed(mut app_exit_events: EventWriter<AppExit>, windows: Re
 ///
 /// [`WindowPlugin`]: crate::WindowPlugin
 pub fn close_when_requested(
-    mut windows: ResMut<Windows>,
+    mut commands: Commands,
+    windows: Query<(), With<Window>>,
     mut closed: EventReader<WindowCloseRequested>,
 ) {
     for event in closed.iter() {
-        windows.get_mut(event.id).map(Window::close);
+        if windows.contains(event.window) {
+            commands.entity(event.window).despawn();
+        }
     }
 }
 
@@ -37,21 +40,54 @@ pub fn close_when_requested(
 ///
 /// This is useful for examples or prototyping.
 pub fn close_on_esc(
-    mut focused: Local<Option<WindowId>>,
+    mut commands: Commands,
+    mut focused: Local<Option<Entity>>,
     mut focused_events: EventReader<WindowFocused>,
-    mut windows: ResMut<Windows>,
+    windows: Query<(), With<Window>>,
     input: Res<Input<KeyCode>>,
 ) {
     // TODO: Track this in e.g. a resource to ensure consistent behaviour across similar systems
     for event in focused_events.iter() {
-        *focused = event.focused.then(|| event.id);
+        *focused = event.focused.then_some(event.window);
     }
 
-    if let Some(focused) = &*focused {
-        if input.just_pressed(KeyCode::Escape) {
-            if let Some(window) = windows.get_mut(*focused) {
-                window.close();
-            }
+    if let Some(focused) = *focused {
+        if input.just_pressed(KeyCode::Escape) && windows.contains(focused) {
+            commands.entity(focused).despawn();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{PrimaryWindow, Window, WindowCloseRequested, WindowPlugin};
+    use bevy_app::{App, AppExit};
+    use bevy_ecs::{event::Events, prelude::*};
+    use bevy_input::InputPlugin;
+
+    #[test]
+    fn close_requested_windows() {
+        let mut app = App::new();
+        app.add_plugin(InputPlugin);
+        app.add_plugin(WindowPlugin::default());
+        let primary = app
+            .world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(&app.world);
+        let other = app.world.spawn().insert(Window::default()).id();
+
+        app.world
+            .resource_mut::<Events<WindowCloseRequested>>()
+            .send(WindowCloseRequested { window: other });
+        app.update();
+        assert!(app.world.get_entity(other).is_none());
+        assert!(app.world.resource::<Events<AppExit>>().is_empty());
+
+        app.world
+            .resource_mut::<Events<WindowCloseRequested>>()
+            .send(WindowCloseRequested { window: primary });
+        app.update();
+        app.update();
+        assert!(!app.world.resource::<Events<AppExit>>().is_empty());
+    }
+}
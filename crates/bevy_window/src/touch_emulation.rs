@@ -0,0 +1,225 @@
+//! Emulation of touches with the mouse, to test touch controls and gestures on desktop.
+use crate::{CursorMoved, WindowPlugin};
+use bevy_app::{App, CoreStage, Plugin, PluginDependency};
+use bevy_ecs::{
+    event::{EventReader, EventWriter},
+    schedule::ParallelSystemDescriptorCoercion,
+    system::Local,
+};
+use bevy_input::{
+    keyboard::{KeyCode, KeyboardInput},
+    mouse::{MouseButton, MouseButtonInput},
+    touch::{TouchInput, TouchPhase},
+    Input, InputPlugin, InputSystem,
+};
+use bevy_math::Vec2;
+
+/// The id of the [`TouchInput`]s emulated with the left mouse button.
+pub const MOUSE_TOUCH_ID: u64 = u64::MAX;
+
+/// The id of the second finger emulated while a control key is held, mirroring the first one
+/// around the point where the left mouse button was pressed.
+pub const MOUSE_SECOND_TOUCH_ID: u64 = u64::MAX - 1;
+
+/// The state of the [`mouse_touch_emulation_system`].
+#[derive(Default)]
+pub struct MouseTouchState {
+    /// The cursor position of the last [`CursorMoved`] event.
+    cursor: Option<Vec2>,
+    /// The position where the left mouse button was pressed, while it is pressed.
+    pressed_at: Option<Vec2>,
+    /// Whether a second finger is emulated for the current press.
+    second_touch: bool,
+    /// The keys held according to the [`KeyboardInput`] events read so far.
+    ///
+    /// The system runs before the [`InputSystem`]s, so the [`Input<KeyCode>`] resource isn't
+    /// up to date yet.
+    keys: Input<KeyCode>,
+}
+
+impl MouseTouchState {
+    fn send(&self, phase: TouchPhase, position: Vec2, touch_events: &mut EventWriter<TouchInput>) {
+        touch_events.send(TouchInput {
+            phase,
+            position,
+            force: None,
+            id: MOUSE_TOUCH_ID,
+        });
+        if let (true, Some(pressed_at)) = (self.second_touch, self.pressed_at) {
+            touch_events.send(TouchInput {
+                phase,
+                position: 2.0 * pressed_at - position,
+                force: None,
+                id: MOUSE_SECOND_TOUCH_ID,
+            });
+        }
+    }
+}
+
+/// Sends [`TouchInput`]s emulating a finger touching the screen while the left mouse button is
+/// pressed.
+///
+/// Holding a control key while pressing the button adds a second finger, mirrored around the
+/// pressed point, to emulate pinches and rotations.
+pub fn mouse_touch_emulation_system(
+    mut state: Local<MouseTouchState>,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut cursor_moved_events: EventReader<CursorMoved>,
+    mut mouse_button_events: EventReader<MouseButtonInput>,
+    mut touch_events: EventWriter<TouchInput>,
+) {
+    for event in keyboard_events.iter() {
+        if let Some(key_code) = event.key_code {
+            if event.state.is_pressed() {
+                state.keys.press(key_code);
+            } else {
+                state.keys.release(key_code);
+            }
+        }
+    }
+
+    for event in cursor_moved_events.iter() {
+        state.cursor = Some(event.position);
+        if state.pressed_at.is_some() {
+            state.send(TouchPhase::Moved, event.position, &mut touch_events);
+        }
+    }
+
+    for event in mouse_button_events.iter() {
+        let position = match (event.button, state.cursor) {
+            (MouseButton::Left, Some(position)) => position,
+            _ => continue,
+        };
+        if event.state.is_pressed() && state.pressed_at.is_none() {
+            state.pressed_at = Some(position);
+            state.second_touch = state
+                .keys
+                .any_pressed([KeyCode::LControl, KeyCode::RControl]);
+            state.send(TouchPhase::Started, position, &mut touch_events);
+        } else if !event.state.is_pressed() && state.pressed_at.is_some() {
+            state.send(TouchPhase::Ended, position, &mut touch_events);
+            state.pressed_at = None;
+        }
+    }
+}
+
+/// Emulates touches with the mouse, see [`mouse_touch_emulation_system`].
+///
+/// Touch positions are taken from the cursor, so they are in window coordinates, with the origin
+/// at the bottom left of the window.
+#[derive(Default)]
+pub struct MouseTouchEmulationPlugin;
+
+impl Plugin for MouseTouchEmulationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PreUpdate,
+            mouse_touch_emulation_system.before(InputSystem),
+        );
+    }
+
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        vec![
+            PluginDependency::required::<InputPlugin>(),
+            PluginDependency::required::<WindowPlugin>(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MouseTouchEmulationPlugin, MOUSE_SECOND_TOUCH_ID, MOUSE_TOUCH_ID};
+    use crate::{CursorMoved, WindowPlugin};
+    use bevy_app::App;
+    use bevy_ecs::{entity::Entity, event::Events};
+    use bevy_input::{
+        gesture::{GestureEvent, GesturePlugin},
+        keyboard::{KeyCode, KeyboardInput, PhysicalKey},
+        mouse::{MouseButton, MouseButtonInput},
+        touch::Touches,
+        ButtonState, InputPlugin,
+    };
+    use bevy_math::Vec2;
+    use bevy_time::TimePlugin;
+
+    fn move_cursor(app: &mut App, position: Vec2) {
+        app.world
+            .resource_mut::<Events<CursorMoved>>()
+            .send(CursorMoved {
+                window: Entity::from_raw(0),
+                position,
+            });
+    }
+
+    fn left_button(app: &mut App, state: ButtonState) {
+        app.world
+            .resource_mut::<Events<MouseButtonInput>>()
+            .send(MouseButtonInput {
+                button: MouseButton::Left,
+                state,
+            });
+    }
+
+    #[test]
+    fn mouse_touches() {
+        let mut app = App::new();
+        app.add_plugin(TimePlugin)
+            .add_plugin(InputPlugin)
+            .add_plugin(WindowPlugin {
+                add_primary_window: false,
+                exit_on_all_closed: false,
+                ..Default::default()
+            })
+            .add_plugin(GesturePlugin)
+            .add_plugin(MouseTouchEmulationPlugin);
+
+        move_cursor(&mut app, Vec2::new(100.0, 100.0));
+        left_button(&mut app, ButtonState::Pressed);
+        app.update();
+        let touches = app.world.resource::<Touches>();
+        assert!(touches.just_pressed(MOUSE_TOUCH_ID));
+        assert!(touches.get_pressed(MOUSE_SECOND_TOUCH_ID).is_none());
+
+        left_button(&mut app, ButtonState::Released);
+        app.update();
+        let gestures: Vec<GestureEvent> = app
+            .world
+            .resource_mut::<Events<GestureEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            gestures,
+            vec![GestureEvent::Tap {
+                position: Vec2::new(100.0, 100.0)
+            }]
+        );
+
+        // Pinch with a control key pressed in the same frame
+        app.world
+            .resource_mut::<Events<KeyboardInput>>()
+            .send(KeyboardInput {
+                scan_code: 0,
+                key_code: Some(KeyCode::LControl),
+                physical_key: PhysicalKey::ControlLeft,
+                logical_key: KeyCode::LControl.into(),
+                state: ButtonState::Pressed,
+            });
+        move_cursor(&mut app, Vec2::new(50.0, 50.0));
+        left_button(&mut app, ButtonState::Pressed);
+        app.update();
+        move_cursor(&mut app, Vec2::new(60.0, 50.0));
+        app.update();
+        let touches = app.world.resource::<Touches>();
+        assert_eq!(
+            touches.get_pressed(MOUSE_TOUCH_ID).unwrap().position(),
+            Vec2::new(60.0, 50.0)
+        );
+        assert_eq!(
+            touches
+                .get_pressed(MOUSE_SECOND_TOUCH_ID)
+                .unwrap()
+                .position(),
+            Vec2::new(40.0, 50.0)
+        );
+    }
+}
@@ -1,10 +1,37 @@
+use bevy_ecs::{component::Component, entity::Entity};
 use bevy_math::{DVec2, IVec2, Vec2};
-use bevy_utils::{tracing::warn, Uuid};
-use raw_window_handle::RawWindowHandle;
+use bevy_utils::tracing::warn;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
-/// A unique ID for a [`Window`].
-pub struct WindowId(Uuid);
+/// Marker component for the primary [`Window`], created by the [`WindowPlugin`](crate::WindowPlugin).
+///
+/// There should be at most one primary window.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PrimaryWindow;
+
+/// A reference to a [`Window`], used to target a window before knowing its entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum WindowRef {
+    /// The window marked with [`PrimaryWindow`].
+    Primary,
+    /// The window of the given entity.
+    Entity(Entity),
+}
+
+impl Default for WindowRef {
+    fn default() -> Self {
+        WindowRef::Primary
+    }
+}
+
+impl WindowRef {
+    /// Returns the entity of the referenced window, given the entity of the primary window.
+    pub fn normalize(&self, primary_window: Option<Entity>) -> Option<Entity> {
+        match self {
+            WindowRef::Primary => primary_window,
+            WindowRef::Entity(entity) => Some(*entity),
+        }
+    }
+}
 
 /// Presentation mode for a window.
 ///
@@ -39,36 +66,18 @@ pub enum PresentMode {
     Fifo = 2, // NOTE: The explicit ordinal values mirror wgpu and the vulkan spec.
 }
 
-impl WindowId {
-    /// Creates a new [`WindowId`].
-    pub fn new() -> Self {
-        WindowId(Uuid::new_v4())
-    }
-    /// The [`WindowId`] for the primary window.
-    pub fn primary() -> Self {
-        WindowId(Uuid::from_u128(0))
-    }
-    /// Get whether or not this [`WindowId`] is for the primary window.
-    pub fn is_primary(&self) -> bool {
-        *self == WindowId::primary()
-    }
-}
-
-use crate::CursorIcon;
-use std::fmt;
-
-use crate::raw_window_handle::RawWindowHandleWrapper;
-
-impl fmt::Display for WindowId {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.as_simple().fmt(f)
-    }
-}
-
-impl Default for WindowId {
-    fn default() -> Self {
-        WindowId::primary()
-    }
+use crate::{CursorGrabMode, CursorIcon};
+use bevy_asset::{HandleId, HandleUntyped};
+
+/// The pixels of a window icon, see [`Window::icon_image`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowIcon {
+    /// The pixels of the icon, row by row from the top left, as 8-bit sRGB RGBA values.
+    pub rgba: Vec<u8>,
+    /// The width of the icon, in pixels.
+    pub width: u32,
+    /// The height of the icon, in pixels.
+    pub height: u32,
 }
 
 /// The size limits on a window.
@@ -78,7 +87,7 @@ impl Default for WindowId {
 /// Please note that if the window is resizable, then when the window is
 /// maximized it may have a size outside of these limits. The functionality
 /// required to disable maximizing is not yet exposed by winit.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct WindowResizeConstraints {
     pub min_width: f32,
     pub min_height: f32,
@@ -133,7 +142,9 @@ impl WindowResizeConstraints {
 
 /// An operating system window that can present content and receive user input.
 ///
-/// To create a window, use a [`EventWriter<CreateWindow>`](`crate::CreateWindow`).
+/// Windows are entities with a `Window` component: spawning one creates a window, and despawning
+/// it closes the window. The windowing backend applies changes to the component to the operating
+/// system window, and updates it with the state reported by the operating system.
 ///
 /// ## Window Sizes
 ///
@@ -151,121 +162,61 @@ impl WindowResizeConstraints {
 ///
 /// ## Accessing a `Window` from a system
 ///
-/// To access a `Window` from a system, use [`bevy_ecs::change_detection::ResMut`]`<`[`crate::Windows`]`>`.
+/// To access a `Window` from a system, use a [`Query`](bevy_ecs::system::Query), filtered with
+/// [`PrimaryWindow`] to only get the primary window.
 ///
 /// ### Example
 /// ```no_run
 /// # use bevy_app::App;
-/// # use bevy_window::Windows;
-/// # use bevy_ecs::change_detection::ResMut;
+/// # use bevy_window::{PrimaryWindow, Window};
+/// # use bevy_ecs::prelude::*;
 /// # fn main(){
 /// # App::new().add_system(access_window_system).run();
 /// # }
-/// fn access_window_system(mut windows: ResMut<Windows>){
+/// fn access_window_system(mut windows: Query<&mut Window, With<PrimaryWindow>>){
 ///     for mut window in windows.iter_mut(){
 ///         window.set_title(String::from("Yay, I'm a window!"));
 ///     }
 /// }
 /// ```
-#[derive(Debug)]
+#[derive(Component, Debug, Clone)]
 pub struct Window {
-    id: WindowId,
     requested_width: f32,
     requested_height: f32,
     physical_width: u32,
     physical_height: u32,
     resize_constraints: WindowResizeConstraints,
     position: Option<IVec2>,
+    initial_position: Option<Vec2>,
+    position_request: Option<IVec2>,
     scale_factor_override: Option<f64>,
     backend_scale_factor: f64,
     title: String,
     present_mode: PresentMode,
     resizable: bool,
     decorations: bool,
+    transparent: bool,
+    icon: Option<HandleUntyped>,
+    /// The pixels of the image with the given id, if it is loaded.
+    icon_image: Option<(HandleId, Option<WindowIcon>)>,
     cursor_icon: CursorIcon,
     cursor_visible: bool,
-    cursor_locked: bool,
+    cursor_grab_mode: CursorGrabMode,
     physical_cursor_position: Option<DVec2>,
-    raw_window_handle: RawWindowHandleWrapper,
+    cursor_position_request: Option<Vec2>,
     focused: bool,
     mode: WindowMode,
+    maximize_request: Option<bool>,
+    minimize_request: Option<bool>,
     canvas: Option<String>,
     fit_canvas_to_parent: bool,
-    command_queue: Vec<WindowCommand>,
-}
-/// A command to be sent to a window.
-///
-/// Bevy apps don't interact with this `enum` directly. Instead, they should use the methods on [`Window`].
-/// This `enum` is meant for authors of windowing plugins. See the documentation on [`crate::WindowPlugin`] for more information.
-#[derive(Debug)]
-pub enum WindowCommand {
-    /// Set the window's [`WindowMode`].
-    SetWindowMode {
-        mode: WindowMode,
-        resolution: (u32, u32),
-    },
-    /// Set the window's title.
-    SetTitle {
-        title: String,
-    },
-    /// Set the window's scale factor.
-    SetScaleFactor {
-        scale_factor: f64,
-    },
-    /// Set the window's resolution.
-    SetResolution {
-        logical_resolution: (f32, f32),
-        scale_factor: f64,
-    },
-    /// Set the window's [`PresentMode`].
-    SetPresentMode {
-        present_mode: PresentMode,
-    },
-    /// Set whether or not the window is resizable.
-    SetResizable {
-        resizable: bool,
-    },
-    /// Set whether or not the window has decorations.
-    ///
-    /// Examples of decorations include the close, full screen, and minimize buttons
-    SetDecorations {
-        decorations: bool,
-    },
-    /// Set whether or not the cursor's postition is locked.
-    SetCursorLockMode {
-        locked: bool,
-    },
-    /// Set the cursor's [`CursorIcon`].
-    SetCursorIcon {
-        icon: CursorIcon,
-    },
-    /// Set whether or not the cursor is visible.
-    SetCursorVisibility {
-        visible: bool,
-    },
-    /// Set the cursor's position.
-    SetCursorPosition {
-        position: Vec2,
-    },
-    /// Set whether or not the window is maxizimed.
-    SetMaximized {
-        maximized: bool,
-    },
-    /// Set whether or not the window is minimized.
-    SetMinimized {
-        minimized: bool,
-    },
-    /// Set the window's position on the screen.
-    SetPosition {
-        position: IVec2,
-    },
-    /// Set the window's [`WindowResizeConstraints`]
-    SetResizeConstraints {
-        resize_constraints: WindowResizeConstraints,
-    },
-    Close,
 }
 
+impl Default for Window {
+    fn default() -> Self {
+        Window::new(&WindowDescriptor::default())
+    }
+}
 /// Defines the way a window is displayed.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum WindowMode {
@@ -282,47 +233,43 @@ pub enum WindowMode {
 }
 
 impl Window {
-    /// Creates a new [`Window`].
-    pub fn new(
-        id: WindowId,
-        window_descriptor: &WindowDescriptor,
-        physical_width: u32,
-        physical_height: u32,
-        scale_factor: f64,
-        position: Option<IVec2>,
-        raw_window_handle: RawWindowHandle,
-    ) -> Self {
+    /// Creates a new [`Window`] from a [`WindowDescriptor`], to be spawned.
+    ///
+    /// Until the windowing backend creates the window, its physical size assumes a backend scale
+    /// factor of `1.0`.
+    pub fn new(window_descriptor: &WindowDescriptor) -> Self {
+        let scale_factor = window_descriptor.scale_factor_override.unwrap_or(1.0);
         Window {
-            id,
             requested_width: window_descriptor.width,
             requested_height: window_descriptor.height,
-            position,
-            physical_width,
-            physical_height,
+            position: None,
+            initial_position: window_descriptor.position,
+            position_request: None,
+            physical_width: (window_descriptor.width as f64 * scale_factor) as u32,
+            physical_height: (window_descriptor.height as f64 * scale_factor) as u32,
             resize_constraints: window_descriptor.resize_constraints,
             scale_factor_override: window_descriptor.scale_factor_override,
-            backend_scale_factor: scale_factor,
+            backend_scale_factor: 1.0,
             title: window_descriptor.title.clone(),
             present_mode: window_descriptor.present_mode,
             resizable: window_descriptor.resizable,
             decorations: window_descriptor.decorations,
+            transparent: window_descriptor.transparent,
+            icon: window_descriptor.icon.clone(),
+            icon_image: None,
             cursor_visible: window_descriptor.cursor_visible,
-            cursor_locked: window_descriptor.cursor_locked,
+            cursor_grab_mode: window_descriptor.cursor_grab_mode,
             cursor_icon: CursorIcon::Default,
             physical_cursor_position: None,
-            raw_window_handle: RawWindowHandleWrapper::new(raw_window_handle),
+            cursor_position_request: None,
             focused: true,
             mode: window_descriptor.mode,
+            maximize_request: None,
+            minimize_request: None,
             canvas: window_descriptor.canvas.clone(),
             fit_canvas_to_parent: window_descriptor.fit_canvas_to_parent,
-            command_queue: Vec::new(),
         }
     }
-    /// Get the window's [`WindowId`].
-    #[inline]
-    pub fn id(&self) -> WindowId {
-        self.id
-    }
 
     /// The current logical width of the window's client area.
     #[inline]
@@ -379,11 +326,25 @@ impl Window {
     pub fn position(&self) -> Option<IVec2> {
         self.position
     }
+
+    /// The logical position the window was requested to be created at, see
+    /// [`WindowDescriptor::position`].
+    #[inline]
+    pub fn initial_position(&self) -> Option<Vec2> {
+        self.initial_position
+    }
+
     /// Set whether or not the window is maximized.
     #[inline]
     pub fn set_maximized(&mut self, maximized: bool) {
-        self.command_queue
-            .push(WindowCommand::SetMaximized { maximized });
+        self.maximize_request = Some(maximized);
+    }
+
+    /// Takes the request made with [`set_maximized`](Window::set_maximized), for windowing
+    /// backends.
+    #[inline]
+    pub fn take_maximize_request(&mut self) -> Option<bool> {
+        self.maximize_request.take()
     }
 
     /// Sets the window to minimized or back.
@@ -393,8 +354,27 @@ impl Window {
     /// - Wayland: Un-minimize is unsupported.
     #[inline]
     pub fn set_minimized(&mut self, minimized: bool) {
-        self.command_queue
-            .push(WindowCommand::SetMinimized { minimized });
+        self.minimize_request = Some(minimized);
+    }
+
+    /// Takes the request made with [`set_minimized`](Window::set_minimized), for windowing
+    /// backends.
+    #[inline]
+    pub fn take_minimize_request(&mut self) -> Option<bool> {
+        self.minimize_request.take()
+    }
+
+    /// Whether a maximize, minimize, position or cursor position request is waiting to be taken by the
+    /// windowing backend.
+    ///
+    /// Taking a request changes the window, so backends should check this first to avoid
+    /// changing it on every frame.
+    #[inline]
+    pub fn has_pending_requests(&self) -> bool {
+        self.maximize_request.is_some()
+            || self.minimize_request.is_some()
+            || self.position_request.is_some()
+            || self.cursor_position_request.is_some()
     }
 
     /// Modifies the position of the window in physical pixels.
@@ -412,15 +392,20 @@ impl Window {
     /// - Android / Wayland: Unsupported.
     #[inline]
     pub fn set_position(&mut self, position: IVec2) {
-        self.command_queue
-            .push(WindowCommand::SetPosition { position });
+        self.position_request = Some(position);
+    }
+
+    /// Takes the request made with [`set_position`](Window::set_position), for windowing
+    /// backends.
+    #[inline]
+    pub fn take_position_request(&mut self) -> Option<IVec2> {
+        self.position_request.take()
     }
 
     /// Modifies the minimum and maximum window bounds for resizing in logical pixels.
     #[inline]
     pub fn set_resize_constraints(&mut self, resize_constraints: WindowResizeConstraints) {
-        self.command_queue
-            .push(WindowCommand::SetResizeConstraints { resize_constraints });
+        self.resize_constraints = resize_constraints;
     }
 
     /// Request the OS to resize the window such the the client area matches the
@@ -433,10 +418,6 @@ impl Window {
 
         self.requested_width = width;
         self.requested_height = height;
-        self.command_queue.push(WindowCommand::SetResolution {
-            logical_resolution: (self.requested_width, self.requested_height),
-            scale_factor: self.scale_factor(),
-        });
     }
 
     /// Override the os-reported scaling factor.
@@ -447,13 +428,6 @@ impl Window {
         }
 
         self.scale_factor_override = scale_factor;
-        self.command_queue.push(WindowCommand::SetScaleFactor {
-            scale_factor: self.scale_factor(),
-        });
-        self.command_queue.push(WindowCommand::SetResolution {
-            logical_resolution: (self.requested_width, self.requested_height),
-            scale_factor: self.scale_factor(),
-        });
     }
 
     #[allow(missing_docs)]
@@ -504,8 +478,7 @@ impl Window {
     }
     /// Set the window's title.
     pub fn set_title(&mut self, title: String) {
-        self.title = title.to_string();
-        self.command_queue.push(WindowCommand::SetTitle { title });
+        self.title = title;
     }
 
     #[inline]
@@ -520,8 +493,6 @@ impl Window {
     /// Set the window's [`PresentMode`].
     pub fn set_present_mode(&mut self, present_mode: PresentMode) {
         self.present_mode = present_mode;
-        self.command_queue
-            .push(WindowCommand::SetPresentMode { present_mode });
     }
     /// Get whether or not the window is resizable.
     #[inline]
@@ -531,8 +502,6 @@ impl Window {
     /// Set whether or not the window is resizable.
     pub fn set_resizable(&mut self, resizable: bool) {
         self.resizable = resizable;
-        self.command_queue
-            .push(WindowCommand::SetResizable { resizable });
     }
     /// Get whether or not decorations are enabled.
     ///
@@ -554,37 +523,63 @@ impl Window {
     /// **`iOS`**, **`Android`**, and the **`Web`** do not have decorations.
     pub fn set_decorations(&mut self, decorations: bool) {
         self.decorations = decorations;
-        self.command_queue
-            .push(WindowCommand::SetDecorations { decorations });
     }
-    /// Get whether or not the cursor is locked.
+    /// Get the handle to the window's icon, if it isn't the platform default.
+    #[inline]
+    pub fn icon(&self) -> Option<&HandleUntyped> {
+        self.icon.as_ref()
+    }
+    /// Set the window's icon, shown in the title bar and task bar, from a handle to an `Image`
+    /// from `bevy_render`. `None` resets it to the platform default.
+    ///
+    /// The icon is shown once the image is loaded, and updated when the image is modified.
     ///
     /// ## Platform-specific
     ///
-    /// - **`macOS`** doesn't support cursor lock, but most windowing plugins can emulate it. See [issue #4875](https://github.com/bevyengine/bevy/issues/4875#issuecomment-1153977546) for more information.
-    /// - **`iOS/Android`** don't have cursors.
+    /// - **`macOS`**, **`iOS`**, **`Android`** and the **`Web`** don't support window icons.
+    pub fn set_icon(&mut self, icon: Option<HandleUntyped>) {
+        self.icon = icon;
+    }
+    /// Get the pixels of the window's [icon](Window::icon), once its image is loaded.
+    #[inline]
+    pub fn icon_image(&self) -> Option<&WindowIcon> {
+        match (&self.icon, &self.icon_image) {
+            (Some(icon), Some((id, image))) if icon.id == *id => image.as_ref(),
+            _ => None,
+        }
+    }
+    /// Returns the id of the image the [`icon_image`](Window::icon_image) was read from, if any.
+    #[inline]
+    pub fn icon_image_id(&self) -> Option<HandleId> {
+        self.icon_image.as_ref().map(|(id, _)| *id)
+    }
+    /// Sets the pixels of the image with the given id, for the renderer.
+    ///
+    /// `image` is `None` while the image isn't loaded, or if it can't be used as an icon.
+    #[inline]
+    pub fn update_icon_image_from_renderer(&mut self, id: HandleId, image: Option<WindowIcon>) {
+        self.icon_image = Some((id, image));
+    }
+    /// Get the cursor's [`CursorGrabMode`].
     #[inline]
-    pub fn cursor_locked(&self) -> bool {
-        self.cursor_locked
+    pub fn cursor_grab_mode(&self) -> CursorGrabMode {
+        self.cursor_grab_mode
     }
-    /// Set whether or not the cursor is locked.
+    /// Set the cursor's [`CursorGrabMode`].
     ///
     /// This doesn't hide the cursor. For that, use [`set_cursor_visibility`](Window::set_cursor_visibility)
     ///
     /// ## Platform-specific
     ///
-    /// - **`macOS`** doesn't support cursor lock, but most windowing plugins can emulate it. See [issue #4875](https://github.com/bevyengine/bevy/issues/4875#issuecomment-1153977546) for more information.
-    /// - **`iOS/Android`** don't have cursors.
-    pub fn set_cursor_lock_mode(&mut self, lock_mode: bool) {
-        self.cursor_locked = lock_mode;
-        self.command_queue
-            .push(WindowCommand::SetCursorLockMode { locked: lock_mode });
+    /// See [`CursorGrabMode`] for the modes supported by each platform.
+    pub fn set_cursor_grab_mode(&mut self, grab_mode: CursorGrabMode) {
+        self.cursor_grab_mode = grab_mode;
     }
     /// Get whether or not the cursor is visible.
     ///
     /// ## Platform-specific
     ///
-    /// - **`Windows`**, **`X11`**, and **`Wayland`**: The cursor is hidden only when inside the window. To stop the cursor from leaving the window, use [`set_cursor_lock_mode`](Window::set_cursor_lock_mode).
+    /// - **`Windows`**, **`X11`**, and **`Wayland`**: The cursor is hidden only when inside the window. To stop the cursor from leaving the window, use [`set_cursor_grab_mode`](Window::set_cursor_grab_mode).
     /// - **`macOS`**: The cursor is hidden only when the window is focused.
     /// - **`iOS`** and **`Android`** do not have cursors
     #[inline]
@@ -595,14 +590,11 @@ impl Window {
     ///
     /// ## Platform-specific
     ///
-    /// - **`Windows`**, **`X11`**, and **`Wayland`**: The cursor is hidden only when inside the window. To stop the cursor from leaving the window, use [`set_cursor_lock_mode`](Window::set_cursor_lock_mode).
+    /// - **`Windows`**, **`X11`**, and **`Wayland`**: The cursor is hidden only when inside the window. To stop the cursor from leaving the window, use [`set_cursor_grab_mode`](Window::set_cursor_grab_mode).
     /// - **`macOS`**: The cursor is hidden only when the window is focused.
     /// - **`iOS`** and **`Android`** do not have cursors
     pub fn set_cursor_visibility(&mut self, visibile_mode: bool) {
         self.cursor_visible = visibile_mode;
-        self.command_queue.push(WindowCommand::SetCursorVisibility {
-            visible: visibile_mode,
-        });
     }
     /// Get the current [`CursorIcon`]
     #[inline]
@@ -611,8 +603,7 @@ impl Window {
     }
     /// Set the [`CursorIcon`]
     pub fn set_cursor_icon(&mut self, icon: CursorIcon) {
-        self.command_queue
-            .push(WindowCommand::SetCursorIcon { icon });
+        self.cursor_icon = icon;
     }
 
     /// The current mouse position, in physical pixels.
@@ -630,8 +621,14 @@ impl Window {
     }
     /// Set the cursor's position
     pub fn set_cursor_position(&mut self, position: Vec2) {
-        self.command_queue
-            .push(WindowCommand::SetCursorPosition { position });
+        self.cursor_position_request = Some(position);
+    }
+
+    /// Takes the request made with [`set_cursor_position`](Window::set_cursor_position), for
+    /// windowing backends.
+    #[inline]
+    pub fn take_cursor_position_request(&mut self) -> Option<Vec2> {
+        self.cursor_position_request.take()
     }
 
     #[allow(missing_docs)]
@@ -653,29 +650,6 @@ impl Window {
     /// Set the window's [`WindowMode`]
     pub fn set_mode(&mut self, mode: WindowMode) {
         self.mode = mode;
-        self.command_queue.push(WindowCommand::SetWindowMode {
-            mode,
-            resolution: (self.physical_width, self.physical_height),
-        });
-    }
-    /// Close the operating system window corresponding to this [`Window`].
-    ///  
-    /// This will also lead to this [`Window`] being removed from the
-    /// [`Windows`] resource.
-    ///
-    /// If the default [`WindowPlugin`] is used, when no windows are
-    /// open, the [app will exit](bevy_app::AppExit).  
-    /// To disable this behaviour, set `exit_on_all_closed` on the [`WindowPlugin`]
-    /// to `false`
-    ///
-    /// [`Windows`]: crate::Windows
-    /// [`WindowPlugin`]: crate::WindowPlugin
-    pub fn close(&mut self) {
-        self.command_queue.push(WindowCommand::Close);
-    }
-    #[inline]
-    pub fn drain_commands(&mut self) -> impl Iterator<Item = WindowCommand> + '_ {
-        self.command_queue.drain(..)
     }
     /// Get whether or not the window has focus.
     ///
@@ -684,9 +658,11 @@ impl Window {
     pub fn is_focused(&self) -> bool {
         self.focused
     }
-    /// Get the [`RawWindowHandleWrapper`] corresponding to this window
-    pub fn raw_window_handle(&self) -> RawWindowHandleWrapper {
-        self.raw_window_handle.clone()
+    /// Get whether or not the background of the window is transparent, see
+    /// [`WindowDescriptor::transparent`].
+    #[inline]
+    pub fn transparent(&self) -> bool {
+        self.transparent
     }
 
     /// The "html canvas" element selector.
@@ -716,8 +692,10 @@ impl Window {
 
 /// Describes the information needed for creating a window.
 ///
-/// This should be set up before adding the [`WindowPlugin`](crate::WindowPlugin).
-/// Most of these settings can also later be configured through the [`Window`](crate::Window) resource.
+/// The primary window is created from the `WindowDescriptor` resource, which should be set up
+/// before adding the [`WindowPlugin`](crate::WindowPlugin). Other windows are spawned with
+/// [`Window::new`]. Most of these settings can also later be configured through the
+/// [`Window`](crate::Window) component.
 ///
 /// See [`examples/window/window_settings.rs`] for usage.
 ///
@@ -760,10 +738,12 @@ pub struct WindowDescriptor {
     pub resizable: bool,
     /// Sets whether the window should have borders and bars.
     pub decorations: bool,
+    /// Sets the window's icon, see [`Window::set_icon`].
+    pub icon: Option<HandleUntyped>,
     /// Sets whether the cursor is visible when the window has focus.
     pub cursor_visible: bool,
-    /// Sets whether the window locks the cursor inside its borders when the window has focus.
-    pub cursor_locked: bool,
+    /// Sets whether and how the window grabs the cursor when the window has focus.
+    pub cursor_grab_mode: CursorGrabMode,
     /// Sets the [`WindowMode`](crate::WindowMode).
     pub mode: WindowMode,
     /// Sets whether the background of the window should be transparent.
@@ -805,7 +785,8 @@ impl Default for WindowDescriptor {
             present_mode: PresentMode::Fifo,
             resizable: true,
             decorations: true,
-            cursor_locked: false,
+            icon: None,
+            cursor_grab_mode: CursorGrabMode::None,
             cursor_visible: true,
             mode: WindowMode::Windowed,
             transparent: false,
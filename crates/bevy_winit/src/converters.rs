@@ -1,20 +1,506 @@
 use bevy_input::{
-    keyboard::{KeyCode, KeyboardInput},
+    keyboard::{KeyCode, KeyboardInput, LogicalKey, PhysicalKey},
     mouse::MouseButton,
     touch::{ForceTouch, TouchInput, TouchPhase},
     ButtonState,
 };
 use bevy_math::Vec2;
-use bevy_window::CursorIcon;
+use bevy_window::{CursorIcon, WindowIcon};
 
+/// Converts a `winit` keyboard input.
+///
+/// The logical key is derived from the key code, as `winit` reports the text produced by the key
+/// in a separate event, see [`KeyboardState`](crate::keyboard::KeyboardState).
 pub fn convert_keyboard_input(keyboard_input: &winit::event::KeyboardInput) -> KeyboardInput {
+    let key_code = keyboard_input.virtual_keycode.map(convert_virtual_key_code);
+    let physical_key = match convert_scan_code_to_physical_key(keyboard_input.scancode) {
+        // Fall back to the position of the key on a QWERTY keyboard
+        PhysicalKey::Unidentified(scan_code) => key_code
+            .and_then(convert_key_code_to_qwerty_physical_key)
+            .unwrap_or(PhysicalKey::Unidentified(scan_code)),
+        physical_key => physical_key,
+    };
     KeyboardInput {
         scan_code: keyboard_input.scancode,
         state: convert_element_state(keyboard_input.state),
-        key_code: keyboard_input.virtual_keycode.map(convert_virtual_key_code),
+        key_code,
+        physical_key,
+        logical_key: key_code.map_or(LogicalKey::Unidentified, LogicalKey::from),
     }
 }
 
+/// Returns the physical key of a key code on a US QWERTY keyboard, for platforms where scan codes
+/// can't be converted.
+fn convert_key_code_to_qwerty_physical_key(key_code: KeyCode) -> Option<PhysicalKey> {
+    Some(match key_code {
+        KeyCode::Key1 => PhysicalKey::Digit1,
+        KeyCode::Key2 => PhysicalKey::Digit2,
+        KeyCode::Key3 => PhysicalKey::Digit3,
+        KeyCode::Key4 => PhysicalKey::Digit4,
+        KeyCode::Key5 => PhysicalKey::Digit5,
+        KeyCode::Key6 => PhysicalKey::Digit6,
+        KeyCode::Key7 => PhysicalKey::Digit7,
+        KeyCode::Key8 => PhysicalKey::Digit8,
+        KeyCode::Key9 => PhysicalKey::Digit9,
+        KeyCode::Key0 => PhysicalKey::Digit0,
+        KeyCode::A => PhysicalKey::KeyA,
+        KeyCode::B => PhysicalKey::KeyB,
+        KeyCode::C => PhysicalKey::KeyC,
+        KeyCode::D => PhysicalKey::KeyD,
+        KeyCode::E => PhysicalKey::KeyE,
+        KeyCode::F => PhysicalKey::KeyF,
+        KeyCode::G => PhysicalKey::KeyG,
+        KeyCode::H => PhysicalKey::KeyH,
+        KeyCode::I => PhysicalKey::KeyI,
+        KeyCode::J => PhysicalKey::KeyJ,
+        KeyCode::K => PhysicalKey::KeyK,
+        KeyCode::L => PhysicalKey::KeyL,
+        KeyCode::M => PhysicalKey::KeyM,
+        KeyCode::N => PhysicalKey::KeyN,
+        KeyCode::O => PhysicalKey::KeyO,
+        KeyCode::P => PhysicalKey::KeyP,
+        KeyCode::Q => PhysicalKey::KeyQ,
+        KeyCode::R => PhysicalKey::KeyR,
+        KeyCode::S => PhysicalKey::KeyS,
+        KeyCode::T => PhysicalKey::KeyT,
+        KeyCode::U => PhysicalKey::KeyU,
+        KeyCode::V => PhysicalKey::KeyV,
+        KeyCode::W => PhysicalKey::KeyW,
+        KeyCode::X => PhysicalKey::KeyX,
+        KeyCode::Y => PhysicalKey::KeyY,
+        KeyCode::Z => PhysicalKey::KeyZ,
+        KeyCode::Escape => PhysicalKey::Escape,
+        KeyCode::F1 => PhysicalKey::F1,
+        KeyCode::F2 => PhysicalKey::F2,
+        KeyCode::F3 => PhysicalKey::F3,
+        KeyCode::F4 => PhysicalKey::F4,
+        KeyCode::F5 => PhysicalKey::F5,
+        KeyCode::F6 => PhysicalKey::F6,
+        KeyCode::F7 => PhysicalKey::F7,
+        KeyCode::F8 => PhysicalKey::F8,
+        KeyCode::F9 => PhysicalKey::F9,
+        KeyCode::F10 => PhysicalKey::F10,
+        KeyCode::F11 => PhysicalKey::F11,
+        KeyCode::F12 => PhysicalKey::F12,
+        KeyCode::Snapshot => PhysicalKey::PrintScreen,
+        KeyCode::Scroll => PhysicalKey::ScrollLock,
+        KeyCode::Pause => PhysicalKey::Pause,
+        KeyCode::Insert => PhysicalKey::Insert,
+        KeyCode::Home => PhysicalKey::Home,
+        KeyCode::Delete => PhysicalKey::Delete,
+        KeyCode::End => PhysicalKey::End,
+        KeyCode::PageDown => PhysicalKey::PageDown,
+        KeyCode::PageUp => PhysicalKey::PageUp,
+        KeyCode::Left => PhysicalKey::ArrowLeft,
+        KeyCode::Up => PhysicalKey::ArrowUp,
+        KeyCode::Right => PhysicalKey::ArrowRight,
+        KeyCode::Down => PhysicalKey::ArrowDown,
+        KeyCode::Back => PhysicalKey::Backspace,
+        KeyCode::Return => PhysicalKey::Enter,
+        KeyCode::Space => PhysicalKey::Space,
+        KeyCode::Numlock => PhysicalKey::NumLock,
+        KeyCode::Numpad0 => PhysicalKey::Numpad0,
+        KeyCode::Numpad1 => PhysicalKey::Numpad1,
+        KeyCode::Numpad2 => PhysicalKey::Numpad2,
+        KeyCode::Numpad3 => PhysicalKey::Numpad3,
+        KeyCode::Numpad4 => PhysicalKey::Numpad4,
+        KeyCode::Numpad5 => PhysicalKey::Numpad5,
+        KeyCode::Numpad6 => PhysicalKey::Numpad6,
+        KeyCode::Numpad7 => PhysicalKey::Numpad7,
+        KeyCode::Numpad8 => PhysicalKey::Numpad8,
+        KeyCode::Numpad9 => PhysicalKey::Numpad9,
+        KeyCode::NumpadAdd => PhysicalKey::NumpadAdd,
+        KeyCode::NumpadSubtract => PhysicalKey::NumpadSubtract,
+        KeyCode::NumpadMultiply => PhysicalKey::NumpadMultiply,
+        KeyCode::NumpadDivide => PhysicalKey::NumpadDivide,
+        KeyCode::NumpadDecimal => PhysicalKey::NumpadDecimal,
+        KeyCode::NumpadEnter => PhysicalKey::NumpadEnter,
+        KeyCode::NumpadEquals => PhysicalKey::NumpadEqual,
+        KeyCode::Apostrophe => PhysicalKey::Quote,
+        KeyCode::Backslash => PhysicalKey::Backslash,
+        KeyCode::Capital => PhysicalKey::CapsLock,
+        KeyCode::Comma => PhysicalKey::Comma,
+        KeyCode::Equals => PhysicalKey::Equal,
+        KeyCode::Grave => PhysicalKey::Backquote,
+        KeyCode::LAlt => PhysicalKey::AltLeft,
+        KeyCode::LBracket => PhysicalKey::BracketLeft,
+        KeyCode::LControl => PhysicalKey::ControlLeft,
+        KeyCode::LShift => PhysicalKey::ShiftLeft,
+        KeyCode::LWin => PhysicalKey::SuperLeft,
+        KeyCode::Minus => PhysicalKey::Minus,
+        KeyCode::Oem102 => PhysicalKey::IntlBackslash,
+        KeyCode::Period => PhysicalKey::Period,
+        KeyCode::RAlt => PhysicalKey::AltRight,
+        KeyCode::RBracket => PhysicalKey::BracketRight,
+        KeyCode::RControl => PhysicalKey::ControlRight,
+        KeyCode::RShift => PhysicalKey::ShiftRight,
+        KeyCode::RWin => PhysicalKey::SuperRight,
+        KeyCode::Semicolon => PhysicalKey::Semicolon,
+        KeyCode::Slash => PhysicalKey::Slash,
+        KeyCode::Tab => PhysicalKey::Tab,
+        KeyCode::Apps => PhysicalKey::ContextMenu,
+        _ => return None,
+    })
+}
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+pub(crate) fn convert_scan_code_to_physical_key(scan_code: u32) -> PhysicalKey {
+    // Scan codes are evdev key codes, see linux/input-event-codes.h
+    match scan_code {
+        1 => PhysicalKey::Escape,
+        2 => PhysicalKey::Digit1,
+        3 => PhysicalKey::Digit2,
+        4 => PhysicalKey::Digit3,
+        5 => PhysicalKey::Digit4,
+        6 => PhysicalKey::Digit5,
+        7 => PhysicalKey::Digit6,
+        8 => PhysicalKey::Digit7,
+        9 => PhysicalKey::Digit8,
+        10 => PhysicalKey::Digit9,
+        11 => PhysicalKey::Digit0,
+        12 => PhysicalKey::Minus,
+        13 => PhysicalKey::Equal,
+        14 => PhysicalKey::Backspace,
+        15 => PhysicalKey::Tab,
+        16 => PhysicalKey::KeyQ,
+        17 => PhysicalKey::KeyW,
+        18 => PhysicalKey::KeyE,
+        19 => PhysicalKey::KeyR,
+        20 => PhysicalKey::KeyT,
+        21 => PhysicalKey::KeyY,
+        22 => PhysicalKey::KeyU,
+        23 => PhysicalKey::KeyI,
+        24 => PhysicalKey::KeyO,
+        25 => PhysicalKey::KeyP,
+        26 => PhysicalKey::BracketLeft,
+        27 => PhysicalKey::BracketRight,
+        28 => PhysicalKey::Enter,
+        29 => PhysicalKey::ControlLeft,
+        30 => PhysicalKey::KeyA,
+        31 => PhysicalKey::KeyS,
+        32 => PhysicalKey::KeyD,
+        33 => PhysicalKey::KeyF,
+        34 => PhysicalKey::KeyG,
+        35 => PhysicalKey::KeyH,
+        36 => PhysicalKey::KeyJ,
+        37 => PhysicalKey::KeyK,
+        38 => PhysicalKey::KeyL,
+        39 => PhysicalKey::Semicolon,
+        40 => PhysicalKey::Quote,
+        41 => PhysicalKey::Backquote,
+        42 => PhysicalKey::ShiftLeft,
+        43 => PhysicalKey::Backslash,
+        44 => PhysicalKey::KeyZ,
+        45 => PhysicalKey::KeyX,
+        46 => PhysicalKey::KeyC,
+        47 => PhysicalKey::KeyV,
+        48 => PhysicalKey::KeyB,
+        49 => PhysicalKey::KeyN,
+        50 => PhysicalKey::KeyM,
+        51 => PhysicalKey::Comma,
+        52 => PhysicalKey::Period,
+        53 => PhysicalKey::Slash,
+        54 => PhysicalKey::ShiftRight,
+        55 => PhysicalKey::NumpadMultiply,
+        56 => PhysicalKey::AltLeft,
+        57 => PhysicalKey::Space,
+        58 => PhysicalKey::CapsLock,
+        59 => PhysicalKey::F1,
+        60 => PhysicalKey::F2,
+        61 => PhysicalKey::F3,
+        62 => PhysicalKey::F4,
+        63 => PhysicalKey::F5,
+        64 => PhysicalKey::F6,
+        65 => PhysicalKey::F7,
+        66 => PhysicalKey::F8,
+        67 => PhysicalKey::F9,
+        68 => PhysicalKey::F10,
+        69 => PhysicalKey::NumLock,
+        70 => PhysicalKey::ScrollLock,
+        71 => PhysicalKey::Numpad7,
+        72 => PhysicalKey::Numpad8,
+        73 => PhysicalKey::Numpad9,
+        74 => PhysicalKey::NumpadSubtract,
+        75 => PhysicalKey::Numpad4,
+        76 => PhysicalKey::Numpad5,
+        77 => PhysicalKey::Numpad6,
+        78 => PhysicalKey::NumpadAdd,
+        79 => PhysicalKey::Numpad1,
+        80 => PhysicalKey::Numpad2,
+        81 => PhysicalKey::Numpad3,
+        82 => PhysicalKey::Numpad0,
+        83 => PhysicalKey::NumpadDecimal,
+        86 => PhysicalKey::IntlBackslash,
+        87 => PhysicalKey::F11,
+        88 => PhysicalKey::F12,
+        96 => PhysicalKey::NumpadEnter,
+        97 => PhysicalKey::ControlRight,
+        98 => PhysicalKey::NumpadDivide,
+        99 => PhysicalKey::PrintScreen,
+        100 => PhysicalKey::AltRight,
+        102 => PhysicalKey::Home,
+        103 => PhysicalKey::ArrowUp,
+        104 => PhysicalKey::PageUp,
+        105 => PhysicalKey::ArrowLeft,
+        106 => PhysicalKey::ArrowRight,
+        107 => PhysicalKey::End,
+        108 => PhysicalKey::ArrowDown,
+        109 => PhysicalKey::PageDown,
+        110 => PhysicalKey::Insert,
+        111 => PhysicalKey::Delete,
+        117 => PhysicalKey::NumpadEqual,
+        119 => PhysicalKey::Pause,
+        125 => PhysicalKey::SuperLeft,
+        126 => PhysicalKey::SuperRight,
+        127 => PhysicalKey::ContextMenu,
+        _ => PhysicalKey::Unidentified(scan_code),
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn convert_scan_code_to_physical_key(scan_code: u32) -> PhysicalKey {
+    // Scan codes are PS/2 set 1 scan codes, extended keys being prefixed by 0xE0
+    match scan_code {
+        0x01 => PhysicalKey::Escape,
+        0x02 => PhysicalKey::Digit1,
+        0x03 => PhysicalKey::Digit2,
+        0x04 => PhysicalKey::Digit3,
+        0x05 => PhysicalKey::Digit4,
+        0x06 => PhysicalKey::Digit5,
+        0x07 => PhysicalKey::Digit6,
+        0x08 => PhysicalKey::Digit7,
+        0x09 => PhysicalKey::Digit8,
+        0x0A => PhysicalKey::Digit9,
+        0x0B => PhysicalKey::Digit0,
+        0x0C => PhysicalKey::Minus,
+        0x0D => PhysicalKey::Equal,
+        0x0E => PhysicalKey::Backspace,
+        0x0F => PhysicalKey::Tab,
+        0x10 => PhysicalKey::KeyQ,
+        0x11 => PhysicalKey::KeyW,
+        0x12 => PhysicalKey::KeyE,
+        0x13 => PhysicalKey::KeyR,
+        0x14 => PhysicalKey::KeyT,
+        0x15 => PhysicalKey::KeyY,
+        0x16 => PhysicalKey::KeyU,
+        0x17 => PhysicalKey::KeyI,
+        0x18 => PhysicalKey::KeyO,
+        0x19 => PhysicalKey::KeyP,
+        0x1A => PhysicalKey::BracketLeft,
+        0x1B => PhysicalKey::BracketRight,
+        0x1C => PhysicalKey::Enter,
+        0x1D => PhysicalKey::ControlLeft,
+        0x1E => PhysicalKey::KeyA,
+        0x1F => PhysicalKey::KeyS,
+        0x20 => PhysicalKey::KeyD,
+        0x21 => PhysicalKey::KeyF,
+        0x22 => PhysicalKey::KeyG,
+        0x23 => PhysicalKey::KeyH,
+        0x24 => PhysicalKey::KeyJ,
+        0x25 => PhysicalKey::KeyK,
+        0x26 => PhysicalKey::KeyL,
+        0x27 => PhysicalKey::Semicolon,
+        0x28 => PhysicalKey::Quote,
+        0x29 => PhysicalKey::Backquote,
+        0x2A => PhysicalKey::ShiftLeft,
+        0x2B => PhysicalKey::Backslash,
+        0x2C => PhysicalKey::KeyZ,
+        0x2D => PhysicalKey::KeyX,
+        0x2E => PhysicalKey::KeyC,
+        0x2F => PhysicalKey::KeyV,
+        0x30 => PhysicalKey::KeyB,
+        0x31 => PhysicalKey::KeyN,
+        0x32 => PhysicalKey::KeyM,
+        0x33 => PhysicalKey::Comma,
+        0x34 => PhysicalKey::Period,
+        0x35 => PhysicalKey::Slash,
+        0x36 => PhysicalKey::ShiftRight,
+        0x37 => PhysicalKey::NumpadMultiply,
+        0x38 => PhysicalKey::AltLeft,
+        0x39 => PhysicalKey::Space,
+        0x3A => PhysicalKey::CapsLock,
+        0x3B => PhysicalKey::F1,
+        0x3C => PhysicalKey::F2,
+        0x3D => PhysicalKey::F3,
+        0x3E => PhysicalKey::F4,
+        0x3F => PhysicalKey::F5,
+        0x40 => PhysicalKey::F6,
+        0x41 => PhysicalKey::F7,
+        0x42 => PhysicalKey::F8,
+        0x43 => PhysicalKey::F9,
+        0x44 => PhysicalKey::F10,
+        0x45 => PhysicalKey::Pause,
+        0x46 => PhysicalKey::ScrollLock,
+        0x47 => PhysicalKey::Numpad7,
+        0x48 => PhysicalKey::Numpad8,
+        0x49 => PhysicalKey::Numpad9,
+        0x4A => PhysicalKey::NumpadSubtract,
+        0x4B => PhysicalKey::Numpad4,
+        0x4C => PhysicalKey::Numpad5,
+        0x4D => PhysicalKey::Numpad6,
+        0x4E => PhysicalKey::NumpadAdd,
+        0x4F => PhysicalKey::Numpad1,
+        0x50 => PhysicalKey::Numpad2,
+        0x51 => PhysicalKey::Numpad3,
+        0x52 => PhysicalKey::Numpad0,
+        0x53 => PhysicalKey::NumpadDecimal,
+        0x56 => PhysicalKey::IntlBackslash,
+        0x57 => PhysicalKey::F11,
+        0x58 => PhysicalKey::F12,
+        0x59 => PhysicalKey::NumpadEqual,
+        0xE01C => PhysicalKey::NumpadEnter,
+        0xE01D => PhysicalKey::ControlRight,
+        0xE035 => PhysicalKey::NumpadDivide,
+        0xE037 => PhysicalKey::PrintScreen,
+        0xE038 => PhysicalKey::AltRight,
+        0xE045 => PhysicalKey::NumLock,
+        0xE047 => PhysicalKey::Home,
+        0xE048 => PhysicalKey::ArrowUp,
+        0xE049 => PhysicalKey::PageUp,
+        0xE04B => PhysicalKey::ArrowLeft,
+        0xE04D => PhysicalKey::ArrowRight,
+        0xE04F => PhysicalKey::End,
+        0xE050 => PhysicalKey::ArrowDown,
+        0xE051 => PhysicalKey::PageDown,
+        0xE052 => PhysicalKey::Insert,
+        0xE053 => PhysicalKey::Delete,
+        0xE05B => PhysicalKey::SuperLeft,
+        0xE05C => PhysicalKey::SuperRight,
+        0xE05D => PhysicalKey::ContextMenu,
+        _ => PhysicalKey::Unidentified(scan_code),
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn convert_scan_code_to_physical_key(scan_code: u32) -> PhysicalKey {
+    // Scan codes are virtual key codes, see Carbon's Events.h
+    match scan_code {
+        0x00 => PhysicalKey::KeyA,
+        0x01 => PhysicalKey::KeyS,
+        0x02 => PhysicalKey::KeyD,
+        0x03 => PhysicalKey::KeyF,
+        0x04 => PhysicalKey::KeyH,
+        0x05 => PhysicalKey::KeyG,
+        0x06 => PhysicalKey::KeyZ,
+        0x07 => PhysicalKey::KeyX,
+        0x08 => PhysicalKey::KeyC,
+        0x09 => PhysicalKey::KeyV,
+        0x0A => PhysicalKey::IntlBackslash,
+        0x0B => PhysicalKey::KeyB,
+        0x0C => PhysicalKey::KeyQ,
+        0x0D => PhysicalKey::KeyW,
+        0x0E => PhysicalKey::KeyE,
+        0x0F => PhysicalKey::KeyR,
+        0x10 => PhysicalKey::KeyY,
+        0x11 => PhysicalKey::KeyT,
+        0x12 => PhysicalKey::Digit1,
+        0x13 => PhysicalKey::Digit2,
+        0x14 => PhysicalKey::Digit3,
+        0x15 => PhysicalKey::Digit4,
+        0x16 => PhysicalKey::Digit6,
+        0x17 => PhysicalKey::Digit5,
+        0x18 => PhysicalKey::Equal,
+        0x19 => PhysicalKey::Digit9,
+        0x1A => PhysicalKey::Digit7,
+        0x1B => PhysicalKey::Minus,
+        0x1C => PhysicalKey::Digit8,
+        0x1D => PhysicalKey::Digit0,
+        0x1E => PhysicalKey::BracketRight,
+        0x1F => PhysicalKey::KeyO,
+        0x20 => PhysicalKey::KeyU,
+        0x21 => PhysicalKey::BracketLeft,
+        0x22 => PhysicalKey::KeyI,
+        0x23 => PhysicalKey::KeyP,
+        0x24 => PhysicalKey::Enter,
+        0x25 => PhysicalKey::KeyL,
+        0x26 => PhysicalKey::KeyJ,
+        0x27 => PhysicalKey::Quote,
+        0x28 => PhysicalKey::KeyK,
+        0x29 => PhysicalKey::Semicolon,
+        0x2A => PhysicalKey::Backslash,
+        0x2B => PhysicalKey::Comma,
+        0x2C => PhysicalKey::Slash,
+        0x2D => PhysicalKey::KeyN,
+        0x2E => PhysicalKey::KeyM,
+        0x2F => PhysicalKey::Period,
+        0x30 => PhysicalKey::Tab,
+        0x31 => PhysicalKey::Space,
+        0x32 => PhysicalKey::Backquote,
+        0x33 => PhysicalKey::Backspace,
+        0x35 => PhysicalKey::Escape,
+        0x36 => PhysicalKey::SuperRight,
+        0x37 => PhysicalKey::SuperLeft,
+        0x38 => PhysicalKey::ShiftLeft,
+        0x39 => PhysicalKey::CapsLock,
+        0x3A => PhysicalKey::AltLeft,
+        0x3B => PhysicalKey::ControlLeft,
+        0x3C => PhysicalKey::ShiftRight,
+        0x3D => PhysicalKey::AltRight,
+        0x3E => PhysicalKey::ControlRight,
+        0x41 => PhysicalKey::NumpadDecimal,
+        0x43 => PhysicalKey::NumpadMultiply,
+        0x45 => PhysicalKey::NumpadAdd,
+        0x47 => PhysicalKey::NumLock,
+        0x4B => PhysicalKey::NumpadDivide,
+        0x4C => PhysicalKey::NumpadEnter,
+        0x4E => PhysicalKey::NumpadSubtract,
+        0x51 => PhysicalKey::NumpadEqual,
+        0x52 => PhysicalKey::Numpad0,
+        0x53 => PhysicalKey::Numpad1,
+        0x54 => PhysicalKey::Numpad2,
+        0x55 => PhysicalKey::Numpad3,
+        0x56 => PhysicalKey::Numpad4,
+        0x57 => PhysicalKey::Numpad5,
+        0x58 => PhysicalKey::Numpad6,
+        0x59 => PhysicalKey::Numpad7,
+        0x5B => PhysicalKey::Numpad8,
+        0x5C => PhysicalKey::Numpad9,
+        0x60 => PhysicalKey::F5,
+        0x61 => PhysicalKey::F6,
+        0x62 => PhysicalKey::F7,
+        0x63 => PhysicalKey::F3,
+        0x64 => PhysicalKey::F8,
+        0x65 => PhysicalKey::F9,
+        0x67 => PhysicalKey::F11,
+        0x6D => PhysicalKey::F10,
+        0x6E => PhysicalKey::ContextMenu,
+        0x6F => PhysicalKey::F12,
+        0x72 => PhysicalKey::Insert,
+        0x73 => PhysicalKey::Home,
+        0x74 => PhysicalKey::PageUp,
+        0x75 => PhysicalKey::Delete,
+        0x76 => PhysicalKey::F4,
+        0x77 => PhysicalKey::End,
+        0x78 => PhysicalKey::F2,
+        0x79 => PhysicalKey::PageDown,
+        0x7A => PhysicalKey::F1,
+        0x7B => PhysicalKey::ArrowLeft,
+        0x7C => PhysicalKey::ArrowRight,
+        0x7D => PhysicalKey::ArrowDown,
+        0x7E => PhysicalKey::ArrowUp,
+        _ => PhysicalKey::Unidentified(scan_code),
+    }
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+pub(crate) fn convert_scan_code_to_physical_key(scan_code: u32) -> PhysicalKey {
+    PhysicalKey::Unidentified(scan_code)
+}
+
 pub fn convert_element_state(element_state: winit::event::ElementState) -> ButtonState {
     match element_state {
         winit::event::ElementState::Pressed => ButtonState::Pressed,
@@ -227,6 +713,12 @@ pub fn convert_virtual_key_code(virtual_key_code: winit::event::VirtualKeyCode)
     }
 }
 
+pub fn convert_window_icon(
+    icon: &WindowIcon,
+) -> Result<winit::window::Icon, winit::window::BadIcon> {
+    winit::window::Icon::from_rgba(icon.rgba.clone(), icon.width, icon.height)
+}
+
 pub fn convert_cursor_icon(cursor_icon: CursorIcon) -> winit::window::CursorIcon {
     match cursor_icon {
         CursorIcon::Default => winit::window::CursorIcon::Default,
@@ -0,0 +1,209 @@
+use bevy_ecs::{event::Events, world::World};
+use bevy_input::{
+    keyboard::{KeyLabels, KeyboardInput, LogicalKey, PhysicalKey},
+    ButtonState,
+};
+use bevy_utils::HashMap;
+
+/// Completes the [`KeyboardInput`] events with the text produced by the keys.
+///
+/// `winit` reports the text produced by a key press in a `ReceivedCharacter` event following the
+/// keyboard input, so presses of keys that may produce text are held back until their character
+/// is received, or until the next keyboard input or update. Their logical key is then the text
+/// reported by the OS, which works for any keyboard layout, and releases report the logical key
+/// of the matching press.
+#[derive(Default)]
+pub(crate) struct KeyboardState {
+    /// A key press waiting for the character it produces.
+    pending_press: Option<KeyboardInput>,
+    /// The logical keys of the pressed keys, by scan code.
+    pressed_logical_keys: HashMap<u32, LogicalKey>,
+}
+
+impl KeyboardState {
+    /// Handles a keyboard input converted from `winit`.
+    pub fn keyboard_input(&mut self, mut input: KeyboardInput, events: &mut Events<KeyboardInput>) {
+        self.flush(events);
+        match input.state {
+            ButtonState::Pressed if !matches!(input.logical_key, LogicalKey::Named(_)) => {
+                self.pending_press = Some(input);
+            }
+            ButtonState::Pressed => {
+                self.pressed_logical_keys
+                    .insert(input.scan_code, input.logical_key.clone());
+                events.send(input);
+            }
+            ButtonState::Released => {
+                if let Some(logical_key) = self.pressed_logical_keys.remove(&input.scan_code) {
+                    input.logical_key = logical_key;
+                }
+                events.send(input);
+            }
+        }
+    }
+
+    /// Handles a character received from `winit`, which was produced by the last key press.
+    pub fn received_character(&mut self, character: char, events: &mut Events<KeyboardInput>) {
+        if let Some(press) = &mut self.pending_press {
+            // Control characters are produced by shortcuts like Ctrl+C, and don't name the key
+            if !character.is_control() {
+                press.logical_key = LogicalKey::Character(character.to_string());
+            }
+        }
+        self.flush(events);
+    }
+
+    /// Sends the key press waiting for its character, if any.
+    pub fn flush(&mut self, events: &mut Events<KeyboardInput>) {
+        if let Some(press) = self.pending_press.take() {
+            self.pressed_logical_keys
+                .insert(press.scan_code, press.logical_key.clone());
+            events.send(press);
+        }
+    }
+}
+
+/// Replaces the [`KeyLabels`] with the labels of the current keyboard layout, on platforms where
+/// it can be queried.
+///
+/// On other platforms, the labels are learned from the key presses.
+pub(crate) fn update_key_labels(world: &mut World) {
+    if let (Some(labels), Some(mut key_labels)) =
+        (layout_key_labels(), world.get_resource_mut::<KeyLabels>())
+    {
+        key_labels.clear();
+        for (key, label) in labels {
+            key_labels.set_label(key, label);
+        }
+    }
+}
+
+/// Returns the labels of the keys producing text in the current keyboard layout.
+#[cfg(target_os = "windows")]
+fn layout_key_labels() -> Option<Vec<(PhysicalKey, String)>> {
+    use crate::converters::convert_scan_code_to_physical_key;
+    use winapi::um::winuser::{GetKeyboardLayout, MapVirtualKeyExW, ToUnicodeEx, MAPVK_VSC_TO_VK};
+
+    // Without modifiers
+    let keyboard_state = [0u8; 256];
+    let mut labels = Vec::new();
+    // SAFETY: the buffers outlive the calls, and their lengths are passed along
+    unsafe {
+        let layout = GetKeyboardLayout(0);
+        for scan_code in 0x01..0x80 {
+            let key = convert_scan_code_to_physical_key(scan_code);
+            // Only keys producing text have a layout dependent label
+            if !matches!(key.qwerty_label(), Some(label) if label.chars().count() == 1) {
+                continue;
+            }
+            let virtual_key = MapVirtualKeyExW(scan_code, MAPVK_VSC_TO_VK, layout);
+            if virtual_key == 0 {
+                continue;
+            }
+            let mut text = [0u16; 4];
+            // Flag 4 leaves the keyboard state unchanged, so pending dead keys aren't consumed
+            let length = ToUnicodeEx(
+                virtual_key,
+                scan_code,
+                keyboard_state.as_ptr(),
+                text.as_mut_ptr(),
+                text.len() as i32,
+                4,
+                layout,
+            );
+            // A negative length is a dead key, whose spacing character is returned
+            let length = match length {
+                length if length < 0 => 1,
+                0 => continue,
+                length => length as usize,
+            };
+            let label = String::from_utf16_lossy(&text[..length]);
+            if !label.chars().any(char::is_control) {
+                labels.push((key, label.to_uppercase()));
+            }
+        }
+    }
+    Some(labels)
+}
+
+/// Returns the labels of the keys producing text in the current keyboard layout.
+#[cfg(not(target_os = "windows"))]
+fn layout_key_labels() -> Option<Vec<(PhysicalKey, String)>> {
+    // `winit` doesn't expose the keyboard layout
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyboardState;
+    use bevy_ecs::event::Events;
+    use bevy_input::{
+        keyboard::{KeyCode, KeyboardInput, LogicalKey, PhysicalKey},
+        ButtonState,
+    };
+
+    fn input(key_code: Option<KeyCode>, state: ButtonState) -> KeyboardInput {
+        KeyboardInput {
+            scan_code: 16,
+            key_code,
+            physical_key: PhysicalKey::KeyQ,
+            logical_key: key_code.map_or(LogicalKey::Unidentified, LogicalKey::from),
+            state,
+        }
+    }
+
+    fn sent(events: &Events<KeyboardInput>) -> Vec<KeyboardInput> {
+        let mut reader = events.get_reader();
+        reader.iter(events).cloned().collect()
+    }
+
+    #[test]
+    fn logical_key_from_received_character() {
+        let mut state = KeyboardState::default();
+        let mut events = Events::<KeyboardInput>::default();
+
+        // A key without key code in a non-Latin layout
+        state.keyboard_input(input(None, ButtonState::Pressed), &mut events);
+        assert!(sent(&events).is_empty());
+        state.received_character('й', &mut events);
+        state.keyboard_input(input(None, ButtonState::Released), &mut events);
+
+        let sent = sent(&events);
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].logical_key, LogicalKey::Character("й".to_string()));
+        assert_eq!(sent[1].logical_key, LogicalKey::Character("й".to_string()));
+        assert_eq!(sent[1].state, ButtonState::Released);
+    }
+
+    #[test]
+    fn flush_presses_without_character() {
+        let mut state = KeyboardState::default();
+        let mut events = Events::<KeyboardInput>::default();
+
+        state.keyboard_input(input(Some(KeyCode::Q), ButtonState::Pressed), &mut events);
+        // A shortcut producing a control character
+        state.received_character('\u{11}', &mut events);
+        state.keyboard_input(input(None, ButtonState::Pressed), &mut events);
+        state.flush(&mut events);
+
+        let sent = sent(&events);
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].logical_key, LogicalKey::Character("q".to_string()));
+        assert_eq!(sent[1].logical_key, LogicalKey::Unidentified);
+    }
+
+    #[test]
+    fn named_keys_are_sent_immediately() {
+        let mut state = KeyboardState::default();
+        let mut events = Events::<KeyboardInput>::default();
+
+        state.keyboard_input(
+            input(Some(KeyCode::LShift), ButtonState::Pressed),
+            &mut events,
+        );
+        assert_eq!(
+            sent(&events)[0].logical_key,
+            LogicalKey::Named(KeyCode::LShift)
+        );
+    }
+}
@@ -1,9 +1,12 @@
 mod converters;
+mod keyboard;
+mod system;
 #[cfg(target_arch = "wasm32")]
 mod web_resize;
 mod winit_config;
 mod winit_windows;
 
+pub use system::CachedWindow;
 pub use winit_config::*;
 pub use winit_windows::*;
 
@@ -11,6 +14,7 @@ use bevy_app::{App, AppExit, CoreStage, Plugin};
 use bevy_ecs::prelude::*;
 use bevy_ecs::{
     event::{Events, ManualEventReader},
+    system::SystemState,
     world::World,
 };
 use bevy_input::{
@@ -20,18 +24,16 @@ use bevy_input::{
 };
 use bevy_math::{ivec2, DVec2, Vec2};
 use bevy_utils::{
-    tracing::{error, info, trace, warn},
+    tracing::{info, trace, warn},
     Instant,
 };
 use bevy_window::{
-    CreateWindow, CursorEntered, CursorLeft, CursorMoved, FileDragAndDrop, ModifiesWindows,
-    ReceivedCharacter, RequestRedraw, WindowBackendScaleFactorChanged, WindowCloseRequested,
-    WindowClosed, WindowCreated, WindowFocused, WindowMoved, WindowResized,
-    WindowScaleFactorChanged, Windows,
+    CursorEntered, CursorLeft, CursorMoved, FileDragAndDrop, ModifiesWindows, ReceivedCharacter,
+    RequestRedraw, Window, WindowBackend, WindowBackendScaleFactorChanged, WindowCloseRequested,
+    WindowCreated, WindowFocused, WindowMoved, WindowResized, WindowScaleFactorChanged,
 };
 
 use winit::{
-    dpi::{LogicalSize, PhysicalPosition},
     event::{self, DeviceEvent, Event, StartCause, WindowEvent},
     event_loop::{ControlFlow, EventLoop, EventLoopWindowTarget},
 };
@@ -41,159 +43,53 @@ pub struct WinitPlugin;
 
 impl Plugin for WinitPlugin {
     fn build(&self, app: &mut App) {
+        WindowBackend::insert(app, std::any::type_name::<Self>());
         app.init_non_send_resource::<WinitWindows>()
             .init_resource::<WinitSettings>()
             .set_runner(winit_runner)
-            .add_system_to_stage(CoreStage::PostUpdate, change_window.label(ModifiesWindows));
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                system::changed_window.label(ModifiesWindows),
+            )
+            // Run last, so windows despawned at any point of the frame are closed
+            .add_system_to_stage(CoreStage::Last, system::despawn_window);
         #[cfg(target_arch = "wasm32")]
         app.add_plugin(web_resize::CanvasParentResizePlugin);
         let event_loop = EventLoop::new();
-        let mut create_window_reader = WinitCreateWindowReader::default();
-        // Note that we create a window here "early" because WASM/WebGL requires the window to exist prior to initializing
-        // the renderer.
-        handle_create_window_events(&mut app.world, &event_loop, &mut create_window_reader.0);
-        app.insert_resource(create_window_reader)
-            .insert_non_send_resource(event_loop);
+        // Note that we create the windows here "early" because WASM/WebGL requires the window to
+        // exist prior to initializing the renderer.
+        let mut create_window_state = SystemState::new(&mut app.world);
+        create_windows(&mut app.world, &event_loop, &mut create_window_state);
+        app.insert_non_send_resource(event_loop);
+        keyboard::update_key_labels(&mut app.world);
     }
 }
 
-fn change_window(
-    mut winit_windows: NonSendMut<WinitWindows>,
-    mut windows: ResMut<Windows>,
-    mut window_dpi_changed_events: EventWriter<WindowScaleFactorChanged>,
-    mut window_close_events: EventWriter<WindowClosed>,
-) {
-    let mut removed_windows = vec![];
-    for bevy_window in windows.iter_mut() {
-        let id = bevy_window.id();
-        for command in bevy_window.drain_commands() {
-            match command {
-                bevy_window::WindowCommand::SetWindowMode {
-                    mode,
-                    resolution: (width, height),
-                } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    match mode {
-                        bevy_window::WindowMode::BorderlessFullscreen => {
-                            window
-                                .set_fullscreen(Some(winit::window::Fullscreen::Borderless(None)));
-                        }
-                        bevy_window::WindowMode::Fullscreen => {
-                            window.set_fullscreen(Some(winit::window::Fullscreen::Exclusive(
-                                get_best_videomode(&window.current_monitor().unwrap()),
-                            )));
-                        }
-                        bevy_window::WindowMode::SizedFullscreen => window.set_fullscreen(Some(
-                            winit::window::Fullscreen::Exclusive(get_fitting_videomode(
-                                &window.current_monitor().unwrap(),
-                                width,
-                                height,
-                            )),
-                        )),
-                        bevy_window::WindowMode::Windowed => window.set_fullscreen(None),
-                    }
-                }
-                bevy_window::WindowCommand::SetTitle { title } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    window.set_title(&title);
-                }
-                bevy_window::WindowCommand::SetScaleFactor { scale_factor } => {
-                    window_dpi_changed_events.send(WindowScaleFactorChanged { id, scale_factor });
-                }
-                bevy_window::WindowCommand::SetResolution {
-                    logical_resolution: (width, height),
-                    scale_factor,
-                } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    window.set_inner_size(
-                        winit::dpi::LogicalSize::new(width, height)
-                            .to_physical::<f64>(scale_factor),
-                    );
-                }
-                bevy_window::WindowCommand::SetPresentMode { .. } => (),
-                bevy_window::WindowCommand::SetResizable { resizable } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    window.set_resizable(resizable);
-                }
-                bevy_window::WindowCommand::SetDecorations { decorations } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    window.set_decorations(decorations);
-                }
-                bevy_window::WindowCommand::SetCursorIcon { icon } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    window.set_cursor_icon(converters::convert_cursor_icon(icon));
-                }
-                bevy_window::WindowCommand::SetCursorLockMode { locked } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    window
-                        .set_cursor_grab(locked)
-                        .unwrap_or_else(|e| error!("Unable to un/grab cursor: {}", e));
-                }
-                bevy_window::WindowCommand::SetCursorVisibility { visible } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    window.set_cursor_visible(visible);
-                }
-                bevy_window::WindowCommand::SetCursorPosition { position } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    let inner_size = window.inner_size().to_logical::<f32>(window.scale_factor());
-                    window
-                        .set_cursor_position(winit::dpi::LogicalPosition::new(
-                            position.x,
-                            inner_size.height - position.y,
-                        ))
-                        .unwrap_or_else(|e| error!("Unable to set cursor position: {}", e));
-                }
-                bevy_window::WindowCommand::SetMaximized { maximized } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    window.set_maximized(maximized);
-                }
-                bevy_window::WindowCommand::SetMinimized { minimized } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    window.set_minimized(minimized);
-                }
-                bevy_window::WindowCommand::SetPosition { position } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    window.set_outer_position(PhysicalPosition {
-                        x: position[0],
-                        y: position[1],
-                    });
-                }
-                bevy_window::WindowCommand::SetResizeConstraints { resize_constraints } => {
-                    let window = winit_windows.get_window(id).unwrap();
-                    let constraints = resize_constraints.check_constraints();
-                    let min_inner_size = LogicalSize {
-                        width: constraints.min_width,
-                        height: constraints.min_height,
-                    };
-                    let max_inner_size = LogicalSize {
-                        width: constraints.max_width,
-                        height: constraints.max_height,
-                    };
+type CreateWindowParams<'w, 's> = (
+    Commands<'w, 's>,
+    Query<'w, 's, (Entity, &'static mut Window), Added<Window>>,
+    EventWriter<'w, 's, WindowCreated>,
+    EventWriter<'w, 's, WindowResized>,
+    NonSendMut<'w, WinitWindows>,
+);
 
-                    window.set_min_inner_size(Some(min_inner_size));
-                    if constraints.max_width.is_finite() && constraints.max_height.is_finite() {
-                        window.set_max_inner_size(Some(max_inner_size));
-                    }
-                }
-                bevy_window::WindowCommand::Close => {
-                    // Since we have borrowed `windows` to iterate through them, we can't remove the window from it.
-                    // Add the removal requests to a queue to solve this
-                    removed_windows.push(id);
-                    // No need to run any further commands - this drops the rest of the commands, although the `bevy_window::Window` will be dropped later anyway
-                    break;
-                }
-            }
-        }
-    }
-    if !removed_windows.is_empty() {
-        for id in removed_windows {
-            // Close the OS window. (The `Drop` impl actually closes the window)
-            let _ = winit_windows.remove_window(id);
-            // Clean up our own data structures
-            windows.remove(id);
-            window_close_events.send(WindowClosed { id });
-        }
-    }
+/// Creates the `winit` windows of the [`Window`] entities spawned since the last call.
+fn create_windows(
+    world: &mut World,
+    event_loop: &EventLoopWindowTarget<()>,
+    state: &mut SystemState<CreateWindowParams<'static, 'static>>,
+) {
+    let (commands, created_windows, window_created_events, window_resized_events, winit_windows) =
+        state.get_mut(world);
+    system::create_window(
+        event_loop,
+        commands,
+        created_windows,
+        window_created_events,
+        window_resized_events,
+        winit_windows,
+    );
+    state.apply(world);
 }
 
 fn run<F>(event_loop: EventLoop<()>, event_handler: F) -> !
@@ -279,22 +175,16 @@ impl Default for WinitPersistentState {
     }
 }
 
-#[derive(Default)]
-struct WinitCreateWindowReader(ManualEventReader<CreateWindow>);
-
 pub fn winit_runner_with(mut app: App) {
     let mut event_loop = app
         .world
         .remove_non_send_resource::<EventLoop<()>>()
         .unwrap();
-    let mut create_window_event_reader = app
-        .world
-        .remove_resource::<WinitCreateWindowReader>()
-        .unwrap()
-        .0;
+    let mut create_window_state = SystemState::new(&mut app.world);
     let mut app_exit_event_reader = ManualEventReader::<AppExit>::default();
     let mut redraw_event_reader = ManualEventReader::<RequestRedraw>::default();
     let mut winit_state = WinitPersistentState::default();
+    let mut keyboard_state = keyboard::KeyboardState::default();
     app.world
         .insert_non_send_resource(event_loop.create_proxy());
 
@@ -307,9 +197,8 @@ pub fn winit_runner_with(mut app: App) {
                               control_flow: &mut ControlFlow| {
         match event {
             event::Event::NewEvents(start) => {
+                let focused = any_window_focused(&mut app.world);
                 let winit_config = app.world.resource::<WinitSettings>();
-                let windows = app.world.resource::<Windows>();
-                let focused = windows.iter().any(|w| w.is_focused());
                 // Check if either the `WaitUntil` timeout was triggered by winit, or that same
                 // amount of time has elapsed since the last app update. This manual check is needed
                 // because we don't know if the criteria for an app update were met until the end of
@@ -332,12 +221,10 @@ pub fn winit_runner_with(mut app: App) {
                 window_id: winit_window_id,
                 ..
             } => {
-                let world = app.world.cell();
-                let winit_windows = world.non_send_resource_mut::<WinitWindows>();
-                let mut windows = world.resource_mut::<Windows>();
-                let window_id =
-                    if let Some(window_id) = winit_windows.get_window_id(winit_window_id) {
-                        window_id
+                let winit_windows = app.world.non_send_resource::<WinitWindows>();
+                let window_entity =
+                    if let Some(entity) = winit_windows.get_window_entity(winit_window_id) {
+                        entity
                     } else {
                         warn!(
                             "Skipped event for unknown winit Window Id {:?}",
@@ -346,61 +233,86 @@ pub fn winit_runner_with(mut app: App) {
                         return;
                     };
 
-                let window = if let Some(window) = windows.get_mut(window_id) {
-                    window
-                } else {
+                if app.world.get::<Window>(window_entity).is_none() {
                     // If we're here, this window was previously opened
-                    info!("Skipped event for closed window: {:?}", window_id);
+                    info!("Skipped event for closed window: {:?}", window_entity);
                     return;
-                };
+                }
                 winit_state.low_power_event = true;
 
+                let world = &mut app.world;
                 match event {
                     WindowEvent::Resized(size) => {
+                        let mut window = world.get_mut::<Window>(window_entity).unwrap();
                         window.update_actual_size_from_backend(size.width, size.height);
-                        let mut resize_events = world.resource_mut::<Events<WindowResized>>();
-                        resize_events.send(WindowResized {
-                            id: window_id,
-                            width: window.width(),
-                            height: window.height(),
-                        });
+                        let (width, height) = (window.width(), window.height());
+                        world
+                            .resource_mut::<Events<WindowResized>>()
+                            .send(WindowResized {
+                                window: window_entity,
+                                width,
+                                height,
+                            });
                     }
                     WindowEvent::CloseRequested => {
                         let mut window_close_requested_events =
                             world.resource_mut::<Events<WindowCloseRequested>>();
-                        window_close_requested_events.send(WindowCloseRequested { id: window_id });
+                        window_close_requested_events.send(WindowCloseRequested {
+                            window: window_entity,
+                        });
                     }
                     WindowEvent::KeyboardInput { ref input, .. } => {
                         let mut keyboard_input_events =
                             world.resource_mut::<Events<KeyboardInput>>();
-                        keyboard_input_events.send(converters::convert_keyboard_input(input));
+                        keyboard_state.keyboard_input(
+                            converters::convert_keyboard_input(input),
+                            &mut keyboard_input_events,
+                        );
                     }
                     WindowEvent::CursorMoved { position, .. } => {
-                        let mut cursor_moved_events = world.resource_mut::<Events<CursorMoved>>();
-                        let winit_window = winit_windows.get_window(window_id).unwrap();
-                        let inner_size = winit_window.inner_size();
+                        let window = world.get::<Window>(window_entity).unwrap();
+                        if let Some(locked_position) = locked_cursor_position(window) {
+                            if locked_position != position {
+                                let winit_windows = world.non_send_resource::<WinitWindows>();
+                                let winit_window = winit_windows.get_window(window_entity);
+                                // The cursor moves back, sending another `CursorMoved` event
+                                if let Some(Ok(())) = winit_window
+                                    .map(|window| window.set_cursor_position(locked_position))
+                                {
+                                    return;
+                                }
+                            }
+                        }
+                        let mut window = world.get_mut::<Window>(window_entity).unwrap();
 
                         // move origin to bottom left
-                        let y_position = inner_size.height as f64 - position.y;
+                        let y_position = window.physical_height() as f64 - position.y;
 
                         let physical_position = DVec2::new(position.x, y_position);
                         window
                             .update_cursor_physical_position_from_backend(Some(physical_position));
+                        let position = (physical_position / window.scale_factor()).as_vec2();
 
+                        let mut cursor_moved_events = world.resource_mut::<Events<CursorMoved>>();
                         cursor_moved_events.send(CursorMoved {
-                            id: window_id,
-                            position: (physical_position / window.scale_factor()).as_vec2(),
+                            window: window_entity,
+                            position,
                         });
                     }
                     WindowEvent::CursorEntered { .. } => {
                         let mut cursor_entered_events =
                             world.resource_mut::<Events<CursorEntered>>();
-                        cursor_entered_events.send(CursorEntered { id: window_id });
+                        cursor_entered_events.send(CursorEntered {
+                            window: window_entity,
+                        });
                     }
                     WindowEvent::CursorLeft { .. } => {
-                        let mut cursor_left_events = world.resource_mut::<Events<CursorLeft>>();
+                        let mut window = world.get_mut::<Window>(window_entity).unwrap();
                         window.update_cursor_physical_position_from_backend(None);
-                        cursor_left_events.send(CursorLeft { id: window_id });
+                        let mut cursor_left_events = world.resource_mut::<Events<CursorLeft>>();
+                        cursor_left_events.send(CursorLeft {
+                            window: window_entity,
+                        });
                     }
                     WindowEvent::MouseInput { state, button, .. } => {
                         let mut mouse_button_input_events =
@@ -431,16 +343,15 @@ pub fn winit_runner_with(mut app: App) {
                         }
                     },
                     WindowEvent::Touch(touch) => {
-                        let mut touch_input_events = world.resource_mut::<Events<TouchInput>>();
-
+                        let window = world.get::<Window>(window_entity).unwrap();
                         let mut location = touch.location.to_logical(window.scale_factor());
 
                         // On a mobile window, the start is from the top while on PC/Linux/OSX from
                         // bottom
                         if cfg!(target_os = "android") || cfg!(target_os = "ios") {
-                            let window_height = windows.primary().height();
-                            location.y = window_height - location.y;
+                            location.y = window.height() - location.y;
                         }
+                        let mut touch_input_events = world.resource_mut::<Events<TouchInput>>();
                         touch_input_events.send(converters::convert_touch_input(touch, location));
                     }
                     WindowEvent::ReceivedCharacter(c) => {
@@ -448,9 +359,13 @@ pub fn winit_runner_with(mut app: App) {
                             world.resource_mut::<Events<ReceivedCharacter>>();
 
                         char_input_events.send(ReceivedCharacter {
-                            id: window_id,
+                            window: window_entity,
                             char: c,
                         });
+
+                        let mut keyboard_input_events =
+                            world.resource_mut::<Events<KeyboardInput>>();
+                        keyboard_state.received_character(c, &mut keyboard_input_events);
                     }
                     WindowEvent::ScaleFactorChanged {
                         scale_factor,
@@ -459,81 +374,98 @@ pub fn winit_runner_with(mut app: App) {
                         let mut backend_scale_factor_change_events =
                             world.resource_mut::<Events<WindowBackendScaleFactorChanged>>();
                         backend_scale_factor_change_events.send(WindowBackendScaleFactorChanged {
-                            id: window_id,
+                            window: window_entity,
                             scale_factor,
                         });
+
+                        let mut window = world.get_mut::<Window>(window_entity).unwrap();
                         let prior_factor = window.scale_factor();
                         window.update_scale_factor_from_backend(scale_factor);
                         let new_factor = window.scale_factor();
-                        if let Some(forced_factor) = window.scale_factor_override() {
-                            // If there is a scale factor override, then force that to be used
-                            // Otherwise, use the OS suggested size
-                            // We have already told the OS about our resize constraints, so
-                            // the new_inner_size should take those into account
-                            *new_inner_size = winit::dpi::LogicalSize::new(
-                                window.requested_width(),
-                                window.requested_height(),
-                            )
-                            .to_physical::<u32>(forced_factor);
-                        } else if approx::relative_ne!(new_factor, prior_factor) {
+                        let scale_factor_changed =
+                            if let Some(forced_factor) = window.scale_factor_override() {
+                                // If there is a scale factor override, then force that to be used
+                                // Otherwise, use the OS suggested size
+                                // We have already told the OS about our resize constraints, so
+                                // the new_inner_size should take those into account
+                                *new_inner_size = winit::dpi::LogicalSize::new(
+                                    window.requested_width(),
+                                    window.requested_height(),
+                                )
+                                .to_physical::<u32>(forced_factor);
+                                false
+                            } else {
+                                approx::relative_ne!(new_factor, prior_factor)
+                            };
+
+                        let new_logical_width = new_inner_size.width as f64 / new_factor;
+                        let new_logical_height = new_inner_size.height as f64 / new_factor;
+                        let resized =
+                            approx::relative_ne!(window.width() as f64, new_logical_width)
+                                || approx::relative_ne!(window.height() as f64, new_logical_height);
+                        window.update_actual_size_from_backend(
+                            new_inner_size.width,
+                            new_inner_size.height,
+                        );
+
+                        if scale_factor_changed {
                             let mut scale_factor_change_events =
                                 world.resource_mut::<Events<WindowScaleFactorChanged>>();
 
                             scale_factor_change_events.send(WindowScaleFactorChanged {
-                                id: window_id,
+                                window: window_entity,
                                 scale_factor,
                             });
                         }
-
-                        let new_logical_width = new_inner_size.width as f64 / new_factor;
-                        let new_logical_height = new_inner_size.height as f64 / new_factor;
-                        if approx::relative_ne!(window.width() as f64, new_logical_width)
-                            || approx::relative_ne!(window.height() as f64, new_logical_height)
-                        {
+                        if resized {
                             let mut resize_events = world.resource_mut::<Events<WindowResized>>();
                             resize_events.send(WindowResized {
-                                id: window_id,
+                                window: window_entity,
                                 width: new_logical_width as f32,
                                 height: new_logical_height as f32,
                             });
                         }
-                        window.update_actual_size_from_backend(
-                            new_inner_size.width,
-                            new_inner_size.height,
-                        );
                     }
                     WindowEvent::Focused(focused) => {
+                        if focused {
+                            // The keyboard layout may have changed while the app was in background
+                            keyboard::update_key_labels(world);
+                        }
+                        let mut window = world.get_mut::<Window>(window_entity).unwrap();
                         window.update_focused_status_from_backend(focused);
                         let mut focused_events = world.resource_mut::<Events<WindowFocused>>();
                         focused_events.send(WindowFocused {
-                            id: window_id,
+                            window: window_entity,
                             focused,
                         });
                     }
                     WindowEvent::DroppedFile(path_buf) => {
                         let mut events = world.resource_mut::<Events<FileDragAndDrop>>();
                         events.send(FileDragAndDrop::DroppedFile {
-                            id: window_id,
+                            window: window_entity,
                             path_buf,
                         });
                     }
                     WindowEvent::HoveredFile(path_buf) => {
                         let mut events = world.resource_mut::<Events<FileDragAndDrop>>();
                         events.send(FileDragAndDrop::HoveredFile {
-                            id: window_id,
+                            window: window_entity,
                             path_buf,
                         });
                     }
                     WindowEvent::HoveredFileCancelled => {
                         let mut events = world.resource_mut::<Events<FileDragAndDrop>>();
-                        events.send(FileDragAndDrop::HoveredFileCancelled { id: window_id });
+                        events.send(FileDragAndDrop::HoveredFileCancelled {
+                            window: window_entity,
+                        });
                     }
                     WindowEvent::Moved(position) => {
                         let position = ivec2(position.x, position.y);
+                        let mut window = world.get_mut::<Window>(window_entity).unwrap();
                         window.update_actual_position_from_backend(position);
                         let mut events = world.resource_mut::<Events<WindowMoved>>();
                         events.send(WindowMoved {
-                            id: window_id,
+                            window: window_entity,
                             position,
                         });
                     }
@@ -556,15 +488,11 @@ pub fn winit_runner_with(mut app: App) {
                 winit_state.active = true;
             }
             event::Event::MainEventsCleared => {
-                handle_create_window_events(
-                    &mut app.world,
-                    event_loop,
-                    &mut create_window_event_reader,
-                );
+                keyboard_state.flush(&mut app.world.resource_mut::<Events<KeyboardInput>>());
+                create_windows(&mut app.world, event_loop, &mut create_window_state);
+                let focused = any_window_focused(&mut app.world);
                 let winit_config = app.world.resource::<WinitSettings>();
                 let update = if winit_state.active {
-                    let windows = app.world.resource::<Windows>();
-                    let focused = windows.iter().any(|w| w.is_focused());
                     match winit_config.update_mode(focused) {
                         UpdateMode::Continuous | UpdateMode::Reactive { .. } => true,
                         UpdateMode::ReactiveLowPower { .. } => {
@@ -583,9 +511,8 @@ pub fn winit_runner_with(mut app: App) {
             }
             Event::RedrawEventsCleared => {
                 {
+                    let focused = any_window_focused(&mut app.world);
                     let winit_config = app.world.resource::<WinitSettings>();
-                    let windows = app.world.resource::<Windows>();
-                    let focused = windows.iter().any(|w| w.is_focused());
                     let now = Instant::now();
                     use UpdateMode::*;
                     *control_flow = match winit_config.update_mode(focused) {
@@ -627,49 +554,9 @@ pub fn winit_runner_with(mut app: App) {
     }
 }
 
-fn handle_create_window_events(
-    world: &mut World,
-    event_loop: &EventLoopWindowTarget<()>,
-    create_window_event_reader: &mut ManualEventReader<CreateWindow>,
-) {
-    let world = world.cell();
-    let mut winit_windows = world.non_send_resource_mut::<WinitWindows>();
-    let mut windows = world.resource_mut::<Windows>();
-    let create_window_events = world.resource::<Events<CreateWindow>>();
-    let mut window_created_events = world.resource_mut::<Events<WindowCreated>>();
-    #[cfg(not(any(target_os = "windows", target_feature = "x11")))]
-    let mut window_resized_events = world.resource_mut::<Events<WindowResized>>();
-    for create_window_event in create_window_event_reader.iter(&create_window_events) {
-        let window = winit_windows.create_window(
-            event_loop,
-            create_window_event.id,
-            &create_window_event.descriptor,
-        );
-        // This event is already sent on windows, x11, and xwayland.
-        // TODO: we aren't yet sure about native wayland, so we might be able to exclude it,
-        // but sending a duplicate event isn't problematic, as windows already does this.
-        #[cfg(not(any(target_os = "windows", target_feature = "x11")))]
-        window_resized_events.send(WindowResized {
-            id: create_window_event.id,
-            width: window.width(),
-            height: window.height(),
-        });
-        windows.add(window);
-        window_created_events.send(WindowCreated {
-            id: create_window_event.id,
-        });
-
-        #[cfg(target_arch = "wasm32")]
-        {
-            let channel = world.resource_mut::<web_resize::CanvasParentResizeEventChannel>();
-            if create_window_event.descriptor.fit_canvas_to_parent {
-                let selector = if let Some(selector) = &create_window_event.descriptor.canvas {
-                    selector
-                } else {
-                    web_resize::WINIT_CANVAS_SELECTOR
-                };
-                channel.listen_to_selector(create_window_event.id, selector);
-            }
-        }
-    }
+fn any_window_focused(world: &mut World) -> bool {
+    world
+        .query::<&Window>()
+        .iter(world)
+        .any(|window| window.is_focused())
 }
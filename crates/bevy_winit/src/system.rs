@@ -0,0 +1,244 @@
+use crate::{
+    converters, get_best_videomode, get_fitting_videomode, set_cursor_grab_mode, set_window_icon,
+    WinitWindows,
+};
+use bevy_ecs::{
+    entity::Entity,
+    event::EventWriter,
+    prelude::{Added, Changed, Component, With},
+    system::{Commands, NonSendMut, Query},
+};
+use bevy_math::IVec2;
+use bevy_utils::tracing::error;
+use bevy_window::{
+    RawWindowHandleWrapper, Window, WindowClosed, WindowCreated, WindowMode, WindowResized,
+    WindowScaleFactorChanged,
+};
+use raw_window_handle::HasRawWindowHandle;
+use winit::{
+    dpi::{LogicalSize, PhysicalPosition},
+    event_loop::EventLoopWindowTarget,
+};
+
+/// The state of a [`Window`] when its changes were last applied to its `winit` window.
+///
+/// Changes to the [`Window`] component are found by comparing it with this copy.
+#[derive(Component, Debug, Clone)]
+pub struct CachedWindow {
+    pub window: Window,
+}
+
+/// Creates the `winit` windows of newly spawned [`Window`] entities.
+///
+/// This needs the [`EventLoopWindowTarget`], so it is run by the runner rather than in a stage.
+#[cfg_attr(
+    any(target_os = "windows", target_feature = "x11"),
+    allow(unused_mut, unused_variables)
+)]
+pub(crate) fn create_window(
+    event_loop: &EventLoopWindowTarget<()>,
+    mut commands: Commands,
+    mut created_windows: Query<(Entity, &mut Window), Added<Window>>,
+    mut window_created_events: EventWriter<WindowCreated>,
+    mut window_resized_events: EventWriter<WindowResized>,
+    mut winit_windows: NonSendMut<WinitWindows>,
+) {
+    for (entity, mut window) in created_windows.iter_mut() {
+        if winit_windows.get_window(entity).is_some() {
+            continue;
+        }
+
+        let winit_window = winit_windows.create_window(event_loop, entity, &window);
+        let inner_size = winit_window.inner_size();
+        window.update_scale_factor_from_backend(winit_window.scale_factor());
+        window.update_actual_size_from_backend(inner_size.width, inner_size.height);
+        if let Ok(position) = winit_window.outer_position() {
+            window.update_actual_position_from_backend(IVec2::new(position.x, position.y));
+        }
+
+        commands.entity(entity).insert_bundle((
+            RawWindowHandleWrapper::new(winit_window.raw_window_handle()),
+            CachedWindow {
+                window: window.clone(),
+            },
+        ));
+
+        // This event is already sent on windows, x11, and xwayland.
+        // TODO: we aren't yet sure about native wayland, so we might be able to exclude it,
+        // but sending a duplicate event isn't problematic, as windows already does this.
+        #[cfg(not(any(target_os = "windows", target_feature = "x11")))]
+        window_resized_events.send(WindowResized {
+            window: entity,
+            width: window.width(),
+            height: window.height(),
+        });
+        window_created_events.send(WindowCreated { window: entity });
+    }
+}
+
+/// Closes the `winit` windows of despawned [`Window`] entities.
+///
+/// The `winit` windows are checked against the live [`Window`] entities, so windows despawned
+/// since the last run are closed whenever that happened.
+pub(crate) fn despawn_window(
+    windows: Query<(), With<Window>>,
+    mut close_events: EventWriter<WindowClosed>,
+    mut winit_windows: NonSendMut<WinitWindows>,
+) {
+    let closed = winit_windows
+        .entity_to_winit
+        .keys()
+        .filter(|entity| !windows.contains(**entity))
+        .copied()
+        .collect::<Vec<_>>();
+    for window in closed {
+        // The `Drop` impl actually closes the window
+        if winit_windows.remove_window(window).is_some() {
+            close_events.send(WindowClosed { window });
+        }
+    }
+}
+
+/// Applies the changes of [`Window`] components to their `winit` windows.
+pub(crate) fn changed_window(
+    mut changed_windows: Query<(Entity, &mut Window, &mut CachedWindow), Changed<Window>>,
+    mut winit_windows: NonSendMut<WinitWindows>,
+    mut window_dpi_changed_events: EventWriter<WindowScaleFactorChanged>,
+) {
+    for (entity, mut window, mut cache) in changed_windows.iter_mut() {
+        let (cursor_position_request, maximize_request, minimize_request, position_request) =
+            if window.has_pending_requests() {
+                (
+                    window.take_cursor_position_request(),
+                    window.take_maximize_request(),
+                    window.take_minimize_request(),
+                    window.take_position_request(),
+                )
+            } else {
+                (None, None, None, None)
+            };
+
+        let cache = &mut cache.window;
+
+        let winit_window = match winit_windows.get_window(entity) {
+            Some(winit_window) => winit_window,
+            None => continue,
+        };
+
+        if window.mode() != cache.mode() {
+            match window.mode() {
+                WindowMode::BorderlessFullscreen => {
+                    winit_window.set_fullscreen(Some(winit::window::Fullscreen::Borderless(None)));
+                }
+                WindowMode::Fullscreen => {
+                    winit_window.set_fullscreen(Some(winit::window::Fullscreen::Exclusive(
+                        get_best_videomode(&winit_window.current_monitor().unwrap()),
+                    )));
+                }
+                WindowMode::SizedFullscreen => winit_window.set_fullscreen(Some(
+                    winit::window::Fullscreen::Exclusive(get_fitting_videomode(
+                        &winit_window.current_monitor().unwrap(),
+                        window.physical_width(),
+                        window.physical_height(),
+                    )),
+                )),
+                WindowMode::Windowed => winit_window.set_fullscreen(None),
+            }
+        }
+
+        if window.title() != cache.title() {
+            winit_window.set_title(window.title());
+        }
+
+        if window.scale_factor_override() != cache.scale_factor_override() {
+            window_dpi_changed_events.send(WindowScaleFactorChanged {
+                window: entity,
+                scale_factor: window.scale_factor(),
+            });
+        }
+
+        if window.scale_factor_override() != cache.scale_factor_override()
+            || window.requested_width() != cache.requested_width()
+            || window.requested_height() != cache.requested_height()
+        {
+            winit_window.set_inner_size(
+                LogicalSize::new(window.requested_width(), window.requested_height())
+                    .to_physical::<f64>(window.scale_factor()),
+            );
+        }
+
+        if window.resizable() != cache.resizable() {
+            winit_window.set_resizable(window.resizable());
+        }
+
+        if window.decorations() != cache.decorations() {
+            winit_window.set_decorations(window.decorations());
+        }
+
+        if window.cursor_icon() != cache.cursor_icon() {
+            winit_window.set_cursor_icon(converters::convert_cursor_icon(window.cursor_icon()));
+        }
+
+        if window.icon_image() != cache.icon_image() {
+            set_window_icon(winit_window, window.icon_image());
+        }
+
+        if window.cursor_grab_mode() != cache.cursor_grab_mode() {
+            set_cursor_grab_mode(winit_window, window.cursor_grab_mode());
+        }
+
+        if window.cursor_visible() != cache.cursor_visible() {
+            winit_window.set_cursor_visible(window.cursor_visible());
+        }
+
+        if let Some(position) = cursor_position_request {
+            let inner_size = winit_window
+                .inner_size()
+                .to_logical::<f32>(winit_window.scale_factor());
+            winit_window
+                .set_cursor_position(winit::dpi::LogicalPosition::new(
+                    position.x,
+                    inner_size.height - position.y,
+                ))
+                .unwrap_or_else(|e| error!("Unable to set cursor position: {}", e));
+            // A locked cursor is locked at its new position
+            window.update_cursor_physical_position_from_backend(Some(
+                position.as_dvec2() * window.scale_factor(),
+            ));
+        }
+
+        if let Some(maximized) = maximize_request {
+            winit_window.set_maximized(maximized);
+        }
+
+        if let Some(minimized) = minimize_request {
+            winit_window.set_minimized(minimized);
+        }
+
+        if let Some(position) = position_request {
+            winit_window.set_outer_position(PhysicalPosition {
+                x: position.x,
+                y: position.y,
+            });
+        }
+
+        if window.resize_constraints() != cache.resize_constraints() {
+            let constraints = window.resize_constraints().check_constraints();
+            let min_inner_size = LogicalSize {
+                width: constraints.min_width,
+                height: constraints.min_height,
+            };
+            let max_inner_size = LogicalSize {
+                width: constraints.max_width,
+                height: constraints.max_height,
+            };
+
+            winit_window.set_min_inner_size(Some(min_inner_size));
+            if constraints.max_width.is_finite() && constraints.max_height.is_finite() {
+                winit_window.set_max_inner_size(Some(max_inner_size));
+            }
+        }
+
+        *cache = window.clone();
+    }
+}
@@ -1,7 +1,7 @@
 use crate::WinitWindows;
 use bevy_app::{App, Plugin};
 use bevy_ecs::prelude::*;
-use bevy_window::WindowId;
+use bevy_window::{Window, WindowCreated};
 use crossbeam_channel::{Receiver, Sender};
 use wasm_bindgen::JsCast;
 use winit::dpi::LogicalSize;
@@ -11,13 +11,14 @@ pub(crate) struct CanvasParentResizePlugin;
 impl Plugin for CanvasParentResizePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CanvasParentResizeEventChannel>()
+            .add_system(listen_to_created_canvases)
             .add_system(canvas_parent_resize_event_handler);
     }
 }
 
 struct ResizeEvent {
     size: LogicalSize<f32>,
-    window_id: WindowId,
+    window: Entity,
 }
 
 pub(crate) struct CanvasParentResizeEventChannel {
@@ -25,12 +26,27 @@ pub(crate) struct CanvasParentResizeEventChannel {
     receiver: Receiver<ResizeEvent>,
 }
 
+fn listen_to_created_canvases(
+    mut created_events: EventReader<WindowCreated>,
+    windows: Query<&Window>,
+    resize_events: Res<CanvasParentResizeEventChannel>,
+) {
+    for event in created_events.iter() {
+        let window = match windows.get(event.window) {
+            Ok(window) if window.fit_canvas_to_parent() => window,
+            _ => continue,
+        };
+        let selector = window.canvas().unwrap_or(WINIT_CANVAS_SELECTOR);
+        resize_events.listen_to_selector(event.window, selector);
+    }
+}
+
 fn canvas_parent_resize_event_handler(
     winit_windows: Res<WinitWindows>,
     resize_events: Res<CanvasParentResizeEventChannel>,
 ) {
     for event in resize_events.receiver.try_iter() {
-        if let Some(window) = winit_windows.get_window(event.window_id) {
+        if let Some(window) = winit_windows.get_window(event.window) {
             window.set_inner_size(event.size);
         }
     }
@@ -58,12 +74,12 @@ impl Default for CanvasParentResizeEventChannel {
 }
 
 impl CanvasParentResizeEventChannel {
-    pub(crate) fn listen_to_selector(&self, window_id: WindowId, selector: &str) {
+    pub(crate) fn listen_to_selector(&self, window: Entity, selector: &str) {
         let sender = self.sender.clone();
         let owned_selector = selector.to_string();
         let resize = move || {
             if let Some(size) = get_size(&owned_selector) {
-                sender.send(ResizeEvent { size, window_id }).unwrap();
+                sender.send(ResizeEvent { size, window }).unwrap();
             }
         };
 
@@ -1,14 +1,14 @@
-use bevy_math::IVec2;
-use bevy_utils::HashMap;
-use bevy_window::{Window, WindowDescriptor, WindowId, WindowMode};
-use raw_window_handle::HasRawWindowHandle;
-use winit::dpi::LogicalSize;
+use crate::converters::{convert_cursor_icon, convert_window_icon};
+use bevy_ecs::entity::Entity;
+use bevy_utils::{tracing::error, HashMap};
+use bevy_window::{CursorGrabMode, Window, WindowIcon, WindowMode};
+use winit::dpi::{LogicalSize, PhysicalPosition};
 
 #[derive(Debug, Default)]
 pub struct WinitWindows {
     pub windows: HashMap<winit::window::WindowId, winit::window::Window>,
-    pub window_id_to_winit: HashMap<WindowId, winit::window::WindowId>,
-    pub winit_to_window_id: HashMap<winit::window::WindowId, WindowId>,
+    pub entity_to_winit: HashMap<Entity, winit::window::WindowId>,
+    pub winit_to_entity: HashMap<winit::window::WindowId, Entity>,
     // Some winit functions, such as `set_window_icon` can only be used from the main thread. If
     // they are used in another thread, the app will hang. This marker ensures `WinitWindows` is
     // only ever accessed with bevy's non-send functions and in NonSend systems.
@@ -16,15 +16,16 @@ pub struct WinitWindows {
 }
 
 impl WinitWindows {
+    /// Creates the `winit` window of a [`Window`] entity.
     pub fn create_window(
         &mut self,
         event_loop: &winit::event_loop::EventLoopWindowTarget<()>,
-        window_id: WindowId,
-        window_descriptor: &WindowDescriptor,
-    ) -> Window {
+        entity: Entity,
+        window: &Window,
+    ) -> &winit::window::Window {
         let mut winit_window_builder = winit::window::WindowBuilder::new();
 
-        winit_window_builder = match window_descriptor.mode {
+        winit_window_builder = match window.mode() {
             WindowMode::BorderlessFullscreen => winit_window_builder.with_fullscreen(Some(
                 winit::window::Fullscreen::Borderless(event_loop.primary_monitor()),
             )),
@@ -36,27 +37,23 @@ impl WinitWindows {
             WindowMode::SizedFullscreen => winit_window_builder.with_fullscreen(Some(
                 winit::window::Fullscreen::Exclusive(get_fitting_videomode(
                     &event_loop.primary_monitor().unwrap(),
-                    window_descriptor.width as u32,
-                    window_descriptor.height as u32,
+                    window.requested_width() as u32,
+                    window.requested_height() as u32,
                 )),
             )),
             _ => {
-                let WindowDescriptor {
-                    width,
-                    height,
-                    position,
-                    scale_factor_override,
-                    ..
-                } = window_descriptor;
-
-                if let Some(position) = position {
+                let width = window.requested_width();
+                let height = window.requested_height();
+                let scale_factor_override = window.scale_factor_override();
+
+                if let Some(position) = window.initial_position() {
                     if let Some(sf) = scale_factor_override {
                         winit_window_builder = winit_window_builder.with_position(
                             winit::dpi::LogicalPosition::new(
                                 position[0] as f64,
                                 position[1] as f64,
                             )
-                            .to_physical::<f64>(*sf),
+                            .to_physical::<f64>(sf),
                         );
                     } else {
                         winit_window_builder =
@@ -68,19 +65,19 @@ impl WinitWindows {
                 }
                 if let Some(sf) = scale_factor_override {
                     winit_window_builder.with_inner_size(
-                        winit::dpi::LogicalSize::new(*width, *height).to_physical::<f64>(*sf),
+                        winit::dpi::LogicalSize::new(width, height).to_physical::<f64>(sf),
                     )
                 } else {
                     winit_window_builder
-                        .with_inner_size(winit::dpi::LogicalSize::new(*width, *height))
+                        .with_inner_size(winit::dpi::LogicalSize::new(width, height))
                 }
             }
-            .with_resizable(window_descriptor.resizable)
-            .with_decorations(window_descriptor.decorations)
-            .with_transparent(window_descriptor.transparent),
+            .with_resizable(window.resizable())
+            .with_decorations(window.decorations())
+            .with_transparent(window.transparent()),
         };
 
-        let constraints = window_descriptor.resize_constraints.check_constraints();
+        let constraints = window.resize_constraints().check_constraints();
         let min_inner_size = LogicalSize {
             width: constraints.min_width,
             height: constraints.min_height,
@@ -100,14 +97,14 @@ impl WinitWindows {
             };
 
         #[allow(unused_mut)]
-        let mut winit_window_builder = winit_window_builder.with_title(&window_descriptor.title);
+        let mut winit_window_builder = winit_window_builder.with_title(window.title());
 
         #[cfg(target_arch = "wasm32")]
         {
             use wasm_bindgen::JsCast;
             use winit::platform::web::WindowBuilderExtWebSys;
 
-            if let Some(selector) = &window_descriptor.canvas {
+            if let Some(selector) = window.canvas() {
                 let window = web_sys::window().unwrap();
                 let document = window.document().unwrap();
                 let canvas = document
@@ -124,23 +121,27 @@ impl WinitWindows {
 
         let winit_window = winit_window_builder.build(event_loop).unwrap();
 
-        if window_descriptor.cursor_locked {
+        if window.cursor_grab_mode() != CursorGrabMode::None {
             match winit_window.set_cursor_grab(true) {
                 Ok(_) | Err(winit::error::ExternalError::NotSupported(_)) => {}
                 Err(err) => Err(err).unwrap(),
             }
         }
+        if window.icon_image().is_some() {
+            set_window_icon(&winit_window, window.icon_image());
+        }
 
-        winit_window.set_cursor_visible(window_descriptor.cursor_visible);
+        winit_window.set_cursor_visible(window.cursor_visible());
+        winit_window.set_cursor_icon(convert_cursor_icon(window.cursor_icon()));
 
-        self.window_id_to_winit.insert(window_id, winit_window.id());
-        self.winit_to_window_id.insert(winit_window.id(), window_id);
+        self.entity_to_winit.insert(entity, winit_window.id());
+        self.winit_to_entity.insert(winit_window.id(), entity);
 
         #[cfg(target_arch = "wasm32")]
         {
             use winit::platform::web::WindowExtWebSys;
 
-            if window_descriptor.canvas.is_none() {
+            if window.canvas().is_none() {
                 let canvas = winit_window.canvas();
 
                 let window = web_sys::window().unwrap();
@@ -152,42 +153,68 @@ impl WinitWindows {
             }
         }
 
-        let position = winit_window
-            .outer_position()
-            .ok()
-            .map(|position| IVec2::new(position.x, position.y));
-        let inner_size = winit_window.inner_size();
-        let scale_factor = winit_window.scale_factor();
-        let raw_window_handle = winit_window.raw_window_handle();
-        self.windows.insert(winit_window.id(), winit_window);
-        Window::new(
-            window_id,
-            window_descriptor,
-            inner_size.width,
-            inner_size.height,
-            scale_factor,
-            position,
-            raw_window_handle,
-        )
+        self.windows
+            .entry(winit_window.id())
+            .or_insert(winit_window)
     }
 
-    pub fn get_window(&self, id: WindowId) -> Option<&winit::window::Window> {
-        self.window_id_to_winit
-            .get(&id)
+    /// Get the `winit` window of a [`Window`] entity.
+    pub fn get_window(&self, entity: Entity) -> Option<&winit::window::Window> {
+        self.entity_to_winit
+            .get(&entity)
             .and_then(|id| self.windows.get(id))
     }
 
-    pub fn get_window_id(&self, id: winit::window::WindowId) -> Option<WindowId> {
-        self.winit_to_window_id.get(&id).cloned()
+    /// Get the [`Window`] entity of a `winit` window.
+    pub fn get_window_entity(&self, id: winit::window::WindowId) -> Option<Entity> {
+        self.winit_to_entity.get(&id).cloned()
     }
 
-    pub fn remove_window(&mut self, id: WindowId) -> Option<winit::window::Window> {
-        let winit_id = self.window_id_to_winit.remove(&id)?;
-        // Don't remove from winit_to_window_id, to track that we used to know about this winit window
+    /// Removes the `winit` window of a [`Window`] entity, which closes it when dropped.
+    pub fn remove_window(&mut self, entity: Entity) -> Option<winit::window::Window> {
+        let winit_id = self.entity_to_winit.remove(&entity)?;
+        // Don't remove from winit_to_entity, to track that we used to know about this winit window
         self.windows.remove(&winit_id)
     }
 }
 
+/// Sets the icon of a window, or resets it to the platform default.
+pub fn set_window_icon(window: &winit::window::Window, icon: Option<&WindowIcon>) {
+    match icon.map(convert_window_icon).transpose() {
+        Ok(icon) => window.set_window_icon(icon),
+        Err(err) => error!("Unable to set window icon: {}", err),
+    }
+}
+
+/// Sets how the cursor is grabbed by a window.
+///
+/// `winit` can only grab the cursor, which confines it on Windows, X11 and Wayland and locks it on
+/// macOS. On the platforms confining it, [`CursorGrabMode::Locked`] is emulated by moving the
+/// cursor back to its [locked position](locked_cursor_position) whenever it moves.
+pub fn set_cursor_grab_mode(window: &winit::window::Window, grab_mode: CursorGrabMode) {
+    window
+        .set_cursor_grab(grab_mode != CursorGrabMode::None)
+        .unwrap_or_else(|e| error!("Unable to un/grab cursor: {}", e));
+}
+
+/// Returns the position, in `winit` coordinates, the cursor should be moved back to if the
+/// cursor of `window` is [locked](CursorGrabMode::Locked) on a platform where `winit` can only
+/// confine it.
+///
+/// The cursor is locked where it was when the lock began, while the window is focused.
+pub fn locked_cursor_position(window: &Window) -> Option<PhysicalPosition<f64>> {
+    if cfg!(target_os = "macos")
+        || window.cursor_grab_mode() != CursorGrabMode::Locked
+        || !window.is_focused()
+    {
+        return None;
+    }
+    // Bevy's origin is the bottom left of the window, winit's the top left
+    window.physical_cursor_position().map(|position| {
+        PhysicalPosition::new(position.x, window.physical_height() as f64 - position.y)
+    })
+}
+
 pub fn get_fitting_videomode(
     monitor: &winit::monitor::MonitorHandle,
     width: u32,
@@ -239,3 +266,41 @@ pub fn get_best_videomode(monitor: &winit::monitor::MonitorHandle) -> winit::mon
 unsafe impl Send for WinitWindows {}
 #[cfg(target_arch = "wasm32")]
 unsafe impl Sync for WinitWindows {}
+
+#[cfg(test)]
+mod tests {
+    use super::locked_cursor_position;
+    use crate::converters::convert_window_icon;
+    use bevy_math::DVec2;
+    use bevy_window::{CursorGrabMode, Window, WindowDescriptor, WindowIcon};
+
+    #[test]
+    fn window_icon() {
+        let icon = WindowIcon {
+            rgba: vec![255; 2 * 2 * 4],
+            width: 2,
+            height: 2,
+        };
+        assert!(convert_window_icon(&icon).is_ok());
+
+        let icon = WindowIcon { width: 3, ..icon };
+        assert!(convert_window_icon(&icon).is_err());
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    #[test]
+    fn locked_cursor() {
+        let mut window = Window::new(&WindowDescriptor::default());
+        window.update_actual_size_from_backend(800, 600);
+        window.update_focused_status_from_backend(true);
+        window.update_cursor_physical_position_from_backend(Some(DVec2::new(100.0, 200.0)));
+        assert_eq!(locked_cursor_position(&window), None);
+
+        window.set_cursor_grab_mode(CursorGrabMode::Locked);
+        let position = locked_cursor_position(&window).unwrap();
+        assert_eq!((position.x, position.y), (100.0, 400.0));
+
+        window.update_focused_status_from_backend(false);
+        assert_eq!(locked_cursor_position(&window), None);
+    }
+}
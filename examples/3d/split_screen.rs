@@ -1,10 +1,8 @@
 //! Renders two cameras to the same window to accomplish "split screen".
 
 use bevy::{
-    core_pipeline::clear_color::ClearColorConfig,
-    prelude::*,
-    render::camera::Viewport,
-    window::{WindowId, WindowResized},
+    core_pipeline::clear_color::ClearColorConfig, prelude::*, render::camera::Viewport,
+    window::WindowResized,
 };
 
 fn main() {
@@ -83,7 +81,7 @@ struct LeftCamera;
 struct RightCamera;
 
 fn set_camera_viewports(
-    windows: Res<Windows>,
+    windows: Query<(Entity, &Window), With<PrimaryWindow>>,
     mut resize_events: EventReader<WindowResized>,
     mut left_camera: Query<&mut Camera, (With<LeftCamera>, Without<RightCamera>)>,
     mut right_camera: Query<&mut Camera, With<RightCamera>>,
@@ -92,8 +90,8 @@ fn set_camera_viewports(
     // so then each camera always takes up half the screen.
     // A resize_event is sent when the window is first created, allowing us to reuse this system for initial setup.
     for resize_event in resize_events.iter() {
-        if resize_event.id == WindowId::primary() {
-            let window = windows.primary();
+        let (primary_window, window) = windows.single();
+        if resize_event.window == primary_window {
             let mut left_camera = left_camera.single_mut();
             left_camera.viewport = Some(Viewport {
                 physical_position: UVec2::new(0, 0),
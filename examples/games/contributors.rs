@@ -253,12 +253,12 @@ fn velocity_system(time: Res<Time>, mut velocity_query: Query<&mut Velocity>) {
 /// velocity. On collision with the ground it applies an upwards
 /// force.
 fn collision_system(
-    windows: Res<Windows>,
+    windows: Query<&Window, With<PrimaryWindow>>,
     mut query: Query<(&mut Velocity, &mut Transform), With<Contributor>>,
 ) {
     let mut rng = rand::thread_rng();
 
-    let window = windows.primary();
+    let window = windows.single();
 
     let ceiling = window.height() / 2.;
     let ground = -(window.height() / 2.);
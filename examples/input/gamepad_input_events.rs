@@ -14,9 +14,12 @@ fn main() {
 
 fn gamepad_events(mut gamepad_event: EventReader<GamepadEvent>) {
     for event in gamepad_event.iter() {
-        match event.event_type {
-            GamepadEventType::Connected => {
-                info!("{:?} Connected", event.gamepad);
+        match &event.event_type {
+            GamepadEventType::Connected(info) => {
+                info!(
+                    "{:?} Connected: {} (vendor {:?}, product {:?})",
+                    event.gamepad, info.name, info.vendor_id, info.product_id
+                );
             }
             GamepadEventType::Disconnected => {
                 info!("{:?} Disconnected", event.gamepad);
@@ -18,7 +18,7 @@ fn main() {
 }
 
 fn touch_camera(
-    windows: ResMut<Windows>,
+    windows: Query<&Window, With<PrimaryWindow>>,
     mut touches: EventReader<TouchInput>,
     mut camera: Query<&mut Transform, With<Camera3d>>,
     mut last_position: Local<Option<Vec2>>,
@@ -28,7 +28,7 @@ fn touch_camera(
             *last_position = None;
         }
         if let Some(last_position) = *last_position {
-            let window = windows.primary();
+            let window = windows.single();
             let mut transform = camera.single_mut();
             *transform = Transform::from_xyz(
                 transform.translation.x
@@ -6,7 +6,8 @@ use std::any::TypeId;
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        // You must manually register each instance of a generic type
+        // You must manually register each instance of a generic type.
+        // The types of its fields, such as `u32` here, are registered along with it.
         .register_type::<MyType<u32>>()
         .add_startup_system(setup)
         .run();
@@ -22,6 +22,7 @@ fn main() {
 // FromResources). The simplest registered component just needs these two derives:
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)] // this tells the reflect derive to also reflect component behaviors
+#[reflect(Default)] // fields equal to their default value are left out of saved scenes
 struct ComponentA {
     pub x: f32,
     pub y: f32,
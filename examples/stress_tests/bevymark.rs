@@ -63,7 +63,7 @@ struct BirdScheduled {
 
 fn scheduled_spawner(
     mut commands: Commands,
-    windows: Res<Windows>,
+    windows: Query<&Window, With<PrimaryWindow>>,
     mut scheduled: ResMut<BirdScheduled>,
     mut counter: ResMut<BevyCounter>,
     bird_texture: Res<BirdTexture>,
@@ -162,7 +162,7 @@ fn mouse_handler(
     mut commands: Commands,
     time: Res<Time>,
     mouse_button_input: Res<Input<MouseButton>>,
-    windows: Res<Windows>,
+    windows: Query<&Window, With<PrimaryWindow>>,
     bird_texture: Res<BirdTexture>,
     mut counter: ResMut<BevyCounter>,
 ) {
@@ -185,12 +185,12 @@ fn mouse_handler(
 
 fn spawn_birds(
     commands: &mut Commands,
-    windows: &Windows,
+    windows: &Query<&Window, With<PrimaryWindow>>,
     counter: &mut BevyCounter,
     spawn_count: usize,
     texture: Handle<Image>,
 ) {
-    let window = windows.primary();
+    let window = windows.single();
     let bird_x = (window.width() as f32 / -2.) + HALF_BIRD_SIZE;
     let bird_y = (window.height() as f32 / 2.) - HALF_BIRD_SIZE;
     let mut rng = thread_rng();
@@ -230,8 +230,11 @@ fn movement_system(time: Res<Time>, mut bird_query: Query<(&mut Bird, &mut Trans
     }
 }
 
-fn collision_system(windows: Res<Windows>, mut bird_query: Query<(&mut Bird, &Transform)>) {
-    let window = windows.primary();
+fn collision_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut bird_query: Query<(&mut Bird, &Transform)>,
+) {
+    let window = windows.single();
     let half_width = window.width() as f32 * 0.5;
     let half_height = window.height() as f32 * 0.5;
 
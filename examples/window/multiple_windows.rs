@@ -3,7 +3,7 @@
 use bevy::{
     prelude::*,
     render::camera::RenderTarget,
-    window::{CreateWindow, PresentMode, WindowId},
+    window::{PresentMode, WindowRef},
 };
 
 fn main() {
@@ -14,11 +14,7 @@ fn main() {
         .run();
 }
 
-fn setup(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut create_window_events: EventWriter<CreateWindow>,
-) {
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     // add entities to the world
     commands.spawn_bundle(SceneBundle {
         scene: asset_server.load("models/monkey/Monkey.gltf#Scene0"),
@@ -35,25 +31,23 @@ fn setup(
         ..default()
     });
 
-    let window_id = WindowId::new();
-
-    // sends out a "CreateWindow" event, which will be received by the windowing backend
-    create_window_events.send(CreateWindow {
-        id: window_id,
-        descriptor: WindowDescriptor {
+    // spawns a window entity, which will be opened by the windowing backend
+    let second_window = commands
+        .spawn()
+        .insert(Window::new(&WindowDescriptor {
             width: 800.,
             height: 600.,
             present_mode: PresentMode::Immediate,
             title: "Second window".to_string(),
             ..default()
-        },
-    });
+        }))
+        .id();
 
     // second window camera
     commands.spawn_bundle(Camera3dBundle {
         transform: Transform::from_xyz(6.0, 0.0, 0.0).looking_at(Vec3::ZERO, Vec3::Y),
         camera: Camera {
-            target: RenderTarget::Window(window_id),
+            target: RenderTarget::Window(WindowRef::Entity(second_window)),
             ..default()
         },
         ..default()
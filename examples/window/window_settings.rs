@@ -20,8 +20,8 @@ fn main() {
 }
 
 /// This system will then change the title during execution
-fn change_title(time: Res<Time>, mut windows: ResMut<Windows>) {
-    let window = windows.primary_mut();
+fn change_title(time: Res<Time>, mut windows: Query<&mut Window, With<PrimaryWindow>>) {
+    let mut window = windows.single_mut();
     window.set_title(format!(
         "Seconds since startup: {}",
         time.seconds_since_startup().round()
@@ -29,10 +29,13 @@ fn change_title(time: Res<Time>, mut windows: ResMut<Windows>) {
 }
 
 /// This system toggles the cursor's visibility when the space bar is pressed
-fn toggle_cursor(input: Res<Input<KeyCode>>, mut windows: ResMut<Windows>) {
-    let window = windows.primary_mut();
+fn toggle_cursor(input: Res<Input<KeyCode>>, mut windows: Query<&mut Window, With<PrimaryWindow>>) {
+    let mut window = windows.single_mut();
     if input.just_pressed(KeyCode::Space) {
-        window.set_cursor_lock_mode(!window.cursor_locked());
+        window.set_cursor_grab_mode(match window.cursor_grab_mode() {
+            CursorGrabMode::None => CursorGrabMode::Locked,
+            CursorGrabMode::Confined | CursorGrabMode::Locked => CursorGrabMode::None,
+        });
         window.set_cursor_visibility(!window.cursor_visible());
     }
 }
@@ -40,7 +43,7 @@ fn toggle_cursor(input: Res<Input<KeyCode>>, mut windows: ResMut<Windows>) {
 /// This system cycles the cursor's icon through a small set of icons when clicking
 fn cycle_cursor_icon(
     input: Res<Input<MouseButton>>,
-    mut windows: ResMut<Windows>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
     mut index: Local<usize>,
 ) {
     const ICONS: &[CursorIcon] = &[
@@ -50,7 +53,7 @@ fn cycle_cursor_icon(
         CursorIcon::Text,
         CursorIcon::Copy,
     ];
-    let window = windows.primary_mut();
+    let mut window = windows.single_mut();
     if input.just_pressed(MouseButton::Left) {
         *index = (*index + 1) % ICONS.len();
         window.set_cursor_icon(ICONS[*index]);
@@ -17,9 +17,12 @@ fn main() {
         .run();
 }
 
-fn minimise_automatically(mut windows: ResMut<Windows>, mut frames: Local<u32>) {
+fn minimise_automatically(
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut frames: Local<u32>,
+) {
     if *frames == 60 {
-        windows.get_primary_mut().unwrap().set_minimized(true);
+        windows.single_mut().set_minimized(true);
     } else {
         *frames += 1;
     }
@@ -94,9 +94,9 @@ fn change_window_size(
     }
 }
 
-fn sync_dimensions(dim: Res<Dimensions>, mut windows: ResMut<Windows>) {
+fn sync_dimensions(dim: Res<Dimensions>, mut windows: Query<&mut Window, With<PrimaryWindow>>) {
     if dim.is_changed() {
-        windows.get_primary_mut().unwrap().set_resolution(
+        windows.single_mut().set_resolution(
             dim.width.try_into().unwrap(),
             dim.height.try_into().unwrap(),
         );
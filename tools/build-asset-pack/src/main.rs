@@ -0,0 +1,83 @@
+use std::{
+    fs::{self, File},
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+
+use bevy_asset::{AssetPackWriter, PackCompression};
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+struct Args {
+    /// Asset folder to package
+    #[clap(value_parser, default_value = "assets")]
+    input: PathBuf,
+
+    /// Asset pack to write. By default, the input folder name with a `.pack` extension
+    #[clap(short, long, value_parser)]
+    output: Option<PathBuf>,
+
+    #[clap(short, long, value_parser)]
+    /// Compress entries with Zstandard. Reading them requires Bevy's `zstd` feature
+    zstd: bool,
+
+    #[clap(short, long, value_parser, default_value_t = 19)]
+    /// Zstandard compression level
+    level: i32,
+}
+
+fn main() {
+    let cli = Args::parse();
+    let output = cli
+        .output
+        .clone()
+        .unwrap_or_else(|| cli.input.with_extension(bevy_asset::ASSET_PACK_EXTENSION));
+
+    let mut files = Vec::new();
+    collect_files(&cli.input, &mut files);
+    files.sort();
+
+    let mut writer = AssetPackWriter::new();
+    let (mut total_size, mut packed_size) = (0, 0);
+    for file in files {
+        let bytes = fs::read(&file).expect("Error reading asset");
+        let path = file.strip_prefix(&cli.input).unwrap();
+        total_size += bytes.len();
+
+        // only keep the compressed data when it is actually smaller
+        let compressed = cli
+            .zstd
+            .then(|| zstd::encode_all(bytes.as_slice(), cli.level).expect("Error compressing"))
+            .filter(|compressed| compressed.len() < bytes.len());
+        if let Some(compressed) = compressed {
+            packed_size += compressed.len();
+            writer.add_compressed(path, PackCompression::Zstd, compressed, bytes.len() as u64);
+        } else {
+            packed_size += bytes.len();
+            writer.add(path, bytes);
+        }
+    }
+
+    let file = File::create(&output).expect("Error creating asset pack");
+    writer
+        .write(BufWriter::new(file))
+        .expect("Error writing asset pack");
+    println!(
+        "Packed {} assets ({} bytes) into {} ({} bytes of data)",
+        writer.len(),
+        total_size,
+        output.display(),
+        packed_size
+    );
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).expect("Error reading asset folder") {
+        let path = entry.expect("Error reading asset folder").path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}